@@ -117,7 +117,8 @@ criterion_group!(
     config = ecs_default_criterion();
     targets =
     set,
-    set_remove
+    set_remove,
+    set_chained_vs_insert
 );
 
 criterion_group!(
@@ -47,3 +47,59 @@ pub fn set_remove(criterion: &mut Criterion) {
 
     group.finish();
 }
+
+pub fn set_chained_vs_insert(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("flecs");
+
+    group.bench_function("set_4_chained", |bencher| {
+        let world = World::new();
+        let entities = create_entities(&world, ENTITY_COUNT as usize);
+
+        bencher.iter_custom(|iters| {
+            let mut elapsed = Duration::ZERO;
+            for _ in 0..iters {
+                let start = Instant::now();
+                for e in &entities {
+                    e.set(Position { x: 1.0, y: 2.0 })
+                        .set(Velocity { x: 3.0, y: 4.0 })
+                        .set(C1(5))
+                        .add(T1::id());
+                }
+                elapsed += start.elapsed();
+
+                for e in &entities {
+                    e.clear();
+                }
+            }
+            elapsed / ENTITY_COUNT //time average per entity operation
+        });
+    });
+
+    group.bench_function("set_4_insert", |bencher| {
+        let world = World::new();
+        let entities = create_entities(&world, ENTITY_COUNT as usize);
+
+        bencher.iter_custom(|iters| {
+            let mut elapsed = Duration::ZERO;
+            for _ in 0..iters {
+                let start = Instant::now();
+                for e in &entities {
+                    e.insert(|b| {
+                        b.set(Position { x: 1.0, y: 2.0 })
+                            .set(Velocity { x: 3.0, y: 4.0 })
+                            .set(C1(5))
+                            .add(T1::id());
+                    });
+                }
+                elapsed += start.elapsed();
+
+                for e in &entities {
+                    e.clear();
+                }
+            }
+            elapsed / ENTITY_COUNT //time average per entity operation
+        });
+    });
+
+    group.finish();
+}
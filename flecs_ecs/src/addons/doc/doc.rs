@@ -158,6 +158,9 @@ pub trait Doc<'a>: WorldProvider<'a> + Into<Entity> + Clone {
 
     /// Add link to external documentation to entity.
     ///
+    /// This adds `(flecs.doc.Description, flecs.doc.Link)` to the entity. UIs such as
+    /// the explorer show it as a clickable URL.
+    ///
     /// # Arguments
     ///
     /// * `link` - The link to add.
@@ -172,11 +175,12 @@ pub trait Doc<'a>: WorldProvider<'a> + Into<Entity> + Clone {
 
     /// Add color to entity.
     ///
-    /// UIs can use color as hint to improve visualizing entities.
+    /// This adds `(flecs.doc.Description, flecs.doc.Color)` to the entity. UIs can use
+    /// color as a hint to improve visualizing entities. UIs such as the explorer accept
+    /// any CSS color, for example `"#4287f5"` or `"red"`.
     ///
     /// # Arguments
     ///
-    /// * `world` - The world.
     /// * `color` - The color to add.
     ///
     /// # See also
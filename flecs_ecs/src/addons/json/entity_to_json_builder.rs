@@ -0,0 +1,114 @@
+//! [`EntityToJsonBuilder`] configures how an entity is serialized to JSON.
+
+use super::*;
+use crate::core::*;
+use alloc::string::String;
+
+/// [`EntityToJsonBuilder`] is a builder for the options of
+/// [`EntityView::to_json()`]. Created with [`EntityView::to_json_builder()`].
+///
+/// The builder starts out with the same options that are used when no
+/// descriptor is passed to [`EntityView::to_json()`]: full paths and component
+/// values are serialized, everything else is disabled.
+pub struct EntityToJsonBuilder<'a> {
+    desc: EntityToJsonDesc,
+    entity: EntityView<'a>,
+}
+
+impl<'a> EntityToJsonBuilder<'a> {
+    pub(crate) fn new(entity: EntityView<'a>) -> Self {
+        Self {
+            desc: EntityToJsonDesc {
+                serialize_entity_id: false,
+                serialize_doc: false,
+                serialize_full_paths: true,
+                serialize_inherited: false,
+                serialize_values: true,
+                serialize_builtin: false,
+                serialize_type_info: false,
+                serialize_alerts: false,
+                serialize_refs: 0,
+                serialize_matches: false,
+                component_filter: None,
+            },
+            entity,
+        }
+    }
+
+    /// Serialize the numeric entity id.
+    pub fn serialize_entity_id(&mut self, value: bool) -> &mut Self {
+        self.desc.serialize_entity_id = value;
+        self
+    }
+
+    /// Serialize doc attributes, such as the brief description and color.
+    pub fn serialize_doc(&mut self, value: bool) -> &mut Self {
+        self.desc.serialize_doc = value;
+        self
+    }
+
+    /// Serialize full paths for tags, components and pairs instead of names.
+    pub fn serialize_full_paths(&mut self, value: bool) -> &mut Self {
+        self.desc.serialize_full_paths = value;
+        self
+    }
+
+    /// Serialize components inherited from base entities.
+    pub fn serialize_inherited(&mut self, value: bool) -> &mut Self {
+        self.desc.serialize_inherited = value;
+        self
+    }
+
+    /// Serialize component values. If disabled only the component names are
+    /// serialized.
+    pub fn serialize_values(&mut self, value: bool) -> &mut Self {
+        self.desc.serialize_values = value;
+        self
+    }
+
+    /// Serialize builtin data, such as the name and parent, as components.
+    pub fn serialize_builtin(&mut self, value: bool) -> &mut Self {
+        self.desc.serialize_builtin = value;
+        self
+    }
+
+    /// Serialize type info of components. Requires component values to be
+    /// serialized.
+    pub fn serialize_type_info(&mut self, value: bool) -> &mut Self {
+        self.desc.serialize_type_info = value;
+        self
+    }
+
+    /// Serialize the active alerts of the entity.
+    pub fn serialize_alerts(&mut self, value: bool) -> &mut Self {
+        self.desc.serialize_alerts = value;
+        self
+    }
+
+    /// Serialize the entities that have a `(relationship, entity)` pair,
+    /// i.e. the incoming edges of `relationship`.
+    ///
+    /// # Arguments
+    ///
+    /// * `relationship` - The relationship to serialize references for.
+    pub fn serialize_refs(&mut self, relationship: impl IntoEntity) -> &mut Self {
+        self.desc.serialize_refs = *relationship.into_entity(self.entity.world());
+        self
+    }
+
+    /// Serialize which queries the entity matches with.
+    pub fn serialize_matches(&mut self, value: bool) -> &mut Self {
+        self.desc.serialize_matches = value;
+        self
+    }
+
+    /// Serialize the entity with the configured options.
+    ///
+    /// # Panics
+    ///
+    /// Panics if serialization fails, for example when a component has an
+    /// invalid value.
+    pub fn build(&self) -> String {
+        self.entity.to_json(Some(&self.desc))
+    }
+}
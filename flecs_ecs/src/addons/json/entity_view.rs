@@ -43,6 +43,15 @@ impl EntityView<'_> {
     }
 
     /// Serialize entity to JSON.
+    ///
+    /// # Arguments
+    ///
+    /// * `desc` - Serialization options, or `None` for the defaults.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::to_json_builder()`]
+    /// * C API: `ecs_entity_to_json`
     pub fn to_json(&self, desc: Option<&EntityToJsonDesc>) -> String {
         let world = self.world_ptr();
         let id = *self.id;
@@ -62,6 +71,40 @@ impl EntityView<'_> {
         }
     }
 
+    /// Create a builder to configure how the entity is serialized to JSON.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// #[flecs(meta)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world.entity_named("e").set(Position { x: 10.0, y: 20.0 });
+    ///
+    /// let json = e
+    ///     .to_json_builder()
+    ///     .serialize_values(false)
+    ///     .serialize_full_paths(false)
+    ///     .build();
+    ///
+    /// assert_eq!(json, r#"{"name":"e", "components":{"Position":null}}"#);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::to_json()`]
+    pub fn to_json_builder(&self) -> EntityToJsonBuilder<'_> {
+        EntityToJsonBuilder::new(*self)
+    }
+
     /// Deserialize entity to JSON.
     pub fn from_json(self, json: &str) -> Self {
         let world = self.world_ptr_mut();
@@ -88,5 +88,8 @@ pub type WorldToJsonDesc = sys::ecs_world_to_json_desc_t;
 pub type EntityToJsonDesc = sys::ecs_entity_to_json_desc_t;
 pub type IterToJsonDesc = sys::ecs_iter_to_json_desc_t;
 
+mod entity_to_json_builder;
 mod entity_view;
 mod world;
+
+pub use entity_to_json_builder::EntityToJsonBuilder;
@@ -94,6 +94,85 @@ impl<'a, T: 'static> Component<'a, T> {
         opaque.as_type(copy_id);
         opaque
     }
+
+    /// Serialize the component as a primitive value.
+    ///
+    /// Registers the component as an opaque type that serializes as `Type`, using
+    /// `func` to convert the component to it, and deserializes by converting the
+    /// value back with `T::from`. This is intended for newtype and handle
+    /// components. Values that don't fit in `Type` are ignored when deserializing.
+    ///
+    /// `func` must not capture anything, like the other opaque callbacks.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `Type` - The primitive type to serialize as, see [`OpaquePrimitive`].
+    ///
+    /// # Arguments
+    ///
+    /// * `func` - Converts the component to `Type`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component, Default)]
+    /// struct Handle(u64);
+    ///
+    /// impl From<u64> for Handle {
+    ///     fn from(value: u64) -> Self {
+    ///         Handle(value)
+    ///     }
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.component::<Handle>().opaque_as::<u64>(|h: &Handle| h.0);
+    ///
+    /// let mut h = Handle(42);
+    /// assert_eq!(world.to_json::<Handle>(&h), "42");
+    ///
+    /// world.from_json::<Handle>(&mut h, "7", None);
+    /// assert_eq!(h.0, 7);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Component::opaque()`]
+    pub fn opaque_as<Type>(&self, func: impl Fn(&T) -> Type) -> &Self
+    where
+        Type: OpaquePrimitive,
+        T: From<Type>,
+    {
+        fn serialize_as<F, T, Type>(ser: &Serializer, value: &T) -> i32
+        where
+            F: Fn(&T) -> Type,
+            Type: ComponentId,
+        {
+            // SAFETY: `F` is zero-sized (asserted in `register_serialize`), so it has
+            // no data and can be conjured from nothing.
+            let func = unsafe { core::mem::transmute_copy::<_, F>(&()) };
+            ser.value(&func(value))
+        }
+
+        fn register_serialize<F, T, Type>(opaque: &mut Opaque<'_, T>, func: F)
+        where
+            F: Fn(&T) -> Type,
+            Type: ComponentId,
+        {
+            const {
+                assert!(core::mem::size_of::<F>() == 0);
+            }
+            core::mem::forget(func);
+            opaque.serialize(serialize_as::<F, T, Type>);
+        }
+
+        let mut opaque = self.opaque_id(Type::id());
+        register_serialize(&mut opaque, func);
+        Type::__register_assign(&mut opaque);
+        self
+    }
 }
 
 impl<T: EnumComponentInfo + 'static> Component<'_, T> {
@@ -131,6 +131,7 @@ macro_rules! member_ext {
     }};
 }
 
+#[cfg(feature = "std")]
 #[allow(dead_code, clippy::print_stdout)]
 /// Run this to regenerate the tuple rules for [`component_ext!`]
 fn codegen_tuple_struct_macro() {
@@ -389,10 +390,20 @@ pub fn opaque_option_struct<T: Default>(world: WorldRef) -> Opaque<Option<T>, T>
         let member = unsafe { core::ffi::CStr::from_ptr(member) };
         if member == c"None" {
             *data = None;
-            std::thread_local! {
-                static BITBUCKET: core::cell::Cell<bool> = const { core::cell::Cell::new(false) };
+            #[cfg(feature = "std")]
+            {
+                std::thread_local! {
+                    static BITBUCKET: core::cell::Cell<bool> = const { core::cell::Cell::new(false) };
+                }
+                return BITBUCKET.with(core::cell::Cell::as_ptr) as *mut _;
+            }
+            // without thread locals all threads share the bucket, the written value is never read
+            #[cfg(not(feature = "std"))]
+            {
+                static BITBUCKET: core::sync::atomic::AtomicBool =
+                    core::sync::atomic::AtomicBool::new(false);
+                return BITBUCKET.as_ptr() as *mut _;
             }
-            return BITBUCKET.with(core::cell::Cell::as_ptr) as *mut _;
         } else if member == c"Some" {
             if data.is_none() {
                 *data = Some(T::default());
@@ -136,29 +136,4 @@ mod tests {
     //     println!("{}", json);
     //     assert_eq!("10", json);
     // }
-
-    // #[derive(Component, Default)]
-    // struct Position {
-    //     x: f32,
-    //     y: f32,
-    // }
-
-    // //#[test]
-    // fn test_expr() {
-    //     let world = World::new();
-
-    //     world
-    //         .component::<Position>()
-    //         .member::<f32>("x", 1, core::mem::offset_of!(Position, x) as i32)
-    //         .member::<f32>("y", 1, core::mem::offset_of!(Position, y) as i32);
-
-    //     let e = world.entity().set(Position { x: 10.0, y: 20.0 });
-
-    //     let pos_id = <Position as ComponentId>::id(&world);
-
-    //     // e.get::<&Position>(|pos| {
-    //     //     let expr = world.to_expr(pos);
-    //     //     println!("{}", expr);
-    //     // });
-    // }
 }
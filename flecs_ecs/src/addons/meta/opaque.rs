@@ -216,3 +216,39 @@ impl<T, ElemType> Drop for Opaque<'_, T, ElemType> {
         }
     }
 }
+
+/// Primitive types a component can be serialized as with [`Component::opaque_as()`].
+///
+/// Implemented for the integer types and `bool`.
+pub trait OpaquePrimitive: ComponentId {
+    // Not public API.
+    #[doc(hidden)]
+    fn __register_assign<T: From<Self> + 'static>(opaque: &mut Opaque<'_, T>);
+}
+
+macro_rules! impl_opaque_primitive {
+    ($assign:ident, $value:ty, $($t:ty),*) => {
+        $(
+            impl OpaquePrimitive for $t {
+                fn __register_assign<T: From<Self> + 'static>(opaque: &mut Opaque<'_, T>) {
+                    // values that don't fit in the primitive leave the component unchanged
+                    #[allow(irrefutable_let_patterns)]
+                    opaque.$assign(|dst: &mut T, value: $value| {
+                        if let Ok(value) = <$t>::try_from(value) {
+                            *dst = T::from(value);
+                        }
+                    });
+                }
+            }
+        )*
+    };
+}
+
+impl_opaque_primitive!(assign_uint, u64, u8, u16, u32, u64, usize);
+impl_opaque_primitive!(assign_int, i64, i8, i16, i32, i64, isize);
+
+impl OpaquePrimitive for bool {
+    fn __register_assign<T: From<Self> + 'static>(opaque: &mut Opaque<'_, T>) {
+        opaque.assign_bool(|dst: &mut T, value: bool| *dst = T::from(value));
+    }
+}
@@ -268,13 +268,64 @@ impl UntypedComponent<'_> {
         return member(type_id, unit_id, name, core::extent<MemberType>::value, offset);
              */
 
-    /// Add bitmask constant
+    /// Mark the component as a bitmask type.
+    ///
+    /// Bitmask types are serialized as the names of the flags that are set
+    /// (e.g. `"Write|Read"`) instead of as a plain integer. The component must
+    /// have the layout of a `u32`, such as a `#[repr(C)]` struct with a single
+    /// `u32` field. Flags are registered with [`UntypedComponent::bit()`], which
+    /// marks the component as a bitmask as well.
+    ///
+    /// This is what `#[flecs(bitmask)]` calls when the component is registered.
+    pub fn bitmask(self) -> Self {
+        // SAFETY: `EcsBitmask` is initialized by its own ctor hook, no value is provided
+        unsafe { sys::ecs_add_id(self.world_ptr_mut(), *self.id, flecs::meta::Bitmask::ID) };
+        self
+    }
+
+    /// Add a bitmask constant.
+    ///
+    /// Marks the component as a bitmask type (see [`UntypedComponent::bitmask()`])
+    /// and registers `name` as a flag with `value`. Flecs expects bitmask constants
+    /// to be stored as `(Constant, u32)`, so `value` should be a `u32`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the flag, used when serializing values.
+    /// * `value` - The bit(s) the flag represents.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component, Default)]
+    /// #[repr(C)]
+    /// struct Permissions {
+    ///     bits: u32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world
+    ///     .component::<Permissions>()
+    ///     .bit("Read", 0x1u32)
+    ///     .bit("Write", 0x2u32);
+    ///
+    /// let json = world.to_json::<Permissions>(&Permissions { bits: 0x3 });
+    /// assert_eq!(json, "\"Write|Read\"");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`UntypedComponent::bitmask()`]
+    /// * [`UntypedComponent::constant()`]
     pub fn bit<T: ComponentId>(self, name: &str, value: T) -> Self {
         let name = compact_str::format_compact!("{}\0", name);
         let world = self.world_ptr_mut();
         let id = *self.id;
 
-        unsafe { sys::ecs_add_id(world, id, flecs::meta::Bitmask::ID) };
+        self.bitmask();
 
         let desc = sys::ecs_entity_desc_t {
             name: name.as_ptr() as *const _,
@@ -314,7 +365,51 @@ impl UntypedComponent<'_> {
         self
     }
 
-    /// add member value range
+    /// Set the value range of the last added member.
+    ///
+    /// Ranges are used for validation and by tools such as the explorer to
+    /// render sliders. The range is stored on the struct member, and in the
+    /// [`MemberRanges`](flecs::meta::MemberRanges) component of the member
+    /// entity once one exists (see [`UntypedComponent::create_member_entities()`]).
+    /// Does nothing if no member was added yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The minimum value.
+    /// * `max` - The maximum value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct CpuUtilization {
+    ///     value: f64,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let c = world
+    ///     .component::<CpuUtilization>()
+    ///     .member(f64::id(), "value")
+    ///     .range(0.0, 100.0)
+    ///     .warning_range(0.0, 60.0)
+    ///     .error_range(0.0, 80.0)
+    ///     .create_member_entities();
+    ///
+    /// c.lookup("value")
+    ///     .get::<&flecs::meta::MemberRanges>(|ranges| {
+    ///         assert_eq!(ranges.value.max, 100.0);
+    ///         assert_eq!(ranges.warning.max, 60.0);
+    ///         assert_eq!(ranges.error.max, 80.0);
+    ///     });
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`UntypedComponent::warning_range()`]
+    /// * [`UntypedComponent::error_range()`]
     pub fn range(self, min: f64, max: f64) -> Self {
         let m = unsafe { sys::ecs_cpp_last_member(self.world_ptr(), *self.id) };
         if m.is_null() {
@@ -346,7 +441,20 @@ impl UntypedComponent<'_> {
         self
     }
 
-    /// add member warning range
+    /// Set the warning range of the last added member.
+    ///
+    /// Values outside of this range are considered a warning. See
+    /// [`UntypedComponent::range()`] for an example.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The minimum value.
+    /// * `max` - The maximum value.
+    ///
+    /// # See also
+    ///
+    /// * [`UntypedComponent::range()`]
+    /// * [`UntypedComponent::error_range()`]
     pub fn warning_range(self, min: f64, max: f64) -> Self {
         let m = unsafe { sys::ecs_cpp_last_member(self.world_ptr(), *self.id) };
         if m.is_null() {
@@ -378,7 +486,20 @@ impl UntypedComponent<'_> {
         self
     }
 
-    /// add member error range
+    /// Set the error range of the last added member.
+    ///
+    /// Values outside of this range are considered an error. See
+    /// [`UntypedComponent::range()`] for an example.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The minimum value.
+    /// * `max` - The maximum value.
+    ///
+    /// # See also
+    ///
+    /// * [`UntypedComponent::range()`]
+    /// * [`UntypedComponent::warning_range()`]
     pub fn error_range(self, min: f64, max: f64) -> Self {
         let m = unsafe { sys::ecs_cpp_last_member(self.world_ptr(), *self.id) };
         if m.is_null() {
@@ -64,7 +64,7 @@
 //!
 //! ## Remote Access
 //!
-//! - **REST API** - HTTP server for remote data access
+//! - **[`rest`]** - HTTP server for remote data access
 //!   - Feature: `flecs_rest`
 //!   - Used for: Web-based UIs, remote inspection, Flecs Explorer
 //!
@@ -198,6 +198,9 @@ pub mod units;
 #[cfg(feature = "flecs_metrics")]
 pub mod metrics;
 
+#[cfg(feature = "flecs_rest")]
+pub mod rest;
+
 #[cfg(feature = "flecs_alerts")]
 pub mod alerts;
 #[cfg(feature = "flecs_alerts")]
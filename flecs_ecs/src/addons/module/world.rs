@@ -32,6 +32,75 @@ impl World {
     /// * [`Module`]
     /// * [`World::module()`]
     pub fn import<T: Module>(&self) -> EntityView<'_> {
+        // Modules imported from inside an `import_named` module keep their own scope
+        let prev_named_scope = self.world_ctx().named_import_scope.replace(0);
+        let module = self.import_internal::<T>();
+        self.world_ctx().named_import_scope.set(prev_named_scope);
+        module
+    }
+
+    /// Import a module under a custom scope name.
+    ///
+    /// Runs the module's registration with the scope set to a root entity named
+    /// `name`, which takes the place of the module entity: [`World::module()`]
+    /// calls inside the module return this scope instead of renaming the module
+    /// type. This makes it possible to import the same module more than once,
+    /// with each import creating its own named entities, systems and observers.
+    ///
+    /// Components registered by type still have a single id per world. They are
+    /// created in the scope of whichever import registers them first, and later
+    /// imports reuse them, so only entity naming and scoping differ between imports.
+    ///
+    /// Importing with a name that was already imported returns the existing scope
+    /// without running the module again.
+    ///
+    /// ```
+    /// # use flecs_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// struct Spawners;
+    ///
+    /// impl Module for Spawners {
+    ///     fn module(world: &World) {
+    ///         world.module::<Spawners>("Spawners");
+    ///         world.entity_named("Spawner");
+    ///     }
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.import_named::<Spawners>("level_a");
+    /// world.import_named::<Spawners>("level_b");
+    ///
+    /// assert!(world.try_lookup("level_a::Spawner").is_some());
+    /// assert!(world.try_lookup("level_b::Spawner").is_some());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::import()`]
+    /// * [`World::module()`]
+    pub fn import_named<T: Module>(&self, name: &str) -> EntityView<'_> {
+        let prev_scope = self.set_scope(0);
+        let scope = self.entity_named(name);
+
+        if scope.has(flecs::Module::ID) {
+            self.set_scope(prev_scope);
+            return scope;
+        }
+
+        let prev_named_scope = self.world_ctx().named_import_scope.replace(*scope.id());
+        self.set_scope(scope);
+
+        T::module(self);
+
+        self.set_scope(prev_scope);
+        self.world_ctx().named_import_scope.set(prev_named_scope);
+
+        scope.add(flecs::Module::ID);
+        scope
+    }
+
+    fn import_internal<T: Module>(&self) -> EntityView<'_> {
         // Reset scope
         let prev_scope = self.set_scope(0);
         let world = self.world();
@@ -107,6 +176,9 @@ impl World {
     /// This operation is not mandatory, but can be called inside the module ctor to
     /// obtain the entity associated with the module, or override the module name.
     ///
+    /// When the module is imported with [`World::import_named()`], this returns the
+    /// named scope and leaves the module type's name untouched.
+    ///
     /// # Type Parameters
     ///
     /// * `M` - The type of the module.
@@ -125,6 +197,12 @@ impl World {
     /// * [`Module`]
     /// * [`World::import()`]
     pub fn module<M: ComponentId>(&self, name: &str) -> EntityView<'_> {
+        let named_scope = self.world_ctx().named_import_scope.get();
+        if named_scope != 0 {
+            self.set_scope(named_scope);
+            return EntityView::new_from(self, named_scope);
+        }
+
         let comp = self.component::<M>();
         let id = comp.id();
 
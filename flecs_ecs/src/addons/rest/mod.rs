@@ -0,0 +1,101 @@
+//! Start and stop the REST API server from Rust.
+//!
+//! The REST server exposes the world over HTTP, which is what the
+//! [Flecs Explorer](https://www.flecs.dev/explorer) connects to. It is enabled with
+//! [`World::enable_rest()`](crate::core::World::enable_rest), which returns a
+//! [`RestServer`] handle that shuts the server down when it is stopped or dropped.
+//!
+//! The server accepts connections on a background thread. Requests are answered
+//! while the world is progressed, so the application has to keep calling
+//! [`World::progress()`](crate::core::World::progress).
+//!
+//! # Example
+//!
+//! ```no_run
+//! use flecs_ecs::addons::rest::RestConfig;
+//! use flecs_ecs::prelude::*;
+//!
+//! let world = World::new();
+//!
+//! let server = world.enable_rest(RestConfig::default());
+//!
+//! while world.progress() {}
+//!
+//! server.stop();
+//! ```
+//!
+//! # See also
+//!
+//! - [`flecs::rest::Rest`] - The component that runs the server
+//! - [`App::enable_rest()`](crate::addons::app::App::enable_rest) - Enable REST via the app addon
+
+mod world;
+
+use alloc::string::String;
+
+use crate::core::*;
+
+/// Configuration for [`World::enable_rest()`](crate::core::World::enable_rest).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RestConfig {
+    /// Port to listen on. `0` uses the default port `27750`.
+    pub port: u16,
+    /// Interface address to bind to. `None` binds to `0.0.0.0`.
+    pub ipaddr: Option<String>,
+}
+
+/// Handle to a running REST server, created with
+/// [`World::enable_rest()`](crate::core::World::enable_rest).
+///
+/// The server is shut down when the handle is stopped or dropped. Shutting down
+/// joins the thread that accepts connections.
+#[must_use = "the REST server is stopped when the handle is dropped"]
+pub struct RestServer<'a> {
+    world: WorldRef<'a>,
+    generation: u32,
+}
+
+impl<'a> RestServer<'a> {
+    pub(crate) fn new(world: WorldRef<'a>) -> Self {
+        let real_world = world.real_world();
+        let ctx = real_world.world_ctx();
+        let generation = ctx.rest_server_generation.get().wrapping_add(1);
+        ctx.rest_server_generation.set(generation);
+        Self { world, generation }
+    }
+
+    fn is_current(&self) -> bool {
+        self.world
+            .real_world()
+            .world_ctx()
+            .rest_server_generation
+            .get()
+            == self.generation
+    }
+
+    /// Returns whether the server is listening.
+    ///
+    /// This is `false` if the server could not be created, for example because
+    /// the port is already in use, or if it was replaced by a later call to
+    /// [`World::enable_rest()`](crate::core::World::enable_rest). Flecs logs an
+    /// error when the server can't be created.
+    pub fn is_running(&self) -> bool {
+        self.is_current()
+            && self
+                .world
+                .try_get::<&flecs::rest::Rest>(|rest| !rest.impl_.is_null())
+                .unwrap_or(false)
+    }
+
+    /// Stop the server. Same as dropping the handle.
+    pub fn stop(self) {}
+}
+
+impl Drop for RestServer<'_> {
+    fn drop(&mut self) {
+        // leave a server alone that replaced this one through another `enable_rest` call
+        if self.is_current() && self.world.has(flecs::rest::Rest::id()) {
+            self.world.remove(flecs::rest::Rest::id());
+        }
+    }
+}
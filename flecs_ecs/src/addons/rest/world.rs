@@ -0,0 +1,71 @@
+use super::{RestConfig, RestServer};
+use crate::core::*;
+
+/// REST mixin implementation
+impl World {
+    /// Start the REST API server.
+    ///
+    /// This sets the [`flecs::rest::Rest`] singleton, which creates an HTTP server
+    /// that accepts connections on a background thread. Requests are answered
+    /// while the world is progressed. The server is shut down when the returned
+    /// handle is stopped or dropped. A server started by an earlier call is
+    /// replaced.
+    ///
+    /// Requires the `flecs_rest` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The port and interface address to listen on.
+    ///
+    /// # Returns
+    ///
+    /// A handle to the server. If the server could not be created, for example
+    /// because the port is in use, an error is logged and
+    /// [`RestServer::is_running()`] returns `false`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use flecs_ecs::addons::rest::RestConfig;
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let server = world.enable_rest(RestConfig {
+    ///     port: 27750,
+    ///     ipaddr: Some("127.0.0.1".to_string()),
+    /// });
+    /// assert!(server.is_running());
+    ///
+    /// for _ in 0..100 {
+    ///     world.progress();
+    /// }
+    ///
+    /// server.stop();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`addons::rest`](crate::addons::rest)
+    /// * [`App::enable_rest()`](crate::addons::app::App::enable_rest)
+    pub fn enable_rest(&self, config: RestConfig) -> RestServer<'_> {
+        let ipaddr = config.ipaddr.as_deref().map_or(
+            core::ptr::null_mut(),
+            copy_and_allocate_c_char_from_rust_str,
+        );
+
+        // replace a running server, setting the component again would leak it
+        if self.has(flecs::rest::Rest::id()) {
+            self.remove(flecs::rest::Rest::id());
+        }
+
+        // the component takes ownership of `ipaddr` and frees it with the os api
+        self.set(flecs::rest::Rest {
+            port: config.port,
+            ipaddr,
+            impl_: core::ptr::null_mut(),
+        });
+
+        RestServer::new(self.world())
+    }
+}
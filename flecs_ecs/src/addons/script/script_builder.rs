@@ -64,6 +64,95 @@ impl<'a> ScriptBuilder<'a> {
         }
     }
 
+    /// Binds a Rust value to a script variable, so scripts can reference it as `$name`.
+    ///
+    /// The value is stored in a world-level const variable (see C API `ecs_const_var_init`),
+    /// which is visible to every script evaluated after this call, not just the one built
+    /// by this builder. Calling `set_var` again with the same name replaces the value.
+    ///
+    /// `T` must have reflection data registered (primitives do by default) for the script
+    /// to be able to read it, and must implement [`Clone`] since the value is copied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a variable with the same name already exists with a different type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// world
+    ///     .script()
+    ///     .set_var("spawn_count", 3i32)
+    ///     .build_from_code("const count: $spawn_count");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::get_const_var()`]
+    /// * C API: `ecs_const_var_init`
+    pub fn set_var<T: ComponentId + DataComponent>(&mut self, name: &str, value: T) -> &mut Self {
+        const {
+            if !T::IMPLS_CLONE {
+                panic!("script variables are copied into the world, T must implement Clone");
+            }
+        }
+
+        let world = self.world.world_ptr_mut();
+        let type_id = T::entity_id(self.world);
+        let c_name = compact_str::format_compact!("{}\0", name);
+
+        // SAFETY: world is a valid world pointer and c_name is null-terminated.
+        let var = unsafe {
+            sys::ecs_lookup_path_w_sep(
+                world,
+                0,
+                c_name.as_ptr() as *const _,
+                SEPARATOR.as_ptr(),
+                SEPARATOR.as_ptr(),
+                false,
+            )
+        };
+        // SAFETY: ecs_const_var_get returns a null value for entities that are not const vars.
+        let existing = if var != 0 {
+            unsafe { sys::ecs_const_var_get(world, var) }
+        } else {
+            sys::ecs_value_t {
+                type_: 0,
+                ptr: core::ptr::null_mut(),
+            }
+        };
+
+        if !existing.ptr.is_null() {
+            assert_eq!(
+                existing.type_, type_id,
+                "script variable `{name}` already exists with a different type"
+            );
+            // SAFETY: the const var storage holds an initialized value of type T (checked above).
+            unsafe {
+                *(existing.ptr as *mut T) = value;
+                sys::ecs_const_var_modified(world, var);
+            }
+        } else {
+            let mut value = value;
+            let mut desc = sys::ecs_const_var_desc_t {
+                name: c_name.as_ptr() as *const _,
+                parent: 0,
+                type_: type_id,
+                value: &mut value as *mut T as *mut core::ffi::c_void,
+            };
+            // SAFETY: world is a valid world pointer; desc has a null-terminated name and points
+            // to a valid T, which flecs copies with the registered copy hook.
+            let var = unsafe { sys::ecs_const_var_init(world, &mut desc) };
+            assert_ne!(var, 0, "failed to create script variable `{name}`");
+        }
+
+        self
+    }
+
     /// Loads a managed script from a file into the ECS world.
     ///
     /// This function initializes an ECS script from a file specified by `filename`.
@@ -206,6 +206,47 @@ impl<'a> Script<'a> {
         str
     }
 
+    /// Parse an expression into a value.
+    /// This operation evaluates the expression and assigns the result to the value of the provided type.
+    ///
+    /// # Safety
+    /// The caller must ensure that `value` points to valid, initialized data of the type specified by `id_of_value`.
+    ///
+    /// # Returns
+    ///
+    /// True if success, false if the expression could not be parsed or assigned to the type,
+    /// or if input is left after the parsed expression.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_expr_run`
+    pub unsafe fn from_expr<T: IntoEntity>(
+        world: impl WorldProvider<'a>,
+        id_of_value: T,
+        value: *mut T::CastType,
+        expr: &str,
+    ) -> bool {
+        let world = world.world();
+        let id = *id_of_value.into_entity(world);
+        let expr = compact_str::format_compact!("{}\0", expr);
+        let mut value = sys::ecs_value_t {
+            type_: id,
+            ptr: value as *mut core::ffi::c_void,
+        };
+        // SAFETY: the world pointer is valid, `expr` is NUL-terminated and the caller guarantees
+        // that `value.ptr` points to initialized data of type `id`. A null desc uses the defaults.
+        let end = unsafe {
+            sys::ecs_expr_run(
+                world.world_ptr_mut(),
+                expr.as_ptr() as *const _,
+                &mut value,
+                core::ptr::null(),
+            )
+        };
+        // SAFETY: a non-null `end` points into the NUL-terminated `expr`.
+        !end.is_null() && unsafe { *end } == 0
+    }
+
     pub fn get_const_var(world: impl WorldProvider<'a>, name: &str) -> Option<sys::ecs_value_t> {
         let world_ptr = world.world_ptr();
         let name = compact_str::format_compact!("{}\0", name);
@@ -92,6 +92,49 @@ impl World {
         unsafe { Script::to_expr(self, T::id(), value as *const T) }
     }
 
+    /// Deserialize value from an expression.
+    /// This operation parses a string in the flecs expression format, such as the output of
+    /// [`to_expr`](Self::to_expr), into a new value of the provided type.
+    ///
+    /// Members that are not assigned by the expression keep their [`Default`] value.
+    /// The type must have reflection data, for example by deriving it with `#[flecs(meta)]`.
+    ///
+    /// # Returns
+    ///
+    /// The parsed value, or `None` if the expression could not be parsed or assigned to the type,
+    /// or if input is left after the parsed expression.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use flecs_ecs::prelude::*;
+    /// #[derive(Component, Default, Debug, PartialEq)]
+    /// #[flecs(meta)]
+    /// struct Position {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let expr = world.to_expr(&Position { x: 10, y: 20 });
+    /// assert_eq!(expr, "{x: 10, y: 20}");
+    /// assert_eq!(
+    ///     world.from_expr::<Position>(&expr),
+    ///     Some(Position { x: 10, y: 20 })
+    /// );
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_expr_run`
+    pub fn from_expr<T: ComponentId + Default>(&self, expr: &str) -> Option<T> {
+        let mut value = T::default();
+        // SAFETY: `value` is an initialized `T` and `T::id` is the component id of `T`.
+        let ok = unsafe { Script::from_expr(self, T::id(), &mut value as *mut T, expr) };
+        ok.then_some(value)
+    }
+
     /*
 
     template <typename T>
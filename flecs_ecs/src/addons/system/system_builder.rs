@@ -108,16 +108,149 @@ where
         self.kind(enum_id)
     }
 
-    /// Specify whether system should be ran in staged context.
+    /// Specify whether the system runs outside of readonly mode.
+    ///
+    /// By default systems run in readonly mode: structural changes such as
+    /// adding or removing components are enqueued in a stage and only merged at
+    /// the next sync point. An immediate system runs on the real world instead
+    /// of a stage, which also means it can't run on multiple threads.
+    ///
+    /// Operations in an immediate system are still deferred while the system is
+    /// running, but the pipeline inserts a sync point before it so it sees all
+    /// earlier changes, and its own changes are merged as soon as it finishes,
+    /// making them visible to later systems in the same frame. Use
+    /// [`World::defer_suspend`] inside the system to make operations visible
+    /// immediately.
     ///
     /// # Arguments
     ///
-    /// * `value` - If false,  system will always run staged.
+    /// * `value` - If true, the system runs outside of readonly mode. If false,
+    ///   the system always runs staged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Spawned;
+    ///
+    /// let world = World::new();
+    ///
+    /// world
+    ///     .system::<()>()
+    ///     .immediate(true)
+    ///     .run(|mut it| {
+    ///         while it.next() {}
+    ///         it.world().entity().add(Spawned::id());
+    ///     });
+    ///
+    /// world.progress();
+    ///
+    /// assert_eq!(world.count(Spawned::id()), 1);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`SystemBuilder::run_immediate()`]
+    /// * [`System::immediate()`]
+    /// * C API: `ecs_system_desc_t::immediate`
     pub fn immediate(&mut self, value: bool) -> &mut Self {
         self.desc.immediate = value;
         self
     }
 
+    /// Build an immediate system with a run callback.
+    ///
+    /// Shorthand for `.immediate(true).run(func)`. See [`SystemBuilder::immediate()`]
+    /// for what running outside of readonly mode means.
+    ///
+    /// # Arguments
+    ///
+    /// * `func` - The run callback, see [`SystemAPI::run()`].
+    ///
+    /// # See also
+    ///
+    /// * [`SystemBuilder::immediate()`]
+    pub fn run_immediate<Func>(&mut self, func: Func) -> System<'a>
+    where
+        Func: FnMut(TableIter<true, ()>) + 'static,
+    {
+        self.immediate(true);
+        self.run(func)
+    }
+
+    /// Specify whether the system may run on multiple threads.
+    ///
+    /// When [`World::set_threads()`] is set to more than one thread, the entities
+    /// matched by a multi threaded system are split across the worker threads,
+    /// each iterating its own share on its own stage. The `par_*` methods of
+    /// [`ParSystemAPI`] set this for you, and require the callback and the query's
+    /// component types to be `Send`, which is what makes them safe. This toggle
+    /// sets the flag directly, so it can be combined with any callback method.
+    ///
+    /// With the `flecs_safety_locks` feature, each stage gets its own set of
+    /// column lock counters while the pipeline runs multi threaded. Conflicting
+    /// accesses within one worker, such as writing a component the system already
+    /// borrows, still panic. The system's own writes don't overlap because the
+    /// scheduler gives every worker a disjoint share of the matched entities.
+    /// Components borrowed through `get` (on an entity or a singleton) are
+    /// additionally tracked per entity across all workers, so two threads writing
+    /// the same component of the same entity at once, or one writing while
+    /// another reads it, panic. The locks don't make a non-`Sync` callback or its
+    /// captured state safe to share.
+    ///
+    /// # Safety
+    ///
+    /// When `value` is true, the caller must ensure that:
+    /// - The callback set after this call can be invoked from several threads at
+    ///   once: it is `Send` and `Sync`, and doesn't mutate captured state without
+    ///   synchronization.
+    /// - The component types accessed by the query are `Send` (and `Sync` for
+    ///   shared access).
+    /// - Component data outside the entities passed to the callback is not
+    ///   written from more than one worker at a time. Without
+    ///   `flecs_safety_locks` this isn't checked at all.
+    ///
+    /// Setting `value` to false is always sound.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - If true, the system may run on multiple threads.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.set_threads(2);
+    ///
+    /// let mut builder = world.system::<&mut Position>();
+    /// // SAFETY: the callback captures nothing and Position is Send + Sync.
+    /// unsafe { builder.multi_threaded(true) };
+    /// let system = builder.each(|p| p.x += 1.0);
+    ///
+    /// assert!(system.multi_threaded());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`ParSystemAPI::par_each()`]
+    /// * [`System::multi_threaded()`]
+    /// * [`World::set_threads()`]
+    /// * C API: `ecs_system_desc_t::multi_threaded`
+    pub unsafe fn multi_threaded(&mut self, value: bool) -> &mut Self {
+        self.set_multi_threaded(value);
+        self
+    }
+
     /// Attempts to build the system, returning `None` if system creation fails.
     ///
     /// This is the fallible counterpart of [`build()`](Builder::build): it returns
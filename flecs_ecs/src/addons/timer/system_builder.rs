@@ -29,6 +29,30 @@ impl<T: QueryTuple> SystemBuilder<'_, T> {
 
     /// Set tick source.
     /// This operation sets a shared tick source for the system.
+    ///
+    /// The tick source can be any entity that is a tick source, including another
+    /// system with an interval or rate. Systems that share a tick source are
+    /// guaranteed to run on the same frames, which is not the case for systems that
+    /// merely have the same interval.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let spawn = world
+    ///     .system::<()>()
+    ///     .set_interval(0.5)
+    ///     .run(|mut it| while it.next() {});
+    ///
+    /// // runs on exactly the same frames as `spawn`
+    /// world
+    ///     .system::<()>()
+    ///     .set_tick_source(spawn)
+    ///     .run(|mut it| while it.next() {});
+    /// ```
     pub fn set_tick_source(&mut self, tick_source: impl IntoEntity) -> &mut Self {
         self.desc.tick_source = *tick_source.into_entity(self.world());
         self
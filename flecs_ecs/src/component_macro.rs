@@ -177,6 +177,94 @@
 //! - Inherits from prefabs (`OnInstantiate, Inherit`)
 //! - Automatically adds `RequiredTag` (`With, RequiredTag`)
 //!
+//! ### Union Relationships
+//!
+//! A relationship for which an entity can have at most one target at a time, such as a
+//! state machine, can be stored as a union with the `union` attribute:
+//!
+//! ```rust
+//! # use flecs_ecs::prelude::*;
+//! #[derive(Component)]
+//! #[flecs(union)]
+//! struct Movement;
+//! ```
+//!
+//! Flecs implements union storage as a relationship with both the `DontFragment` and `Exclusive`
+//! traits, so `#[flecs(union)]` is shorthand for `#[flecs(traits(DontFragment, Exclusive))]`.
+//! Switching targets doesn't move the entity to a different table, and the current target can be
+//! read with `entity.target(Movement::id(), 0)`. Because the targets are not stored in tables,
+//! union relationships come with the limitations of [`flecs::DontFragment`](crate::core::flecs::DontFragment),
+//! such as cached queries not being able to match tables by a specific target.
+//!
+//! ### Toggleable Components
+//!
+//! A component that should be switched on and off without being removed can be marked with the
+//! `can_toggle` attribute, which is shorthand for `#[flecs(traits(CanToggle))]`:
+//!
+//! ```rust
+//! # use flecs_ecs::prelude::*;
+//! #[derive(Component)]
+//! #[flecs(can_toggle)]
+//! struct Shield;
+//!
+//! let world = World::new();
+//! let e = world.entity().add(Shield::id());
+//!
+//! e.toggle(Shield::id(), false);
+//! assert!(e.has(Shield::id()));
+//! assert!(!e.is_enabled(Shield::id()));
+//! ```
+//!
+//! Toggling doesn't move the entity to a different table. Queries skip entities for which a
+//! queried component is toggled off; to visit them anyway, make the term optional and check
+//! [`is_enabled`](crate::core::EntityView::is_enabled) in the callback.
+//!
+//! ### Symmetric Relationships
+//!
+//! Relationships that only make sense in both directions can be marked with the `symmetric`
+//! attribute, which is shorthand for `#[flecs(traits(Symmetric))]`. Adding `(Friend, b)` to `a`
+//! then also adds `(Friend, a)` to `b`, and removing it removes both:
+//!
+//! ```rust
+//! # use flecs_ecs::prelude::*;
+//! #[derive(Component)]
+//! #[flecs(symmetric)]
+//! struct Friend;
+//!
+//! let world = World::new();
+//! let a = world.entity();
+//! let b = world.entity().add((Friend, a));
+//!
+//! assert!(a.has((Friend, b)));
+//! ```
+//!
+//! ### Prefab Instantiation
+//!
+//! What happens to a prefab's component when an instance is created is controlled by the
+//! [`flecs::OnInstantiate`](crate::core::flecs::OnInstantiate) trait. The `override`, `inherit`
+//! and `dont_inherit` attributes are shorthand for the three policies:
+//!
+//! - `#[flecs(override)]` - The instance gets its own copy, which requires `Clone` (the default)
+//! - `#[flecs(inherit)]` - The instance shares the prefab's value
+//! - `#[flecs(dont_inherit)]` - The instance does not get the component
+//!
+//! ```rust
+//! # use flecs_ecs::prelude::*;
+//! #[derive(Component)]
+//! #[flecs(inherit)]
+//! struct MaxSpeed(f32);
+//!
+//! let world = World::new();
+//! let prefab = world.prefab().set(MaxSpeed(10.0));
+//! let instance = world.entity().is_a(prefab);
+//!
+//! assert!(instance.has(MaxSpeed::id()));
+//! assert!(!instance.owns(MaxSpeed::id()));
+//! ```
+//!
+//! The same policies can be set at runtime with
+//! `world.component::<MaxSpeed>().add_trait::<(flecs::OnInstantiate, flecs::Inherit)>()`.
+//!
 //! ### Using Qualified Trait Names
 //!
 //! Traits can be specified with full paths for clarity:
@@ -221,6 +309,39 @@
 //! assert_eq!(c.name(), "CustomComponentName");
 //! ```
 //!
+//! ## Component Scope
+//!
+//! The `scope` attribute makes the component entity a child of another entity type when the
+//! component is registered, for example to place it inside a module:
+//!
+//! ```rust
+//! # use flecs_ecs::prelude::*;
+//! #[derive(Component)]
+//! struct PhysicsModule;
+//!
+//! impl Module for PhysicsModule {
+//!     fn module(world: &World) {
+//!         world.module::<PhysicsModule>("physics");
+//!     }
+//! }
+//!
+//! #[derive(Component)]
+//! #[flecs(scope = PhysicsModule)]
+//! struct Gravity {
+//!     value: f32,
+//! }
+//!
+//! let world = World::new();
+//! world.import::<PhysicsModule>();
+//!
+//! let c = world.component::<Gravity>();
+//! assert_eq!(c.path().unwrap(), "::physics::Gravity");
+//! ```
+//!
+//! The scope type is registered on demand, so the component can be used before the module is
+//! imported; importing the module later moves the scope (and with it the component) to the
+//! module's path.
+//!
 //! ## Meta Information
 //!
 //! The `meta` attribute enables Flecs reflection system for your component. This allows runtime inspection of component structure:
@@ -278,6 +399,32 @@
 //! }
 //! ```
 //!
+//! ### Bitmask Types
+//!
+//! Flag-style components are reflected as bitmasks with the `bitmask` attribute, so that
+//! serializers print the names of the set flags instead of a number. Flags are listed as
+//! `Name = value` and registered as `(Constant, u32)` constants:
+//!
+//! ```rust
+//! # use flecs_ecs::prelude::*;
+//! #[derive(Component, Default)]
+//! #[repr(C)]
+//! #[flecs(bitmask(Read = 0x1, Write = 0x2, Execute = 0x4))]
+//! struct Permissions {
+//!     bits: u32,
+//! }
+//!
+//! let world = World::new();
+//! let json = world.to_json::<Permissions>(&Permissions { bits: 0x3 });
+//! assert_eq!(json, "\"Write|Read\"");
+//! ```
+//!
+//! The component must be `#[repr(C)]` or `#[repr(transparent)]` with the size and alignment of a
+//! `u32`, which is checked at compile time. A bare `#[flecs(bitmask)]` only marks the type
+//! as a bitmask, in which case the flags can be added at runtime with
+//! [`bit`](crate::core::UntypedComponent::bit). `bitmask` replaces `meta` and can't be combined
+//! with it.
+//!
 //! ## Adding Components
 //!
 //! The `add(...)` attribute automatically adds other components or pairs when this component is registered:
@@ -384,6 +531,34 @@
 //! struct MyComponent;
 //! ```
 //!
+//! ## Custom Default Value
+//!
+//! Components that are added without a value, such as with `add(T::id())`, are constructed
+//! with [`Default`]. The `default = expr` option constructs them with `expr` instead, which is
+//! useful for components that shouldn't implement `Default`, or generic components whose
+//! parameters don't:
+//!
+//! ```rust
+//! # use flecs_ecs::prelude::*;
+//! #[derive(Component)]
+//! #[flecs(default = Health::full())]
+//! struct Health {
+//!     value: u32,
+//! }
+//!
+//! impl Health {
+//!     fn full() -> Self {
+//!         Health { value: 100 }
+//!     }
+//! }
+//!
+//! let world = World::new();
+//! let entity = world.entity().add(Health::id());
+//! assert_eq!(entity.get::<&Health>(|h| h.value), 100);
+//! ```
+//!
+//! `expr` is evaluated each time a value is constructed. It is not supported on `#[repr(C)]` enums.
+//!
 //! ## Component Hooks
 //!
 //! Hooks allow you to execute custom code during component lifecycle events. They are specified using the `hooks(...)` attribute.
@@ -6,6 +6,7 @@ use core::{
 use crate::core::*;
 use crate::sys;
 
+use flecs_ecs_derive::tuples;
 use hashbrown::hash_map::Entry;
 
 /// A marker trait to indicate that a component represents a trait
@@ -595,6 +596,27 @@ pub trait ComponentId: Sized + ComponentInfo + 'static + OnComponentRegistration
     fn index() -> u32;
 }
 
+/// A tuple of component types that can be registered together with
+/// [`World::register_components()`].
+pub trait ComponentTuple {
+    // Not public API.
+    #[doc(hidden)]
+    fn __register_components(world: &World);
+}
+
+macro_rules! impl_component_tuple {
+    ($($t:ident),*) => {
+        impl<$($t: ComponentId),*> ComponentTuple for ($($t,)*) {
+            #[allow(unused_variables)]
+            fn __register_components(world: &World) {
+                $(world.component::<$t>();)*
+            }
+        }
+    }
+}
+
+tuples!(impl_component_tuple, 0, 32);
+
 /// `OnInstantiate` policy a component can declare at compile time via
 /// `#[flecs(traits((OnInstantiate, ...)))]`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -24,7 +24,7 @@ pub(crate) struct ComponentBindingCtx {
 
 impl Drop for ComponentBindingCtx {
     fn drop(&mut self) {
-        if std::thread::panicking() {
+        if crate::core::utility::std_compat::thread_panicking() {
             return;
         }
 
@@ -110,6 +110,38 @@ impl<'a> UntypedComponent<'a> {
     pub fn as_entity(&self) -> EntityView<'a> {
         self.entity
     }
+
+    fn component_info(&self) -> Option<&crate::sys::EcsComponent> {
+        // SAFETY: the world pointer is valid for 'a; the returned pointer is either null
+        // or points to the component's `EcsComponent` data.
+        unsafe {
+            (crate::sys::ecs_get_id(
+                self.entity.world_ptr(),
+                *self.entity.id(),
+                flecs::Component::ID,
+            ) as *const crate::sys::EcsComponent)
+                .as_ref()
+        }
+    }
+
+    /// Get the size of the component in bytes.
+    ///
+    /// Returns 0 for tags and for entities that are not components.
+    pub fn size(&self) -> usize {
+        self.component_info().map_or(0, |c| c.size as usize)
+    }
+
+    /// Get the alignment of the component in bytes.
+    ///
+    /// Returns 0 for tags and for entities that are not components.
+    pub fn alignment(&self) -> usize {
+        self.component_info().map_or(0, |c| c.alignment as usize)
+    }
+
+    /// Returns true if the component has no data.
+    pub fn is_tag(&self) -> bool {
+        self.size() == 0
+    }
 }
 
 #[cfg(feature = "flecs_meta")]
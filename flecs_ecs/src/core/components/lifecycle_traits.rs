@@ -65,19 +65,27 @@ use flecs_ecs_derive::extern_abi;
 /// behavior once that memory is later read or dropped. Aborting instead keeps
 /// the invariant that a hook either completes for the whole range or the
 /// process ends.
-#[allow(clippy::print_stderr, reason = "last words before process abort")]
+///
+/// Without `std` a panic can't be caught, so the hook runs as is. `no_std`
+/// targets are expected to abort on panic.
 fn abort_on_hook_panic<R>(hook_kind: &str, type_name: &str, f: impl FnOnce() -> R) -> R {
-    match std::panic::catch_unwind(core::panic::AssertUnwindSafe(f)) {
-        Ok(val) => val,
-        Err(_) => {
-            std::eprintln!(
+    #[cfg(feature = "std")]
+    {
+        match std::panic::catch_unwind(core::panic::AssertUnwindSafe(f)) {
+            Ok(val) => val,
+            Err(_) => crate::core::utility::std_compat::abort(format_args!(
                 "flecs_ecs: panic in `{hook_kind}` lifecycle hook of component `{type_name}`; \
                  aborting: unwinding out of a lifecycle hook would leave component storage in an \
                  undefined state"
-            );
-            std::process::abort()
+            )),
         }
     }
+
+    #[cfg(not(feature = "std"))]
+    {
+        let _ = (hook_kind, type_name);
+        f()
+    }
 }
 
 #[expect(dead_code, reason = "possibly used in the future")]
@@ -112,6 +120,21 @@ pub fn register_ctor_lifecycle_actions<T: Default>(type_hooks: &mut sys::ecs_typ
     // }
 }
 
+/// Value used by the ctor hook of components with `#[flecs(default = expr)]`.
+///
+/// Implemented by the `Component` derive, in place of [`Default`].
+pub trait CustomDefault {
+    // Not public API.
+    #[doc(hidden)]
+    fn __custom_default() -> Self;
+}
+
+pub fn register_custom_ctor_lifecycle_actions<T: CustomDefault>(
+    type_hooks: &mut sys::ecs_type_hooks_t,
+) {
+    type_hooks.ctor = Some(custom_ctor::<T>);
+}
+
 pub fn register_ctor_panic_lifecycle_actions<T>(type_hooks: &mut sys::ecs_type_hooks_t) {
     type_hooks.ctor = Some(panic_ctor::<T>);
 }
@@ -177,6 +200,39 @@ fn ctor<T: Default>(ptr: *mut c_void, count: i32, _type_info: *const sys::ecs_ty
     });
 }
 
+/// Initialize the memory with the value of [`CustomDefault`].
+///
+/// # Arguments
+///
+/// * `ptr` - pointer to the memory to be initialized
+/// * `count` - number of elements to be initialized
+/// * `_type_info` - type info for the type to be initialized
+#[extern_abi]
+fn custom_ctor<T: CustomDefault>(
+    ptr: *mut c_void,
+    count: i32,
+    _type_info: *const sys::ecs_type_info_t,
+) {
+    let size = const { core::mem::size_of::<T>() };
+    if size == 0 {
+        return;
+    }
+
+    ecs_assert!(
+        check_type_info::<T>(_type_info),
+        FlecsErrorCode::InternalError
+    );
+
+    let arr = ptr as *mut MaybeUninit<T>;
+    abort_on_hook_panic("ctor", core::any::type_name::<T>(), || {
+        for i in 0..count as usize {
+            unsafe {
+                MaybeUninit::write(&mut *arr.add(i), T::__custom_default());
+            }
+        }
+    });
+}
+
 /// Runs the destructor for the type.
 ///
 /// # Arguments
@@ -378,6 +434,7 @@ fn move_ctor<T>(
         }
     }
 }
+#[cfg(feature = "std")]
 pub static INITIALIZED_BOXES: std::sync::LazyLock<
     std::sync::Mutex<std::collections::HashSet<String>>,
 > = std::sync::LazyLock::new(|| std::sync::Mutex::new(std::collections::HashSet::new()));
@@ -6,6 +6,7 @@ mod component_binding;
 mod component_untyped;
 #[doc(hidden)]
 pub mod lifecycle_traits;
+mod type_info;
 
 pub use cached_ref::*;
 pub use component::*;
@@ -13,3 +14,4 @@ pub(crate) use component_binding::*;
 pub use component_untyped::*;
 #[doc(hidden)]
 pub use lifecycle_traits::*;
+pub use type_info::*;
@@ -0,0 +1,41 @@
+use crate::core::*;
+use crate::sys;
+
+/// Runtime layout and lifecycle information of a component.
+///
+/// Wraps the data of `ecs_type_info_t`. Obtained through [`World::type_info()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypeInfo {
+    /// The component the type info belongs to.
+    pub component: Entity,
+    /// Size of the component in bytes.
+    pub size: usize,
+    /// Alignment of the component in bytes.
+    pub alignment: usize,
+    /// Whether a constructor hook is registered.
+    pub has_ctor: bool,
+    /// Whether a destructor hook is registered.
+    pub has_dtor: bool,
+    /// Whether a copy hook is registered.
+    pub has_copy: bool,
+    /// Whether any move hook (`move`, `move_ctor`, `move_dtor` or `ctor_move_dtor`) is registered.
+    pub has_move: bool,
+}
+
+impl TypeInfo {
+    pub(crate) fn from_raw(ti: &sys::ecs_type_info_t) -> Self {
+        let hooks = &ti.hooks;
+        TypeInfo {
+            component: Entity(ti.component),
+            size: ti.size as usize,
+            alignment: ti.alignment as usize,
+            has_ctor: hooks.ctor.is_some(),
+            has_dtor: hooks.dtor.is_some(),
+            has_copy: hooks.copy.is_some(),
+            has_move: hooks.move_.is_some()
+                || hooks.move_ctor.is_some()
+                || hooks.move_dtor.is_some()
+                || hooks.ctor_move_dtor.is_some(),
+        }
+    }
+}
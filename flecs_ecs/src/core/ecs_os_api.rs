@@ -10,8 +10,7 @@ extern crate std;
 extern crate alloc;
 use alloc::{boxed::Box, vec::Vec};
 
-use std::sync::LazyLock;
-use std::sync::Mutex;
+use crate::core::utility::std_compat::Mutex;
 
 struct OsApiHook(Box<dyn FnOnce(&mut flecs_ecs::sys::ecs_os_api_t) + Send>);
 
@@ -19,8 +18,7 @@ struct OsApiHook(Box<dyn FnOnce(&mut flecs_ecs::sys::ecs_os_api_t) + Send>);
 ///
 /// Run automatically, once and only once, when the first [`super::World`]
 /// is created, or [`ensure_initialized`] is called directly.
-static OS_API_HOOKS: LazyLock<Mutex<Option<Vec<OsApiHook>>>> =
-    LazyLock::new(|| Mutex::new(Some(Default::default())));
+static OS_API_HOOKS: Mutex<Option<Vec<OsApiHook>>> = Mutex::new(Some(Vec::new()));
 
 /// Initialize the Flecs OS API if not initialized already.
 ///
@@ -5,6 +5,7 @@ use core::ops::Deref;
 use core::ops::{BitAnd, BitOr};
 
 use crate::core::*;
+use crate::sys;
 
 /// An identifier that represents an entity.
 ///
@@ -30,6 +31,61 @@ impl Entity {
         Self(0)
     }
 
+    /// Creates an entity id from its index and generation.
+    ///
+    /// This is the inverse of [`Entity::index()`] and [`Entity::generation()`], and can be used
+    /// to rebuild an id that was serialized as its parts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `generation` does not fit in the 16 generation bits of an entity id.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use flecs_ecs::prelude::*;
+    /// let e = Entity::from_raw_parts(600, 2);
+    ///
+    /// assert_eq!(e.index(), 600);
+    /// assert_eq!(e.generation(), 2);
+    /// assert_eq!(*e, (2 << 32) | 600);
+    /// ```
+    #[inline(always)]
+    pub fn from_raw_parts(index: u32, generation: u32) -> Self {
+        let generation = (generation as u64) << 32;
+        assert!(
+            generation & !sys::ECS_GENERATION_MASK == 0,
+            "generation does not fit in the generation bits of an entity id"
+        );
+        Self(generation | index as u64)
+    }
+
+    /// Returns the index of the entity id, which is the id without its generation.
+    ///
+    /// # See also
+    ///
+    /// * [`Entity::generation()`]
+    /// * [`Entity::from_raw_parts()`]
+    #[inline(always)]
+    pub fn index(&self) -> u32 {
+        (self.0 & sys::ECS_ENTITY_MASK as u64) as u32
+    }
+
+    /// Returns the generation of the entity id.
+    ///
+    /// The generation is increased each time the index of a deleted entity is recycled, so a
+    /// stale id can be told apart from the entity currently using its index.
+    ///
+    /// # See also
+    ///
+    /// * [`Entity::index()`]
+    /// * [`Entity::from_raw_parts()`]
+    /// * [`World::get_alive()`]
+    #[inline(always)]
+    pub fn generation(&self) -> u32 {
+        get_generation(*self)
+    }
+
     /// Returns `true` if the id is not the null (0) id.
     ///
     /// This is a pure numeric check: `Entity` carries no world reference, so this
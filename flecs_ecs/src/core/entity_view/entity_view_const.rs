@@ -517,6 +517,45 @@ impl<'a> EntityView<'a> {
         unsafe { sys::ecs_is_alive(self.world.world_ptr(), *self.id) }
     }
 
+    /// Returns the raw entity id, including its generation.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::generation()`]
+    #[inline(always)]
+    pub fn raw_id(self) -> u64 {
+        *self.id
+    }
+
+    /// Returns the generation of the entity.
+    ///
+    /// The generation is stored in the upper bits of the id and is increased each time the
+    /// index of a deleted entity is recycled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use flecs_ecs::prelude::*;
+    /// let world = World::new();
+    ///
+    /// let e = world.entity();
+    /// assert_eq!(e.generation(), 0);
+    ///
+    /// e.destruct();
+    /// let recycled = world.entity();
+    /// assert_eq!(recycled.id().index(), e.id().index());
+    /// assert_eq!(recycled.generation(), 1);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Entity::generation()`]
+    /// * [`EntityView::raw_id()`]
+    #[inline(always)]
+    pub fn generation(self) -> u32 {
+        self.id.generation()
+    }
+
     /// Returns the entity name.
     ///
     /// Returns the name of the entity if one was assigned, or an empty string if
@@ -931,6 +970,10 @@ impl<'a> EntityView<'a> {
 
     /// Iterate over component ids of an entity.
     ///
+    /// Visits every id in the entity's type, in type order: components, tags and
+    /// pairs alike. This is the same set of ids as [`EntityView::archetype()`],
+    /// and [`EntityView::type_count()`] returns how many there are.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -963,6 +1006,8 @@ impl<'a> EntityView<'a> {
     /// * [`EntityView::each_pair()`] - Iterate over pairs
     /// * [`EntityView::each_target()`] - Iterate over relationship targets
     /// * [`EntityView::archetype()`] - Get entity's archetype
+    /// * [`EntityView::type_count()`] - Get number of ids in entity's type
+    /// * [`EntityView::each_id()`] - Iterate over all ids
     pub fn each_component(self, mut func: impl FnMut(IdView)) {
         let archetype = self.archetype();
 
@@ -972,6 +1017,85 @@ impl<'a> EntityView<'a> {
         }
     }
 
+    /// Iterate over all ids of an entity: components, tags and pairs.
+    ///
+    /// Same walk as [`EntityView::each_component()`], named for generic entity dumps
+    /// that don't care whether an id carries data.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use flecs_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// struct Likes;
+    ///
+    /// let world = World::new();
+    /// let apple = world.entity();
+    /// let entity = world.entity().add(Likes).add((Likes, apple));
+    ///
+    /// let mut count = 0;
+    /// entity.each_id(|id| {
+    ///     println!("Id: {}", id.to_str());
+    ///     count += 1;
+    /// });
+    /// assert_eq!(count, entity.type_count());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::each_component()`] - Iterate over component ids
+    /// * [`EntityView::type_count()`] - Get number of ids in entity's type
+    #[inline(always)]
+    pub fn each_id(self, func: impl FnMut(IdView)) {
+        self.each_component(func);
+    }
+
+    /// Get the number of ids in the entity's type.
+    ///
+    /// Counts components, tags and pairs, which is the number of ids
+    /// [`EntityView::each_id()`] visits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use flecs_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Likes;
+    ///
+    /// let world = World::new();
+    /// let apple = world.entity();
+    ///
+    /// let entity = world
+    ///     .entity()
+    ///     .set(Position { x: 0.0, y: 0.0 })
+    ///     .add(Likes)
+    ///     .add((Likes, apple));
+    ///
+    /// assert_eq!(entity.type_count(), 3);
+    /// assert_eq!(world.entity().type_count(), 0);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The number of ids in the entity's type, or 0 if the entity is not stored
+    /// in a table.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::each_component()`] - Iterate over all ids
+    /// * [`EntityView::archetype()`] - Get entity's archetype
+    /// * [`Archetype::count()`] - Get number of ids in archetype
+    #[inline(always)]
+    pub fn type_count(self) -> usize {
+        self.archetype().count()
+    }
+
     /// Iterates over matching pair IDs of an entity.
     ///
     /// # Examples
@@ -1074,6 +1198,44 @@ impl<'a> EntityView<'a> {
         });
     }
 
+    /// Get all targets for a given relationship.
+    ///
+    /// Collects the targets returned by [`EntityView::target()`] for increasing indices,
+    /// in the order the pairs appear in the entity's type. The type is sorted by id, so
+    /// targets come back ordered by target id, not in the order they were added.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use flecs_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// struct Likes;
+    ///
+    /// let world = World::new();
+    /// let apple = world.entity_named("Apple");
+    /// let banana = world.entity_named("Banana");
+    ///
+    /// let entity = world.entity().add((Likes, banana)).add((Likes, apple));
+    ///
+    /// // ordered by target id: `apple` was created first
+    /// assert_eq!(entity.targets(Likes), [apple, banana]);
+    /// assert!(apple.targets(Likes).is_empty());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::each_target()`] - Iterate over targets
+    /// * [`EntityView::target()`] - Get a single target
+    /// * [`EntityView::target_count()`] - Get number of targets
+    pub fn targets(self, relationship: impl IntoEntity) -> Vec<EntityView<'a>> {
+        let relationship = *relationship.into_entity(self.world);
+        let mut targets = Vec::new();
+        while let Some(target) = self.target(relationship, targets.len() as i32) {
+            targets.push(target);
+        }
+        targets
+    }
+
     /// Get the count of targets for a given relationship.
     ///
     /// Returns the number of entities that are targets of the specified relationship.
@@ -1441,6 +1603,169 @@ pub trait EntityViewGet<'a, Return>: WorldProvider<'a> + Sized {
     /// });
     /// ```
     fn get<T: GetTuple>(self, callback: impl for<'e> FnOnce(T::TupleType<'e>) -> Return) -> Return;
+
+    /// Get the value of a pair where the data is stored in the first element, such
+    /// as `(Data, Target)`.
+    ///
+    /// This is the same as `get::<&(First, Second)>`, but fails to compile when
+    /// `First` is not the type of the pair value. With [`EntityViewGet::get`] the
+    /// pair value is of type `First` whenever `First` is not a tag, which is easy
+    /// to get wrong when both elements are components.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `First` - The first element of the pair, which holds the data.
+    /// * `Second` - The second element of the pair.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the entity does not have the pair or is not alive. See
+    /// [`EntityViewGet::try_get_first()`] for a fallible variant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Amount {
+    ///     value: i32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Apples;
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world
+    ///     .entity()
+    ///     .set_pair::<Amount, Apples>(Amount { value: 3 });
+    ///
+    /// let value = e.get_first::<Amount, Apples>(|amount| amount.value);
+    /// assert_eq!(value, 3);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityViewGet::get_second()`]
+    /// * [`EntityViewGet::try_get_first()`]
+    fn get_first<First, Second>(self, callback: impl FnOnce(&First) -> Return) -> Return
+    where
+        First: ComponentId + DataComponent,
+        Second: ComponentId,
+        (First, Second): ComponentOrPairId<CastType = First> + DataComponent,
+    {
+        self.get::<&(First, Second)>(callback)
+    }
+
+    /// Get the value of a pair where the data is stored in the first element, if
+    /// the entity has the pair.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `First` - The first element of the pair, which holds the data.
+    /// * `Second` - The second element of the pair.
+    ///
+    /// # Returns
+    ///
+    /// The return value of the callback, or `None` if the entity does not have
+    /// the pair, in which case the callback is not run.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityViewGet::get_first()`]
+    /// * [`EntityViewGet::try_get_second()`]
+    fn try_get_first<First, Second>(self, callback: impl FnOnce(&First) -> Return) -> Option<Return>
+    where
+        First: ComponentId + DataComponent,
+        Second: ComponentId,
+        (First, Second): ComponentOrPairId<CastType = First> + DataComponent,
+    {
+        self.try_get::<&(First, Second)>(callback)
+    }
+
+    /// Get the value of a pair where the data is stored in the second element, such
+    /// as `(Relationship, Data)`.
+    ///
+    /// This is the same as `get::<&(First, Second)>`, but fails to compile when
+    /// `Second` is not the type of the pair value. The pair value is only of type
+    /// `Second` when `First` is a tag.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `First` - The first element of the pair, which must be a tag.
+    /// * `Second` - The second element of the pair, which holds the data.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the entity does not have the pair or is not alive. See
+    /// [`EntityViewGet::try_get_second()`] for a fallible variant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Begin;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world
+    ///     .entity()
+    ///     .set_pair::<Begin, Position>(Position { x: 1.0, y: 2.0 });
+    ///
+    /// let x = e.get_second::<Begin, Position>(|pos| pos.x);
+    /// assert_eq!(x, 1.0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityViewGet::get_first()`]
+    /// * [`EntityViewGet::try_get_second()`]
+    fn get_second<First, Second>(self, callback: impl FnOnce(&Second) -> Return) -> Return
+    where
+        First: ComponentId,
+        Second: ComponentId + DataComponent,
+        (First, Second): ComponentOrPairId<CastType = Second> + DataComponent,
+    {
+        self.get::<&(First, Second)>(callback)
+    }
+
+    /// Get the value of a pair where the data is stored in the second element, if
+    /// the entity has the pair.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `First` - The first element of the pair, which must be a tag.
+    /// * `Second` - The second element of the pair, which holds the data.
+    ///
+    /// # Returns
+    ///
+    /// The return value of the callback, or `None` if the entity does not have
+    /// the pair, in which case the callback is not run.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityViewGet::get_second()`]
+    /// * [`EntityViewGet::try_get_first()`]
+    fn try_get_second<First, Second>(
+        self,
+        callback: impl FnOnce(&Second) -> Return,
+    ) -> Option<Return>
+    where
+        First: ComponentId,
+        Second: ComponentId + DataComponent,
+        (First, Second): ComponentOrPairId<CastType = Second> + DataComponent,
+    {
+        self.try_get::<&(First, Second)>(callback)
+    }
 }
 
 impl<'a, Return> EntityViewGet<'a, Return> for EntityView<'a> {
@@ -1725,6 +2050,9 @@ impl<'a> EntityView<'a> {
 
     /// Get component value or pair as untyped pointer. This is not borrow checked as it's a ptr return.
     ///
+    /// Together with [`World::type_info()`], which provides the size and alignment of the
+    /// component, this can be used to read components by id without knowing their Rust type.
+    ///
     /// # Arguments
     ///
     /// * `component_id` - The component to get
@@ -1737,6 +2065,38 @@ impl<'a> EntityView<'a> {
     ///
     /// Ensure the pointer is valid before use. The caller must know the actual type to cast the pointer correctly.
     /// The pointer might get invalided if the table alters.
+    ///
+    /// The pointer doesn't take part in the component locks of the `flecs_safety_locks` feature,
+    /// so reads through it are not checked against mutable access from queries or typed gets.
+    /// Don't read through it while the same component is mutably borrowed elsewhere.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use flecs_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// let e = world.entity().set(Position { x: 1.0, y: 2.0 });
+    ///
+    /// let size = world.type_info(Position::id()).unwrap().size;
+    /// let ptr = e.get_untyped(Position::id());
+    /// assert!(!ptr.is_null());
+    ///
+    /// // SAFETY: the pointer points to a live `Position` of `size` bytes.
+    /// let bytes = unsafe { core::slice::from_raw_parts(ptr as *const u8, size) };
+    /// assert_eq!(bytes.len(), core::mem::size_of::<Position>());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::get_untyped_mut()`]
+    /// * [`World::type_info()`]
+    /// * C API: `ecs_get_id`
     pub fn get_untyped(self, component_id: impl IntoId) -> *const c_void {
         unsafe {
             sys::ecs_get_id(
@@ -1825,6 +2185,15 @@ impl<'a> EntityView<'a> {
     ///
     /// Ensure the pointer is valid before use. The caller must know the actual type to cast the pointer correctly.
     /// The pointer might get invalided if the table alters.
+    ///
+    /// The pointer doesn't take part in the component locks of the `flecs_safety_locks` feature.
+    /// Writing through it while the component is borrowed elsewhere, for example by a query or
+    /// a typed get, is undefined behavior.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::get_untyped()`]
+    /// * C API: `ecs_get_mut_id`
     pub fn get_untyped_mut(self, id: impl IntoId) -> *mut c_void {
         unsafe { sys::ecs_get_mut_id(self.world.world_ptr(), *self.id(), *id.into_id(self)) }
     }
@@ -1983,7 +2352,25 @@ impl<'a> EntityView<'a> {
     ///
     /// # Returns
     ///
-    /// * The parent of the entity.
+    /// * The parent of the entity, or `None` if the entity is a root entity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use flecs_ecs::prelude::*;
+    /// let world = World::new();
+    ///
+    /// let parent = world.entity();
+    /// let child = world.entity().child_of(parent);
+    ///
+    /// assert_eq!(child.parent(), Some(parent));
+    /// assert_eq!(parent.parent(), None);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::has_parent()`]
+    /// * C API: `ecs_get_parent`
     #[inline(always)]
     pub fn parent(self) -> Option<EntityView<'a>> {
         let id = unsafe { sys::ecs_get_parent(self.world.world_ptr(), *self.id) };
@@ -1994,6 +2381,20 @@ impl<'a> EntityView<'a> {
         }
     }
 
+    /// Test if the entity has a parent.
+    ///
+    /// # Returns
+    ///
+    /// * `true` if the entity is a child of another entity, `false` if it is a root entity.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::parent()`]
+    #[inline(always)]
+    pub fn has_parent(self) -> bool {
+        self.parent().is_some()
+    }
+
     /// Lookup an entity by name.
     ///
     /// Lookup an entity in the scope of this entity. The provided path may
@@ -2039,7 +2440,27 @@ impl<'a> EntityView<'a> {
     /// up the tree until found.
     ///
     /// The provided path may contain double colons as scope separators,
-    /// for example: "`Foo::Bar`".
+    /// for example: "`Foo::Bar`". Because the lookup starts in the scope of this
+    /// entity and then moves up to its parents, this can be used to find siblings
+    /// of the entity by name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let ship = world.entity_named("Ship");
+    /// let engine = world.entity_named("Engine").child_of(ship);
+    /// let cockpit = world.entity_named("Cockpit").child_of(ship);
+    /// let pilot = world.entity_named("Pilot").child_of(cockpit);
+    ///
+    /// // siblings and their children are found through the parent's scope
+    /// assert_eq!(engine.try_lookup_recursive("Cockpit"), Some(cockpit));
+    /// assert_eq!(engine.try_lookup_recursive("Cockpit::Pilot"), Some(pilot));
+    /// assert!(engine.try_lookup("Cockpit").is_none());
+    /// ```
     ///
     /// # Arguments
     ///
@@ -2048,8 +2469,13 @@ impl<'a> EntityView<'a> {
     /// # Returns
     ///
     /// The entity if found, otherwise `None`.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::try_lookup()`]
+    /// * C API: `ecs_lookup_path_w_sep`
     #[inline(always)]
-    pub fn try_lookup_recursive(&self, name: &str) -> Option<EntityView<'_>> {
+    pub fn try_lookup_recursive(&self, name: &str) -> Option<EntityView<'a>> {
         self.try_lookup_impl(name, true)
     }
 
@@ -2058,6 +2484,22 @@ impl<'a> EntityView<'a> {
     /// Lookup an entity in the scope of this entity. The provided path may
     /// contain double colons as scope separators, for example: "`Foo::Bar`".
     ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let ship = world.entity_named("Ship");
+    /// let cockpit = world.entity_named("Cockpit").child_of(ship);
+    /// let pilot = world.entity_named("Pilot").child_of(cockpit);
+    ///
+    /// assert_eq!(ship.try_lookup("Cockpit"), Some(cockpit));
+    /// assert_eq!(ship.try_lookup("Cockpit::Pilot"), Some(pilot));
+    /// assert!(ship.try_lookup("Pilot").is_none());
+    /// ```
+    ///
     /// # Arguments
     ///
     /// * `name` - The name of the entity to lookup.
@@ -2065,8 +2507,13 @@ impl<'a> EntityView<'a> {
     /// # Returns
     ///
     /// The entity if found, otherwise `None`.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::try_lookup_recursive()`]
+    /// * C API: `ecs_lookup_path_w_sep`
     #[inline(always)]
-    pub fn try_lookup(&self, name: &str) -> Option<EntityView<'_>> {
+    pub fn try_lookup(&self, name: &str) -> Option<EntityView<'a>> {
         self.try_lookup_impl(name, false)
     }
 
@@ -2086,7 +2533,7 @@ impl<'a> EntityView<'a> {
     ///
     /// The entity, or entity with id 0 if not found.
     #[inline(always)]
-    pub fn lookup_recursive(&self, name: &str) -> EntityView<'_> {
+    pub fn lookup_recursive(&self, name: &str) -> EntityView<'a> {
         self.try_lookup_recursive(name)
             .unwrap_or_else(|| EntityView::new_from(self.world, Entity(0)))
     }
@@ -2106,7 +2553,7 @@ impl<'a> EntityView<'a> {
     ///
     /// The entity, or entity with id 0 if not found. Use [`try_lookup`](Self::try_lookup) for an `Option` return.
     #[inline(always)]
-    pub fn lookup(&self, name: &str) -> EntityView<'_> {
+    pub fn lookup(&self, name: &str) -> EntityView<'a> {
         self.try_lookup(name)
             .unwrap_or_else(|| EntityView::new_from(self.world, Entity(0)))
     }
@@ -2189,6 +2636,63 @@ impl<'a> EntityView<'a> {
         self.has((component_id, enum_constant_entity_id))
     }
 
+    /// Get the enum constant used as the target of a relationship.
+    ///
+    /// This is the counterpart of [`EntityView::add_pair_enum()`]: it looks up the first
+    /// target of the `First` relationship and converts it to an `E` constant.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `First` - The relationship.
+    /// * `E` - The enum type of the target.
+    ///
+    /// # Returns
+    ///
+    /// The enum constant, or `None` if the entity doesn't have the relationship with a
+    /// constant of enum `E` as target. Targets that are not constants of `E` are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use flecs_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// #[flecs(traits(Exclusive))]
+    /// struct State;
+    ///
+    /// #[derive(Component, Debug, PartialEq)]
+    /// #[repr(C)]
+    /// enum Movement {
+    ///     Idle,
+    ///     Running,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world.entity();
+    /// assert_eq!(e.get_enum_target::<State, Movement>(), None);
+    ///
+    /// e.add_pair_enum::<State, _>(Movement::Running);
+    /// assert_eq!(e.get_enum_target::<State, Movement>(), Some(Movement::Running));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::add_pair_enum()`]
+    /// * [`EntityView::has_pair_enum()`]
+    /// * [`EntityView::to_constant()`]
+    pub fn get_enum_target<First, E>(self) -> Option<E>
+    where
+        First: ComponentId,
+        E: ComponentId + ComponentType<Enum> + EnumComponentInfo,
+    {
+        let first = First::entity_id(self.world);
+        let enum_id = E::entity_id(self.world);
+        (0..)
+            .map_while(|index| self.target(first, index))
+            .find(|target| target.parent().is_some_and(|parent| parent.id() == enum_id))
+            .map(|target| target.to_constant::<E>())
+    }
+
     /*
 
         inline E entity_view::to_constant() const {
@@ -2269,6 +2773,10 @@ impl<'a> EntityView<'a> {
 
     /// Test if id is enabled.
     ///
+    /// Returns `false` only for [`flecs::CanToggle`] components that were toggled off with
+    /// [`EntityView::toggle()`] or [`EntityView::disable()`]. A toggled off component is still
+    /// owned by the entity, so [`EntityView::has()`] keeps returning `true`.
+    ///
     /// # Arguments
     /// - `id`: The id to test.
     ///
@@ -2340,6 +2848,156 @@ impl<'a> EntityView<'a> {
         dest_entity
     }
 
+    /// Copy the components of this entity onto an existing entity.
+    ///
+    /// Unlike [`EntityView::duplicate_into()`], the target does not have to be
+    /// empty, which makes this useful for applying a template to an entity. The
+    /// target gains every component, tag and pair of this entity that it doesn't
+    /// have yet, and keeps the components this entity doesn't have. The name and
+    /// symbol of this entity are not copied.
+    ///
+    /// If `copy_value` is true, component values are copied with the copy hook,
+    /// overwriting the values of components the target already has. If it is
+    /// false, components the target already has keep their value and new
+    /// components are default constructed.
+    ///
+    /// Only the ids in the entity's [`archetype()`](EntityView::archetype) are copied.
+    /// Components with the [`flecs::DontFragment`] trait (which includes union
+    /// relationships) are not stored in the archetype and are not copied.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The entity to copy the components to.
+    /// * `copy_value` - If true, the component values are copied as well.
+    ///
+    /// # Returns
+    ///
+    /// The target entity.
+    ///
+    /// # Panics
+    ///
+    /// If `copy_value` is true, panics when a copied component doesn't implement
+    /// `Clone`, like [`EntityView::duplicate()`].
+    ///
+    /// Panics when a component has to be default constructed on the target but
+    /// doesn't implement `Default`, like [`EntityView::add()`]. This is checked
+    /// before the target is modified.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component, Clone, Default)]
+    /// struct Health(u32);
+    ///
+    /// #[derive(Component, Clone, Default)]
+    /// struct Armor(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct Elite;
+    ///
+    /// let world = World::new();
+    ///
+    /// let template = world.entity().set(Health(100)).add(Elite);
+    /// let orc = world.entity().set(Health(10)).set(Armor(5));
+    ///
+    /// template.clone_into(orc, true);
+    ///
+    /// assert!(orc.has(Elite));
+    /// orc.get::<(&Health, &Armor)>(|(health, armor)| {
+    ///     assert_eq!(health.0, 100);
+    ///     assert_eq!(armor.0, 5);
+    /// });
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::duplicate_into()`]
+    pub fn clone_into(self, target: impl IntoEntity, copy_value: bool) -> EntityView<'a> {
+        let target = EntityView::new_from(self.world, target.into_entity(self.world));
+        if target.id == self.id {
+            return target;
+        }
+
+        let name = ecs_pair(flecs::Identifier::ID, flecs::Name::ID);
+        let symbol = ecs_pair(flecs::Identifier::ID, flecs::Symbol::ID);
+
+        // Copy the type first, adding to the target can run observers that
+        // change this entity.
+        let ids: Vec<u64> = self
+            .archetype()
+            .as_slice()
+            .iter()
+            .map(|id| **id)
+            .filter(|&id| id != name && id != symbol)
+            .collect();
+
+        let world = self.world.world_ptr_mut();
+
+        // Ids that aren't copied are default constructed on the target. Validate
+        // them up front like `add` does, so that a component without a default
+        // hook is never zero-initialized and a panic leaves the target unchanged.
+        for &id in &ids {
+            // SAFETY: `world` is the live world both entities belong to, and `id`
+            // is an id of this entity's archetype.
+            let (copies, target_has) = unsafe {
+                (
+                    copy_value && !sys::ecs_get_type_info(world, id).is_null(),
+                    sys::ecs_has_id(world, *target.id, id),
+                )
+            };
+            if !copies && !target_has {
+                check_add_id_validity(world, id);
+            }
+        }
+
+        for id in ids {
+            // SAFETY: `world` is the live world both entities belong to. The
+            // source pointer is checked for null and, together with the size
+            // from the component's type info, describes a valid component value.
+            // `ecs_rust_set` returns storage on the target that holds a valid
+            // value unless `is_new` is set, in which case it is uninitialized and
+            // constructed with the copy constructor instead of being assigned to.
+            unsafe {
+                let type_info = sys::ecs_get_type_info(world, id);
+                if copy_value && !type_info.is_null() && sys::ecs_has_id(world, *self.id, id) {
+                    let size = (*type_info).size as usize;
+                    let src = sys::ecs_get_id(world, *self.id, id);
+                    let res = sys::ecs_rust_set(world, *target.id, id, src, size);
+                    assert!(
+                        !res.ptr.is_null(),
+                        "clone_into failed: target is not alive or the world is invalid"
+                    );
+                    // fetch again, moving the target can move rows of this entity's table
+                    let src = sys::ecs_get_id(world, *self.id, id);
+                    let hooks = &(*type_info).hooks;
+                    let copy = if res.is_new {
+                        hooks.copy_ctor
+                    } else {
+                        hooks.copy
+                    };
+                    match copy {
+                        Some(copy) => copy(res.ptr, src, 1, type_info),
+                        None => core::ptr::copy_nonoverlapping(
+                            src as *const u8,
+                            res.ptr as *mut u8,
+                            size,
+                        ),
+                    }
+                    if res.call_modified {
+                        sys::ecs_modified_id(world, *target.id, id);
+                    }
+                    continue;
+                }
+                // validated above, or the target already has the id
+                sys::ecs_add_id(world, *target.id, id);
+            }
+        }
+
+        target
+    }
+
     /// Returns a mutable entity handle for the current stage.
     ///
     /// When an entity handle created from the world is used while the world is
@@ -2442,6 +3100,49 @@ impl EntityView<'_> {
         self.world().event().entity(self).emit(event);
     }
 
+    /// Emit event for entity, taking ownership of the payload.
+    ///
+    /// The payload is available to observers through [`TableIter::param()`] for the
+    /// duration of the synchronous dispatch and is dropped once all observers have run.
+    ///
+    /// # Type Parameters
+    ///
+    /// * T - the event type to emit.
+    ///
+    /// # Usage:
+    ///
+    /// ```
+    /// # use flecs_ecs::prelude::*;
+    /// # let world = World::new();
+    /// #[derive(Component)]
+    /// struct Damage {
+    ///     amount: u32,
+    /// }
+    ///
+    /// let entity = world.entity();
+    ///
+    /// world
+    ///     .observer::<Damage, ()>()
+    ///     .with(flecs::Any)
+    ///     .run(|mut it| {
+    ///         while it.next() {
+    ///             assert_eq!(it.param().amount, 10);
+    ///         }
+    ///     });
+    ///
+    /// entity.emit_with_payload(Damage { amount: 10 });
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::emit()`]
+    /// * [`EntityView::enqueue()`]
+    /// * [`EntityView::observe_payload()`]
+    /// * [`World::event()`]
+    pub fn emit_with_payload<T: ComponentId + DataComponent>(self, event: T) {
+        self.emit(&event);
+    }
+
     /// Enqueue event for entity.
     ///
     /// # Safety
@@ -2517,7 +3218,31 @@ impl EntityView<'_> {
     ///
     /// * `func` - The callback function
     ///
-    /// See also
+    /// The observer only fires for events emitted on this entity. It is created
+    /// as a child of the entity, so it is deleted together with it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Clicked;
+    ///
+    /// let world = World::new();
+    ///
+    /// let button = world.entity_named("button");
+    /// let other = world.entity_named("other");
+    ///
+    /// button.observe::<Clicked>(|| {
+    ///     println!("button clicked");
+    /// });
+    ///
+    /// button.emit(&Clicked); // fires the observer
+    /// other.emit(&Clicked); // does not fire the observer
+    /// ```
+    ///
+    /// # See also
     ///
     /// * [`EntityView::emit()`]
     /// * [`EntityView::enqueue()`]
@@ -2567,7 +3292,7 @@ impl EntityView<'_> {
     ///
     /// * `func` - The callback function
     ///
-    /// See also
+    /// # See also
     ///
     /// * [`EntityView::emit()`]
     /// * [`EntityView::enqueue()`]
@@ -2617,7 +3342,29 @@ impl EntityView<'_> {
     ///
     /// * `func` - The callback function
     ///
-    /// See also
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Clicked {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let button = world.entity_named("button");
+    ///
+    /// button.observe_payload::<Clicked>(|click| {
+    ///     println!("clicked at ({}, {})", click.x, click.y);
+    /// });
+    ///
+    /// button.emit(&Clicked { x: 10.0, y: 20.0 });
+    /// ```
+    ///
+    /// # See also
     ///
     /// * [`EntityView::emit()`]
     /// * [`EntityView::enqueue()`]
@@ -2667,7 +3414,7 @@ impl EntityView<'_> {
     ///
     /// * `func` - The callback function
     ///
-    /// See also
+    /// # See also
     ///
     /// * [`EntityView::emit()`]
     /// * [`EntityView::enqueue()`]
@@ -2878,49 +3625,3 @@ impl EntityView<'_> {
         }
     }
 }
-
-/// Calls `defer_begin` on construction and `defer_end` on drop, so the defer
-/// block is closed even when the user callback unwinds.
-#[cfg(not(feature = "flecs_safety_locks"))]
-struct DeferGuard<'w> {
-    world: WorldRef<'w>,
-}
-
-#[cfg(not(feature = "flecs_safety_locks"))]
-impl<'w> DeferGuard<'w> {
-    fn new(world: WorldRef<'w>) -> Self {
-        world.defer_begin();
-        Self { world }
-    }
-}
-
-#[cfg(not(feature = "flecs_safety_locks"))]
-impl Drop for DeferGuard<'_> {
-    fn drop(&mut self) {
-        self.world.defer_end();
-    }
-}
-
-/// Locks a table on construction and unlocks it on drop, so the table is
-/// unlocked even when the user callback unwinds.
-struct TableLockGuard {
-    world: *mut sys::ecs_world_t,
-    table: *mut sys::ecs_table_t,
-}
-
-impl TableLockGuard {
-    /// # Safety
-    ///
-    /// `world` and `table` must be valid pointers for the lifetime of the guard.
-    unsafe fn lock(world: *mut sys::ecs_world_t, table: *mut sys::ecs_table_t) -> Self {
-        unsafe { sys::ecs_table_lock(world, table) };
-        Self { world, table }
-    }
-}
-
-impl Drop for TableLockGuard {
-    fn drop(&mut self) {
-        // SAFETY: the caller of `lock` guaranteed the pointers outlive the guard.
-        unsafe { sys::ecs_table_unlock(self.world, self.table) };
-    }
-}
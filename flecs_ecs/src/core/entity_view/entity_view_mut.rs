@@ -5,6 +5,31 @@ use sys::EcsIsA;
 
 use crate::{core::flecs::FlecsComponentTrait, sys};
 
+extern crate alloc;
+use alloc::string::{String, ToString};
+
+/// Error returned by [`EntityView::add_by_name()`] when the expression does not
+/// resolve to a component, tag, entity or pair.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownIdError {
+    expr: String,
+}
+
+impl UnknownIdError {
+    /// The expression that could not be resolved.
+    pub fn expr(&self) -> &str {
+        &self.expr
+    }
+}
+
+impl core::fmt::Display for UnknownIdError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "`{}` does not resolve to a valid id", self.expr)
+    }
+}
+
+impl core::error::Error for UnknownIdError {}
+
 // functions in here match most of the functions in the c++ entity and entity_builder class
 impl<'a> EntityView<'a> {
     /// Adds an ID to the entity.
@@ -67,6 +92,73 @@ impl<'a> EntityView<'a> {
         self
     }
 
+    /// Adds a component, tag, entity or pair by name.
+    ///
+    /// The expression is resolved the same way as an id in the query DSL, so it
+    /// can be a name or path such as `"Position"` or `"game::Health"`, or a pair
+    /// such as `"(ChildOf, parent)"`. The resolved id is added with [`add()`](Self::add).
+    ///
+    /// # Arguments
+    ///
+    /// * `expr` - The name, path or pair expression of the id to add.
+    ///
+    /// # Returns
+    ///
+    /// The entity, or an [`UnknownIdError`] if the expression does not resolve to
+    /// a valid id. The entity is left unchanged in that case.
+    ///
+    /// # Panics
+    ///
+    /// Panics like [`add()`](Self::add) if the id is a component that has data
+    /// but no default hook.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component, Default)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.component::<Position>();
+    ///
+    /// let parent = world.entity_named("parent");
+    /// let e = world
+    ///     .entity()
+    ///     .add_by_name("Position")
+    ///     .and_then(|e| e.add_by_name("(ChildOf, parent)"))
+    ///     .unwrap();
+    ///
+    /// assert!(e.has(Position::id()));
+    /// assert!(e.has((flecs::ChildOf::ID, parent)));
+    ///
+    /// assert!(e.add_by_name("DoesNotExist").is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::add()`]
+    /// * C API: `ecs_id_from_str`
+    pub fn add_by_name(self, expr: &str) -> Result<Self, UnknownIdError> {
+        let world = self.world.world_ptr_mut();
+        let c_expr = compact_str::format_compact!("{}\0", expr);
+        // SAFETY: the world pointer is valid for 'a and `c_expr` is NUL-terminated.
+        let id = unsafe { sys::ecs_id_from_str(world, c_expr.as_ptr() as *const _) };
+
+        // SAFETY: the world pointer is valid for 'a; ecs_id_is_valid accepts any id value.
+        if id == 0 || !unsafe { sys::ecs_id_is_valid(world, id) } {
+            return Err(UnknownIdError {
+                expr: expr.to_string(),
+            });
+        }
+
+        Ok(self.add(Id(id)))
+    }
+
     /// Adds an ID to the entity unchecked. Useful for run-time components.
     ///
     /// The provided `id` can represent various types, including a component, a pair, a tag, or another entity.
@@ -123,6 +215,11 @@ impl<'a> EntityView<'a> {
     }
 
     /// Adds a pair to the entity composed of a tag and an (C) flecs enum constant.
+    ///
+    /// The enum constant is resolved to its constant entity, which becomes the target of the
+    /// pair. Give `First` the [`flecs::Exclusive`] trait to replace the previous constant when
+    /// adding another one, for example to model a state machine. The current constant can be
+    /// read back with [`EntityView::get_enum_target()`].
     pub fn add_pair_enum<First, Second>(self, enum_value: Second) -> Self
     where
         First: ComponentId,
@@ -173,10 +270,31 @@ impl<'a> EntityView<'a> {
     /// Conditional add.
     /// This operation adds if condition is true, removes if condition is false.
     ///
+    /// For pairs, removing uses a wildcard target when the relationship is
+    /// [`flecs::Exclusive`] or the target is 0, so any existing target is removed.
+    /// Like [`EntityView::add()`], this is a single command when the world is deferred.
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Stunned;
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world.entity();
+    ///
+    /// e.add_if(Stunned, true);
+    /// assert!(e.has(Stunned));
+    ///
+    /// e.add_if(Stunned, false);
+    /// assert!(!e.has(Stunned));
+    /// ```
+    ///
     /// # Arguments
     ///
+    /// * `id`: The component, pair or entity to add or remove.
     /// * `condition`: The condition to evaluate.
-    /// * `component`: The component to add.
     pub fn add_if<T: IntoId>(self, id: T, condition: bool) -> Self {
         if condition {
             self.add(id)
@@ -230,9 +348,37 @@ impl<'a> EntityView<'a> {
 
     /// Remove an entity from an entity.
     ///
+    /// Pairs with a [`flecs::Wildcard`] target remove all pairs of that relationship
+    /// in a single call, e.g. to reset a state machine or clear every `(Likes, *)` pair.
+    /// A wildcard relationship (`(*, Target)`) removes every pair with that target.
+    ///
     /// # Arguments
     ///
     /// * `component_id`: The entity to remove.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Likes;
+    ///
+    /// let world = World::new();
+    /// let apple = world.entity();
+    /// let pear = world.entity();
+    ///
+    /// let e = world.entity().add((Likes, apple)).add((Likes, pear));
+    /// assert_eq!(e.target_count::<Likes>(), Some(2));
+    ///
+    /// e.remove((Likes, flecs::Wildcard));
+    /// assert_eq!(e.target_count::<Likes>(), None);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::clear()`]
+    /// * C API: `ecs_remove_id`
     pub fn remove<T: IntoId>(self, id: T) -> Self {
         let id = *id.into_id(self.world);
         let id = if <T as IntoId>::IS_ENUM {
@@ -518,14 +664,132 @@ impl<'a> EntityView<'a> {
 
         let pair_id = ecs_pair(First::entity_id(self.world), Second::entity_id(self.world));
 
+        assert!(
+            {
+                // SAFETY: the world pointer is valid for 'a; ecs_get_typeid accepts any id value.
+                let type_id = unsafe { sys::ecs_get_typeid(self.world.ptr_mut(), pair_id) };
+                type_id != 0
+                    && type_id
+                        == <(First, Second) as ComponentOrPairId>::CastType::entity_id(self.world)
+            },
+            "Pair is not a (data) component of the value type. Possible cause: PairIsTag trait"
+        );
+
+        set_helper(self.world.world_ptr_mut(), *self.id, data, pair_id);
+        self
+    }
+
+    /// Set a pair for an entity where the first element carries the data.
+    ///
+    /// Equivalent to [`EntityView::set_pair`], but checks at compile time that `First`
+    /// is the data type of the pair.
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component, Debug, PartialEq)]
+    /// struct Requires {
+    ///     amount: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Gigawatts;
+    ///
+    /// let world = World::new();
+    ///
+    /// let entity = world
+    ///     .entity()
+    ///     .set_pair_first::<Requires, Gigawatts>(Requires { amount: 1.21 });
+    ///
+    /// entity.get::<&(Requires, Gigawatts)>(|req| {
+    ///     assert_eq!(req.amount, 1.21);
+    /// });
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::set_pair`]
+    /// * [`EntityView::set_pair_second`]
+    pub fn set_pair_first<First, Second>(self, first: First) -> Self
+    where
+        First: ComponentId + DataComponent,
+        Second: ComponentId,
+        (First, Second): ComponentOrPairId,
+    {
+        const {
+            assert!(
+                <(First, Second) as ComponentOrPairId>::IS_FIRST,
+                "`First` does not carry the data of this pair. use `set_pair_second` instead."
+            );
+        };
+
+        let pair_id = ecs_pair(First::entity_id(self.world), Second::entity_id(self.world));
+
         ecs_assert!(
             // SAFETY: the world pointer is valid for 'a; ecs_get_typeid accepts any id value.
-            unsafe { sys::ecs_get_typeid(self.world.ptr_mut(), pair_id) } != 0,
+            unsafe { sys::ecs_get_typeid(self.world.ptr_mut(), pair_id) }
+                == First::entity_id(self.world),
             FlecsErrorCode::InvalidOperation,
-            "Pair is not a (data) component. Possible cause: PairIsTag trait"
+            "First is not the data type of the pair. Possible cause: PairIsTag trait"
         );
 
-        set_helper(self.world.world_ptr_mut(), *self.id, data, pair_id);
+        set_helper(self.world.world_ptr_mut(), *self.id, first, pair_id);
+        self
+    }
+
+    /// Set a pair for an entity where the second element carries the data.
+    ///
+    /// For relationships such as `(Eats, Amount)`, where `Eats` is a tag and `Amount`
+    /// holds the value, the pair is stored with the type of `Second`. Fails to compile
+    /// when `First` is not a tag, since such pairs always take the type of `First`.
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Eats;
+    ///
+    /// #[derive(Component, Debug, PartialEq)]
+    /// struct Amount(u32);
+    ///
+    /// let world = World::new();
+    ///
+    /// let entity = world.entity().set_pair_second::<Eats, Amount>(Amount(5));
+    ///
+    /// entity.get::<&(Eats, Amount)>(|amount| {
+    ///     assert_eq!(*amount, Amount(5));
+    /// });
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::set_pair`]
+    /// * [`EntityView::set_pair_first`]
+    /// * [`EntityView::set_second`]
+    pub fn set_pair_second<First, Second>(self, second: Second) -> Self
+    where
+        First: ComponentId,
+        Second: ComponentId + DataComponent,
+        (First, Second): ComponentOrPairId,
+    {
+        const {
+            assert!(
+                !<(First, Second) as ComponentOrPairId>::IS_FIRST,
+                "`First` carries the data of this pair. use `set_pair_first` instead."
+            );
+        };
+
+        let pair_id = ecs_pair(First::entity_id(self.world), Second::entity_id(self.world));
+
+        ecs_assert!(
+            // SAFETY: the world pointer is valid for 'a; ecs_get_typeid accepts any id value.
+            unsafe { sys::ecs_get_typeid(self.world.ptr_mut(), pair_id) }
+                == Second::entity_id(self.world),
+            FlecsErrorCode::InvalidOperation,
+            "Second is not the data type of the pair. Possible cause: PairIsTag trait"
+        );
+
+        set_helper(self.world.world_ptr_mut(), *self.id, second, pair_id);
         self
     }
 
@@ -704,11 +968,15 @@ impl<'a> EntityView<'a> {
 
         let pair_id = ecs_pair(First::entity_id(self.world), Second::entity_id(self.world));
 
-        ecs_assert!(
-            // SAFETY: the world pointer is valid for 'a; ecs_get_typeid accepts any id value.
-            unsafe { sys::ecs_get_typeid(self.world.ptr_mut(), pair_id) } != 0,
-            FlecsErrorCode::InvalidOperation,
-            "Pair is not a (data) component. Possible cause: PairIsTag trait"
+        assert!(
+            {
+                // SAFETY: the world pointer is valid for 'a; ecs_get_typeid accepts any id value.
+                let type_id = unsafe { sys::ecs_get_typeid(self.world.ptr_mut(), pair_id) };
+                type_id != 0
+                    && type_id
+                        == <(First, Second) as ComponentOrPairId>::CastType::entity_id(self.world)
+            },
+            "Pair is not a (data) component of the value type. Possible cause: PairIsTag trait"
         );
 
         assign_helper(self.world.world_ptr_mut(), *self.id, value, pair_id);
@@ -887,6 +1155,166 @@ impl<'a> EntityView<'a> {
         self.try_assign_helper(second, pair_id)
     }
 
+    /// Get mutable access to a component, adding it with its default value if the entity
+    /// does not have it yet.
+    ///
+    /// The component is obtained with a single `ensure` operation, so no separate `has` check
+    /// is needed. This does not emit `OnSet`, call [`EntityView::modified()`] after changing the value
+    /// if observers should be notified.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component, Default)]
+    /// struct Health {
+    ///     value: i32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// let e = world.entity();
+    ///
+    /// e.get_mut_or_default::<Health, _>(|h| h.value += 10);
+    /// e.get_mut_or_default::<Health, _>(|h| h.value += 10);
+    ///
+    /// assert_eq!(e.get::<&Health>(|h| h.value), 20);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - The callback that receives the component.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::get_mut_or_insert_with()`]
+    /// * C API: `ecs_ensure_id`
+    pub fn get_mut_or_default<T: ComponentId + DataComponent + Default, Return>(
+        self,
+        callback: impl FnOnce(&mut T) -> Return,
+    ) -> Return {
+        const {
+            assert!(
+                core::mem::size_of::<T>() != 0,
+                "cannot get zero-sized-type / tag components"
+            );
+        };
+
+        self.world.check_thread_affinity_exclusive::<T>();
+
+        let world = self.world.world_ptr_mut();
+        let id = T::entity_id(self.world);
+
+        // SAFETY: the world pointer is valid for 'a. `T` implements `Default`, so its
+        // registered constructor hook initializes a valid value when the component is added.
+        unsafe {
+            if !sys::ecs_owns_id(world, *self.id, id) {
+                let ptr =
+                    sys::ecs_ensure_id(world, *self.id, id, const { core::mem::size_of::<T>() })
+                        as *mut T;
+                assert!(
+                    !ptr.is_null(),
+                    "ensure failed: entity is not alive or the world is invalid"
+                );
+                // when deferred the value lives in the command queue, which nothing else can borrow
+                if !sys::ecs_owns_id(world, *self.id, id) {
+                    return callback(&mut *ptr);
+                }
+            }
+        }
+
+        // table storage is handed out through `get` so it takes the same locks
+        self.get::<&mut T>(callback)
+    }
+
+    /// Get mutable access to a component, adding it with the value returned by `init` if the
+    /// entity does not have it yet.
+    ///
+    /// Unlike [`EntityView::get_mut_or_default()`] this does not require `T` to implement
+    /// [`Default`]. `init` is only called when the component is absent. This does not emit `OnSet`,
+    /// call [`EntityView::modified()`] after changing the value if observers should be notified.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Name {
+    ///     value: String,
+    /// }
+    ///
+    /// let world = World::new();
+    /// let e = world.entity();
+    ///
+    /// e.get_mut_or_insert_with::<Name, _>(
+    ///     || Name {
+    ///         value: "Bob".to_string(),
+    ///     },
+    ///     |n| n.value.push_str(" Jr."),
+    /// );
+    ///
+    /// assert_eq!(e.get::<&Name>(|n| n.value.clone()), "Bob Jr.");
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `init` - Creates the component value when the entity does not have it.
+    /// * `callback` - The callback that receives the component.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::get_mut_or_default()`]
+    /// * C API: `ecs_ensure_id`
+    pub fn get_mut_or_insert_with<T: ComponentId + DataComponent, Return>(
+        self,
+        init: impl FnOnce() -> T,
+        callback: impl FnOnce(&mut T) -> Return,
+    ) -> Return {
+        const {
+            assert!(
+                core::mem::size_of::<T>() != 0,
+                "cannot get zero-sized-type / tag components"
+            );
+        };
+
+        self.world.check_thread_affinity_exclusive::<T>();
+
+        let world = self.world.world_ptr_mut();
+        let id = T::entity_id(self.world);
+        let size = const { core::mem::size_of::<T>() };
+
+        // SAFETY: the world pointer is valid for 'a. `ecs_ensure_id` constructs the component,
+        // after which the constructed value is replaced by the value returned by `init`.
+        unsafe {
+            if !sys::ecs_owns_id(world, *self.id, id) {
+                // an inherited component is copied from its base by `ensure`
+                let value = (!sys::ecs_has_id(world, *self.id, id)).then(init);
+                let ptr = sys::ecs_ensure_id(world, *self.id, id, size) as *mut T;
+                assert!(
+                    !ptr.is_null(),
+                    "ensure failed: entity is not alive or the world is invalid"
+                );
+
+                if let Some(value) = value {
+                    // flecs' default ctor zero-fills memory, which is not a valid `T`
+                    if T::NEEDS_DROP && has_default_hook(world, id) {
+                        core::ptr::drop_in_place(ptr);
+                    }
+                    core::ptr::write(ptr, value);
+                }
+
+                // when deferred the value lives in the command queue, which nothing else can borrow
+                if !sys::ecs_owns_id(world, *self.id, id) {
+                    return callback(&mut *ptr);
+                }
+            }
+        }
+
+        // table storage is handed out through `get` so it takes the same locks
+        self.get::<&mut T>(callback)
+    }
+
     /// Sets the name of the entity.
     ///
     /// # Arguments
@@ -967,7 +1395,16 @@ impl<'a> EntityView<'a> {
     /// Disables self (entity).
     ///
     /// Disabled entities are not matched with systems and cannot be searched with queries,
-    /// unless explicitly specified in the query expression.
+    /// unless explicitly specified in the query expression or the query is built with
+    /// [`QueryBuilderImpl::with_disabled()`].
+    ///
+    /// This adds the [`flecs::Disabled`] tag to the entity. To toggle a single component
+    /// instead, use [`EntityView::disable()`].
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::enable_self()`]
+    /// * [`EntityView::is_enabled_self()`]
     pub fn disable_self(self) -> Self {
         // SAFETY: the world pointer is valid for 'a; ecs_enable accepts any entity value.
         unsafe { sys::ecs_enable(self.world.world_ptr_mut(), *self.id, false) }
@@ -995,6 +1432,61 @@ impl<'a> EntityView<'a> {
         self
     }
 
+    /// Toggles an ID which represents a component or pair on or off.
+    ///
+    /// The component stays on the entity, so [`EntityView::has()`] keeps returning `true`, but
+    /// queries skip entities for which the component is toggled off. Only components with the
+    /// [`flecs::CanToggle`] trait can be toggled, which can be added with `#[flecs(can_toggle)]`.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`: The ID to toggle.
+    /// - `enabled`: True to enable, false to disable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use flecs_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// #[flecs(can_toggle)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world.entity().set(Position { x: 1.0, y: 2.0 });
+    ///
+    /// e.toggle(Position::id(), false);
+    /// assert!(e.has(Position::id()));
+    /// assert!(!e.is_enabled(Position::id()));
+    /// assert_eq!(world.query::<&Position>().build().count(), 0);
+    ///
+    /// e.toggle(Position::id(), true);
+    /// assert!(e.is_enabled(Position::id()));
+    /// assert_eq!(world.query::<&Position>().build().count(), 1);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::enable()`]
+    /// * [`EntityView::disable()`]
+    /// * [`EntityView::is_enabled()`]
+    /// * C API: `ecs_enable_id`
+    pub fn toggle(self, id: impl IntoId, enabled: bool) -> Self {
+        // SAFETY: the world pointer is valid for 'a; ecs_enable_id accepts any entity/id values.
+        unsafe {
+            sys::ecs_enable_id(
+                self.world.world_ptr_mut(),
+                *self.id,
+                *id.into_id(self.world),
+                enabled,
+            );
+        }
+        self
+    }
+
     /// Entities created in the function will have the current entity.
     /// This operation is thread safe.
     ///
@@ -1070,18 +1562,108 @@ impl<'a> EntityView<'a> {
         self
     }
 
-    /// Calls the provided function with a world scoped to entity
-    pub fn scope(self, f: impl FnMut(&World)) -> Self {
+    /// Calls the provided function with a world scoped to entity.
+    /// The previous scope is restored afterwards, see [`World::scope()`].
+    pub fn scope(self, f: impl FnOnce(&World)) -> Self {
         let world = &*self.world;
         world.scope(self.id, f);
         self
     }
 
+    /// Add and set several components on the entity with a single table move.
+    ///
+    /// Chaining [`EntityView::set()`] and [`EntityView::add()`] moves the entity to a new
+    /// table for every component. The operations issued on the [`EntityInsertBuilder`] are
+    /// collected instead, and the entity is committed directly to its final table. Values
+    /// are written afterwards and `OnSet` is emitted for each set component.
+    ///
+    /// Components the entity already has are set in place. When the world is deferred the
+    /// operations are enqueued as usual.
+    ///
+    /// # Arguments
+    ///
+    /// * `func` - The function that adds or sets components through the builder.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Velocity {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Enemy;
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world.entity().insert(|b| {
+    ///     b.set(Position { x: 1.0, y: 2.0 })
+    ///         .set(Velocity { x: 0.5, y: 0.0 })
+    ///         .add(Enemy);
+    /// });
+    ///
+    /// assert!(e.has(Position::id()));
+    /// assert!(e.has(Velocity::id()));
+    /// assert!(e.has(Enemy));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::set()`]
+    /// * [`EntityView::add()`]
+    /// * C API: `ecs_commit`
+    pub fn insert(self, func: impl FnOnce(&mut EntityInsertBuilder<'a>)) -> Self {
+        let mut builder = EntityInsertBuilder::new(self);
+        func(&mut builder);
+        builder.commit();
+        self
+    }
+
     /// Signal that component or pair was modified.
     ///
+    /// Writes through [`EntityView::get()`] with `&mut T` or through a raw pointer
+    /// don't notify Flecs. Call this afterwards so `OnSet` observers and change
+    /// detection see the new value. Accepts a typed id or a pair, like [`EntityView::add()`].
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Start;
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world
+    ///     .entity()
+    ///     .set(Position { x: 0.0, y: 0.0 })
+    ///     .set_pair::<Position, Start>(Position { x: 0.0, y: 0.0 });
+    ///
+    /// e.get::<&mut Position>(|p| p.x = 10.0);
+    /// e.modified(Position::id());
+    ///
+    /// e.get::<&mut (Position, Start)>(|p| p.x = 5.0);
+    /// e.modified((Position::id(), Start::id()));
+    /// ```
+    ///
     /// # Arguments
     ///
-    /// * `comp` - The component that was modified.
+    /// * `id` - The component or pair that was modified.
     ///
     /// # See also
     ///
@@ -1157,6 +1739,47 @@ impl<'a> EntityView<'a> {
         unsafe { sys::ecs_delete(self.world.world_ptr_mut(), *self.id) }
     }
 
+    /// Delete all children of an entity.
+    ///
+    /// Deletes all entities with a `(ChildOf, entity)` pair. The children of those
+    /// entities are deleted as well by the `(OnDeleteTarget, Delete)` cleanup policy of
+    /// [`flecs::ChildOf`], so the entire hierarchy below the entity is removed while the
+    /// entity itself is kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let parent = world.entity();
+    /// let child = world.entity().child_of(parent);
+    /// let grandchild = world.entity().child_of(child);
+    ///
+    /// parent.delete_children();
+    ///
+    /// assert!(parent.is_alive());
+    /// assert!(!child.is_alive());
+    /// assert!(!grandchild.is_alive());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::destruct()`]
+    /// * [`World::delete_entities_with()`]
+    /// * C API: `ecs_delete_with`
+    pub fn delete_children(self) -> Self {
+        // SAFETY: the world pointer is valid for 'a; ecs_delete_with accepts any id value.
+        unsafe {
+            sys::ecs_delete_with(
+                self.world.world_ptr_mut(),
+                ecs_pair(flecs::ChildOf::ID, *self.id),
+            );
+        }
+        self
+    }
+
     /// Set child order.
     /// Changes the order of children as returned by [`EntityView::each_child()`].
     /// Only applicable to entities with the [`flecs::OrderedChildren`] trait.
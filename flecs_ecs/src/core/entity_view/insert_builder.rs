@@ -0,0 +1,310 @@
+use crate::core::*;
+use crate::sys;
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+/// A component value waiting in the builder's value buffer to be written into the entity.
+struct PendingWrite {
+    id: sys::ecs_id_t,
+    /// Offset of the value in the buffer. Values are stored unaligned.
+    offset: usize,
+    /// Moves the value into the entity, returns false if it was set through the regular
+    /// path instead (which already emits `OnSet`).
+    write: unsafe fn(*mut sys::ecs_world_t, sys::ecs_entity_t, sys::ecs_id_t, *const u8) -> bool,
+    /// Drops a value that is never written.
+    drop: unsafe fn(*const u8),
+}
+
+/// A builder that collects component operations for an entity and applies them with a
+/// single table move.
+///
+/// Created by [`EntityView::insert()`]. Components are added to the entity in one commit
+/// to the final table, after which the values are written and `OnSet` is emitted.
+///
+/// When the world is deferred, operations are applied as they are issued and are batched
+/// with the rest of the command queue.
+pub struct EntityInsertBuilder<'a> {
+    entity: EntityView<'a>,
+    added: Vec<sys::ecs_id_t>,
+    writes: Vec<PendingWrite>,
+    values: Vec<u8>,
+    deferred: bool,
+}
+
+impl<'a> EntityInsertBuilder<'a> {
+    pub(crate) fn new(entity: EntityView<'a>) -> Self {
+        // SAFETY: the world pointer is valid for 'a.
+        let deferred = unsafe { sys::ecs_is_deferred(entity.world.world_ptr()) };
+        Self {
+            entity,
+            added: Vec::new(),
+            writes: Vec::new(),
+            values: Vec::new(),
+            deferred,
+        }
+    }
+
+    /// The entity the operations are applied to.
+    pub fn entity(&self) -> EntityView<'a> {
+        self.entity
+    }
+
+    /// Sets a component of type `T` on the entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `component` - The component to set on the entity.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::set()`]
+    pub fn set<T: ComponentId + DataComponent>(&mut self, component: T) -> &mut Self {
+        const {
+            assert!(
+                core::mem::size_of::<T>() != 0,
+                "cannot set zero-sized-type / tag components, use `add` instead"
+            );
+        };
+
+        let world = self.entity.world;
+        let id = T::entity_id(world);
+
+        // components that are already present (or inherited) don't need a table move
+        if self.deferred || self.entity.has(id) {
+            set_helper(world.world_ptr_mut(), *self.entity.id, component, id);
+            return self;
+        }
+
+        if let Some(pending) = self.writes.iter().find(|pending| pending.id == id) {
+            // SAFETY: the slot holds a `T` that was not moved out yet; it is replaced by
+            // the new value.
+            unsafe {
+                let slot = self.values.as_mut_ptr().add(pending.offset);
+                (pending.drop)(slot);
+                core::ptr::write_unaligned(slot as *mut T, component);
+            }
+        } else {
+            let offset = self.values.len();
+            self.values.resize(offset + core::mem::size_of::<T>(), 0);
+            // SAFETY: the buffer was just grown by `size_of::<T>()` bytes at `offset`.
+            unsafe {
+                core::ptr::write_unaligned(
+                    self.values.as_mut_ptr().add(offset) as *mut T,
+                    component,
+                );
+            }
+            self.writes.push(PendingWrite {
+                id,
+                offset,
+                write: write_pending::<T>,
+                drop: drop_pending::<T>,
+            });
+            self.added.push(id);
+        }
+        self
+    }
+
+    /// Adds an id to the entity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` is a component without a constructor hook, see [`EntityView::add()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The component, tag, pair or entity to add.
+    pub fn add(&mut self, id: impl IntoId) -> &mut Self {
+        let world = self.entity.world;
+        let id = *id.into_id(world);
+
+        check_add_id_validity(world.world_ptr(), id);
+
+        if self.deferred {
+            // SAFETY: the world pointer is valid for 'a; id validity was checked above.
+            unsafe { sys::ecs_add_id(world.world_ptr_mut(), *self.entity.id, id) };
+        } else if !self.added.contains(&id) && !self.entity.owns(id) {
+            self.added.push(id);
+        }
+        self
+    }
+
+    pub(crate) fn commit(mut self) {
+        if self.added.is_empty() {
+            return;
+        }
+
+        let world = self.entity.world.world_ptr_mut();
+        let entity = *self.entity.id;
+
+        // SAFETY: the world pointer is valid for 'a and the entity is alive; all ids were
+        // validated when they were added to the builder.
+        unsafe {
+            let src = sys::ecs_get_table(world, entity);
+            let mut table = src;
+            let mut non_fragmenting = Vec::new();
+            self.added.retain(|&id| {
+                let next = sys::ecs_table_add_id(world, table, id);
+                if next == table {
+                    non_fragmenting.push(id);
+                    false
+                } else {
+                    table = next;
+                    true
+                }
+            });
+
+            if table != src {
+                // adding an exclusive pair (e.g. `ChildOf`) replaces the existing one, which
+                // must be passed as removed so `OnRemove` is emitted for it
+                let (mut added, mut removed) = diff_ids(table_ids(src), table_ids(table));
+
+                let added = sys::ecs_type_t {
+                    array: added.as_mut_ptr(),
+                    count: added.len() as i32,
+                };
+                let removed = sys::ecs_type_t {
+                    array: removed.as_mut_ptr(),
+                    count: removed.len() as i32,
+                };
+                sys::ecs_commit(
+                    world,
+                    entity,
+                    core::ptr::null_mut(),
+                    table,
+                    &added,
+                    &removed,
+                );
+            }
+
+            for id in non_fragmenting {
+                if !self.writes.iter().any(|pending| pending.id == id) {
+                    sys::ecs_add_id(world, entity, id);
+                }
+            }
+        }
+
+        // write all values before emitting OnSet, so observers see the final state. The
+        // writes are taken first, so a panicking hook can't make `drop` see moved values.
+        let writes = core::mem::take(&mut self.writes);
+        let mut modified = Vec::with_capacity(writes.len());
+        for pending in writes {
+            // SAFETY: every pending slot holds a value that is moved out exactly once here.
+            if unsafe {
+                (pending.write)(
+                    world,
+                    entity,
+                    pending.id,
+                    self.values.as_ptr().add(pending.offset),
+                )
+            } {
+                modified.push(pending.id);
+            }
+        }
+
+        for id in modified {
+            // SAFETY: the world pointer is valid for 'a and the entity has `id`.
+            unsafe { sys::ecs_modified_id(world, entity, id) };
+        }
+    }
+}
+
+impl Drop for EntityInsertBuilder<'_> {
+    fn drop(&mut self) {
+        for pending in &self.writes {
+            // SAFETY: values of writes that are still pending were never moved out.
+            unsafe { (pending.drop)(self.values.as_ptr().add(pending.offset)) };
+        }
+    }
+}
+
+/// Moves the `T` stored at `value` into the entity, see [`PendingWrite::write`].
+///
+/// # Safety
+///
+/// `value` must point to an unaligned `T` that is not used afterwards, and the entity must
+/// have been committed to a table containing `id`.
+unsafe fn write_pending<T: ComponentId + DataComponent>(
+    world: *mut sys::ecs_world_t,
+    entity: sys::ecs_entity_t,
+    id: sys::ecs_id_t,
+    value: *const u8,
+) -> bool {
+    // SAFETY: guaranteed by the caller. The value of `id` is constructed by the commit; a
+    // null pointer means the id did not end up in the table (e.g. non-fragmenting
+    // components) and is set the regular way.
+    unsafe {
+        let component = core::ptr::read_unaligned(value as *const T);
+        let ptr = sys::ecs_get_mut_id(world, entity, id) as *mut T;
+        if ptr.is_null() {
+            set_helper(world, entity, component, id);
+            return false;
+        }
+
+        // flecs' default ctor zero-fills memory, which is not a valid `T`
+        if T::NEEDS_DROP && has_default_hook(world, id) {
+            core::ptr::drop_in_place(ptr);
+        }
+        core::ptr::write(ptr, component);
+        true
+    }
+}
+
+/// Drops the `T` stored at `value`.
+///
+/// # Safety
+///
+/// `value` must point to an unaligned `T` that is not used afterwards.
+unsafe fn drop_pending<T>(value: *const u8) {
+    // SAFETY: guaranteed by the caller.
+    unsafe { drop(core::ptr::read_unaligned(value as *const T)) };
+}
+
+/// Returns the ids only in `dst` and the ids only in `src`. Table types are sorted, so both
+/// are found in a single pass.
+fn diff_ids(
+    src: &[sys::ecs_id_t],
+    dst: &[sys::ecs_id_t],
+) -> (Vec<sys::ecs_id_t>, Vec<sys::ecs_id_t>) {
+    let mut added = Vec::new();
+    let mut removed = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < src.len() && j < dst.len() {
+        match src[i].cmp(&dst[j]) {
+            core::cmp::Ordering::Less => {
+                removed.push(src[i]);
+                i += 1;
+            }
+            core::cmp::Ordering::Greater => {
+                added.push(dst[j]);
+                j += 1;
+            }
+            core::cmp::Ordering::Equal => {
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    removed.extend_from_slice(&src[i..]);
+    added.extend_from_slice(&dst[j..]);
+    (added, removed)
+}
+
+/// Returns the ids of a table's type, or an empty slice for the root table.
+///
+/// # Safety
+///
+/// `table` must be null or point to a valid table.
+unsafe fn table_ids<'t>(table: *const sys::ecs_table_t) -> &'t [sys::ecs_id_t] {
+    // SAFETY: guaranteed by the caller; a table type holds `count` ids.
+    unsafe {
+        if table.is_null() {
+            return &[];
+        }
+        let ty = sys::ecs_table_get_type(table);
+        if ty.is_null() || (*ty).count == 0 {
+            return &[];
+        }
+        core::slice::from_raw_parts((*ty).array, (*ty).count as usize)
+    }
+}
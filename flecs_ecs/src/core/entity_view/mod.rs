@@ -4,7 +4,10 @@ mod bulk_entity_builder;
 mod entity_view_const;
 mod entity_view_impl;
 mod entity_view_mut;
+mod insert_builder;
 mod macros;
 
 pub use entity_view_const::EntityView;
 pub use entity_view_const::EntityViewGet;
+pub use entity_view_mut::UnknownIdError;
+pub use insert_builder::EntityInsertBuilder;
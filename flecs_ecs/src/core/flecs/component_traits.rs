@@ -877,7 +877,10 @@ impl_component_trait!(Sparse, ECS_SPARSE);
 /// let bob = world.entity();
 /// let alice = world.entity();
 /// bob.add((married_to, alice)); // Also adds (MarriedTo, Bob) to Alice
+/// assert!(alice.has((married_to, bob)));
 /// ```
+///
+/// A relationship component can be made symmetric at registration with `#[flecs(symmetric)]`.
 #[derive(Debug, Default, Clone)]
 pub struct Symmetric;
 
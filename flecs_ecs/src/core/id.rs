@@ -44,6 +44,8 @@ impl Deref for Id {
     }
 }
 
+/// Formats the raw numeric id. Use [`IdView`], which has access to the world, to format
+/// the id with component names.
 impl Display for Id {
     #[inline]
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
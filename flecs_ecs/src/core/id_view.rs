@@ -146,6 +146,31 @@ impl core::ops::Deref for IdView<'_> {
     }
 }
 
+/// Formats the id the same way as `ecs_id_str`, resolving names through the world.
+///
+/// Components are formatted by their path (e.g. `Position`), pairs as `(Likes,Apples)` and
+/// id flags are prefixed (e.g. `AUTO_OVERRIDE|Position`).
+impl core::fmt::Display for IdView<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // SAFETY: the world pointer is valid for 'a and `ecs_id_str` accepts any id value.
+        let str_ptr = unsafe { sys::ecs_id_str(self.world.world_ptr(), *self.id) };
+        let Some(str_ptr) = core::ptr::NonNull::new(str_ptr) else {
+            return self.id.fmt(f);
+        };
+
+        // SAFETY: `ecs_id_str` returns a null-terminated string allocated with the flecs
+        // allocator, which is freed with the same allocator after it has been written.
+        unsafe {
+            let result =
+                f.write_str(&core::ffi::CStr::from_ptr(str_ptr.as_ptr()).to_string_lossy());
+            sys::ecs_os_api.free_.expect("os api is missing")(
+                str_ptr.as_ptr() as *mut core::ffi::c_void
+            );
+            result
+        }
+    }
+}
+
 impl<'a> IdView<'a> {
     /// checks if the id is a pair
     ///
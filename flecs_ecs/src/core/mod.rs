@@ -69,6 +69,7 @@ pub use component_registration::*;
 #[doc(inline)]
 pub use components::*;
 pub use entity::Entity;
+pub use entity_view::EntityInsertBuilder;
 pub use entity_view::EntityView;
 pub use entity_view::EntityViewGet;
 pub use event::EventBuilder;
@@ -94,6 +95,7 @@ pub use term::*;
 #[doc(hidden)]
 pub use utility::*;
 pub use world::AsyncStage;
+pub(crate) use world::DeferGuard;
 pub(crate) use world::FlecsArray;
 pub use world::World;
 pub use world::WorldGet;
@@ -10,6 +10,41 @@ use crate::sys;
 /// Observers let applications register callbacks for ECS events.
 ///
 /// These are typically constructed via [`World::observer()`].
+///
+/// `Observer` wraps the observer entity and dereferences to [`EntityView`], so the
+/// handle returned by `each`/`run` can be used to control the observer later on.
+/// Disable it with [`EntityView::disable_self()`], enable it again with
+/// [`EntityView::enable_self()`] and delete it with [`EntityView::destruct()`].
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// let world = World::new();
+///
+/// let observer = world
+///     .observer::<flecs::OnSet, &Position>()
+///     .each(|p| {
+///         println!("set: {}, {}", p.x, p.y);
+///     });
+///
+/// // stop the observer from firing
+/// observer.disable_self();
+/// world.entity().set(Position { x: 1.0, y: 2.0 });
+///
+/// observer.enable_self();
+/// world.entity().set(Position { x: 3.0, y: 4.0 });
+///
+/// // delete the observer when it is no longer needed
+/// observer.destruct();
+/// ```
 #[derive(Clone, Copy)]
 pub struct Observer<'a> {
     entity: EntityView<'a>,
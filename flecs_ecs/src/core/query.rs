@@ -312,8 +312,8 @@
 use core::panic;
 use core::{ffi::c_void, marker::PhantomData, ptr::NonNull};
 
+use crate::core::utility::std_compat::{self, Mutex, PoisonError};
 use alloc::sync::Arc;
-use std::sync::{Mutex, PoisonError};
 
 use flecs_ecs_sys::ecs_get_binding_ctx;
 use sys::ecs_get_alive;
@@ -563,7 +563,9 @@ where
     /// Returns an iterator over the query using the given world or stage.
     ///
     /// Inside `par_*` callbacks, pass the stage obtained from the callback's
-    /// view (e.g. `entity_view.world()` or `table_iter.world()`).
+    /// view (e.g. `entity_view.world()` or `table_iter.world()`). Structural
+    /// changes made during iteration go to that stage's command queue, while
+    /// reads come from the shared world storage; see [`QueryAPI::iter_stage()`].
     ///
     /// Mutable component access through the returned iterator is guarded at
     /// runtime by the same per-column locks as `par_*` field access
@@ -650,7 +652,7 @@ where
             // already reported that), or the thread is unwinding.
             return;
         }
-        let on_owning_thread = std::thread::current().id() == ctx.owning_thread();
+        let on_owning_thread = std_compat::current_thread_id() == ctx.owning_thread();
         if *dead && !on_owning_thread {
             // World is finalizing on its owning thread; the query header may
             // already be freed, so only the world's panic check reports us.
@@ -1074,6 +1076,36 @@ where
     }
 
     /// Returns true if the entity matches the query.
+    ///
+    /// This tests the query against a single entity without iterating the query, which makes
+    /// it cheap to use for conditional logic. Optional terms don't have to be present on the
+    /// entity for it to match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use flecs_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// struct Mesh;
+    ///
+    /// #[derive(Component)]
+    /// struct Material;
+    ///
+    /// let world = World::new();
+    ///
+    /// let renderable = world.query::<(&Mesh, Option<&Material>)>().build();
+    ///
+    /// let e = world.entity();
+    /// assert!(!renderable.has(e));
+    ///
+    /// e.add(Mesh::id());
+    /// assert!(renderable.has(e));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Query::has_table()`]
+    /// * C API: `ecs_query_has`
     pub fn has(&self, entity: impl IntoEntity) -> bool {
         let entity = entity.into_entity(self.world());
         let mut it: sys::ecs_iter_t = unsafe { core::mem::zeroed() };
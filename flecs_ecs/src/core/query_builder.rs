@@ -773,6 +773,56 @@ pub trait QueryBuilderImpl<'a>: TermBuilderImpl<'a> {
         self.set_cache_kind(QueryCacheKind::Auto)
     }
 
+    /// Match entities that are disabled.
+    ///
+    /// Entities disabled with [`EntityView::disable_self()`] have the [`flecs::Disabled`]
+    /// tag and are skipped by queries by default. This opts the query in to matching them
+    /// alongside enabled entities.
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Enemy;
+    ///
+    /// let world = World::new();
+    ///
+    /// world.entity().add(Enemy);
+    /// world.entity().add(Enemy).disable_self();
+    ///
+    /// assert_eq!(world.new_query::<&Enemy>().count(), 1);
+    /// assert_eq!(world.query::<&Enemy>().with_disabled().build().count(), 2);
+    /// ```
+    fn with_disabled(&mut self) -> &mut Self {
+        self.query_flags(QueryFlags::MatchDisabled)
+    }
+
+    /// Match prefab entities.
+    ///
+    /// Entities with the [`flecs::Prefab`] tag are skipped by queries by default, the same
+    /// way [`flecs::Disabled`] entities are (see [`QueryBuilderImpl::with_disabled()`]).
+    /// This opts the query in to matching prefabs alongside regular entities, which is
+    /// useful for tools such as editors. A query that explicitly has a [`flecs::Prefab`]
+    /// term matches prefabs without this.
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Enemy;
+    ///
+    /// let world = World::new();
+    ///
+    /// world.entity().add(Enemy);
+    /// world.prefab().add(Enemy);
+    ///
+    /// assert_eq!(world.new_query::<&Enemy>().count(), 1);
+    /// assert_eq!(world.query::<&Enemy>().with_prefabs().build().count(), 2);
+    /// ```
+    fn with_prefabs(&mut self) -> &mut Self {
+        self.query_flags(QueryFlags::MatchPrefab)
+    }
+
     fn detect_changes(&mut self) -> &mut Self {
         self.query_desc_mut().flags |= sys::EcsQueryDetectChanges;
         self
@@ -797,6 +847,46 @@ pub trait QueryBuilderImpl<'a>: TermBuilderImpl<'a> {
         self
     }
 
+    /// Add a term that matches the provided id.
+    ///
+    /// `id` can be a component or entity id, a pair, or a name. Names (`&str`,
+    /// or a pair with a `&str` element) are looked up when the query is built,
+    /// which makes it possible to mix components known at compile time with
+    /// components registered at runtime, such as with
+    /// [`World::component_untyped_named()`]. If a name can't be resolved, building
+    /// the query fails and [`try_build()`](QueryBuilder::try_build) returns `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id, pair or name to match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let dynamic = world.component_untyped_named("DynamicTag");
+    ///
+    /// world.entity().set(Position { x: 1.0, y: 2.0 }).add(dynamic);
+    /// world.entity().set(Position { x: 3.0, y: 4.0 });
+    ///
+    /// let q = world.query::<&Position>().with("DynamicTag").build();
+    /// assert_eq!(q.count(), 1);
+    ///
+    /// assert!(world.query::<()>().with("Unknown").try_build().is_none());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`QueryBuilderImpl::without()`]
     fn with<'s, T>(&mut self, id: T) -> &mut Self
     where
         Access<'s>: FromAccessArg<T>,
@@ -844,6 +934,33 @@ pub trait QueryBuilderImpl<'a>: TermBuilderImpl<'a> {
     }
 
     /// set term with enum
+    ///
+    /// Enum constants are stored as `(Enum, Constant)` pairs, this adds a term that matches
+    /// entities with that specific constant. In the `query!` DSL this is written as
+    /// `variant Enum::Constant`.
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[repr(C)]
+    /// #[derive(Component)]
+    /// enum TrafficLight {
+    ///     Red,
+    ///     Yellow,
+    ///     Green,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.entity().add_enum(TrafficLight::Red);
+    /// world.entity().add_enum(TrafficLight::Green);
+    ///
+    /// let q = world.query::<()>().with_enum(TrafficLight::Red).build();
+    /// assert_eq!(q.count(), 1);
+    ///
+    /// let q = query!(&world, variant TrafficLight::Red).build();
+    /// assert_eq!(q.count(), 1);
+    /// ```
     fn with_enum<T: ComponentId + ComponentType<Enum> + EnumComponentInfo>(
         &mut self,
         value: T,
@@ -889,6 +1006,93 @@ pub trait QueryBuilderImpl<'a>: TermBuilderImpl<'a> {
         self.with_enum_wildcard::<T>().not()
     }
 
+    /// Only match entities that have at least one of the optional terms.
+    ///
+    /// A query where every term is optional, including the first, has nothing
+    /// to anchor on and matches all entities in the world. This adds an `Or`
+    /// chain of the optional `$this` terms added so far, so that the query only
+    /// visits entities that have any of them. The chain is a filter: it doesn't
+    /// change the fields of the optional terms and is added as a single extra
+    /// field after them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no optional term was added before calling this.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Velocity {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.entity().set(Position { x: 1.0, y: 2.0 });
+    /// world.entity().set(Velocity { x: 1.0, y: 2.0 });
+    ///
+    /// let q = world
+    ///     .query::<(Option<&Position>, Option<&Velocity>)>()
+    ///     .with_any_optional()
+    ///     .build();
+    ///
+    /// let mut count = 0;
+    /// q.each(|(p, v)| {
+    ///     assert!(p.is_some() != v.is_some());
+    ///     count += 1;
+    /// });
+    ///
+    /// assert_eq!(count, 2);
+    /// ```
+    fn with_any_optional(&mut self) -> &mut Self {
+        let mut ids = [0u64; sys::FLECS_TERM_COUNT_MAX as usize];
+        let mut count = 0;
+        for i in 0..self.term_builder().next_term_index {
+            let term = &self.query_desc().terms[i as usize];
+            // the ref ids may carry traversal and kind flags, only the entity part matters
+            let src = term.src.id & !ECS_TERM_REF_FLAGS;
+            if term.oper != OperKind::Optional as i16 || (src != 0 && src != ECS_THIS) {
+                continue;
+            }
+            let first = term.first.id & !ECS_TERM_REF_FLAGS;
+            let second = term.second.id & !ECS_TERM_REF_FLAGS;
+            let id = if term.id != 0 {
+                term.id
+            } else if second != 0 {
+                ecs_pair(first, second)
+            } else {
+                first
+            };
+            if id != 0 {
+                ids[count] = id;
+                count += 1;
+            }
+        }
+
+        assert!(
+            count > 0,
+            "with_any_optional() requires at least one optional term"
+        );
+
+        for (i, id) in ids[..count].iter().enumerate() {
+            self.with(*id).set_inout_none();
+            if i + 1 < count {
+                self.or();
+            }
+        }
+        self
+    }
+
     /// Term notation for more complex query features
     ///
     /// sets the current term to next one in term list
@@ -1123,6 +1327,68 @@ pub trait QueryBuilderImpl<'a>: TermBuilderImpl<'a> {
         self
     }
 
+    /// Sorts the output of a query using a comparator that returns [`Ordering`](core::cmp::Ordering).
+    ///
+    /// This is a convenience over [`order_by()`](Self::order_by) for comparators that only
+    /// look at the component values, such as sorting sprites by a `ZOrder` component.
+    ///
+    /// Sorting requires a cached query; the query is cached automatically unless an
+    /// uncached [`QueryCacheKind`] is set explicitly. Sorting is
+    /// not free: entities are resorted when an iterator is obtained and the sorted
+    /// component or the table order changed since the last iteration.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T`: The component used to sort.
+    ///
+    /// # Arguments
+    ///
+    /// * `compare`: The compare function used to sort the components.
+    ///   The signature of the function must be `fn(&T, &T) -> Ordering`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct ZOrder(i32);
+    ///
+    /// let world = World::new();
+    ///
+    /// world.entity().set(ZOrder(3));
+    /// world.entity().set(ZOrder(1));
+    /// world.entity().set(ZOrder(2));
+    ///
+    /// let q = world
+    ///     .query::<&ZOrder>()
+    ///     .order_by_cmp::<ZOrder>(|a: &ZOrder, b: &ZOrder| a.0.cmp(&b.0))
+    ///     .build();
+    ///
+    /// let mut order = Vec::new();
+    /// q.each(|z| order.push(z.0));
+    /// assert_eq!(order, [1, 2, 3]);
+    /// ```
+    fn order_by_cmp<T>(&mut self, compare: impl OrderByCmpFn<T>) -> &mut Self
+    where
+        T: ComponentId,
+        Self: QueryBuilderImpl<'a>,
+    {
+        const {
+            assert!(
+                !(T::IS_REF || T::IS_MUT),
+                "order_by_cmp<T> requires T to not be a reference"
+            );
+        }
+
+        let cmp: sys::ecs_order_by_action_t = Some(unsafe {
+            core::mem::transmute::<OrderByFnPtr<T>, OrderByFnPtrUnsafe>(compare.to_extern_fn())
+        });
+
+        self.__internal_order_by_id(T::entity_id(self.world()), cmp);
+        self
+    }
+
     #[doc(hidden)]
     fn __internal_order_by_id(
         &mut self,
@@ -1263,6 +1529,35 @@ where
     }
 }
 
+pub trait OrderByCmpFn<T>
+where
+    T: ComponentId,
+{
+    fn to_extern_fn(self) -> OrderByFnPtr<T>;
+}
+
+impl<F, T: ComponentId> OrderByCmpFn<T> for F
+where
+    F: Fn(&T, &T) -> core::cmp::Ordering,
+{
+    fn to_extern_fn(self) -> OrderByFnPtr<T> {
+        const {
+            assert!(core::mem::size_of::<Self>() == 0);
+        }
+        core::mem::forget(self);
+
+        #[extern_abi]
+        fn output<F, T>(_e1: Entity, e1_data: &T, _e2: Entity, e2_data: &T) -> i32
+        where
+            F: Fn(&T, &T) -> core::cmp::Ordering,
+        {
+            (unsafe { core::mem::transmute_copy::<_, F>(&()) })(e1_data, e2_data) as i32
+        }
+
+        output::<F, T>
+    }
+}
+
 pub trait OrderByFnVoid {
     fn to_extern_fn(self) -> OrderByFnVoidPtr;
 }
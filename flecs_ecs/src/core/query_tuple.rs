@@ -148,9 +148,17 @@ pub trait IterableTypeOperation {
     type OnlyPairType: ComponentId;
     const IS_IMMUTABLE: bool;
     const IS_OPTIONAL: bool;
+    /// Whether the term only affects matching and provides no data, see [`With`] and [`Without`].
+    const IS_FILTER: bool;
 
     fn populate_term(term: &mut sys::ecs_term_t);
 
+    /// Pointer to the field data of the term at `index` for the current table.
+    #[inline(always)]
+    fn field_ptr(it: &sys::ecs_iter_t, index: i8) -> *mut u8 {
+        flecs_field::<Self::OnlyPairType>(it, index) as *mut u8
+    }
+
     fn create_tuple_data<'a>(array_components_data: *mut u8, index: usize) -> Self::ActualType<'a>;
 
     fn create_tuple_with_ref_data<'a>(
@@ -171,6 +179,7 @@ where
     type OnlyPairType = <T as ComponentOrPairId>::CastType;
     const IS_IMMUTABLE: bool = true;
     const IS_OPTIONAL: bool = false;
+    const IS_FILTER: bool = false;
 
     #[inline(always)]
     fn populate_term(term: &mut sys::ecs_term_t) {
@@ -211,6 +220,7 @@ where
     type OnlyPairType = <T as ComponentOrPairId>::CastType;
     const IS_IMMUTABLE: bool = false;
     const IS_OPTIONAL: bool = false;
+    const IS_FILTER: bool = false;
 
     #[inline(always)]
     fn populate_term(term: &mut sys::ecs_term_t) {
@@ -251,6 +261,7 @@ where
     type OnlyPairType = <T as ComponentOrPairId>::CastType;
     const IS_IMMUTABLE: bool = true;
     const IS_OPTIONAL: bool = true;
+    const IS_FILTER: bool = false;
 
     fn populate_term(term: &mut sys::ecs_term_t) {
         term.inout = InOutKind::In as i16;
@@ -295,6 +306,7 @@ where
     type OnlyPairType = <T as ComponentOrPairId>::CastType;
     const IS_IMMUTABLE: bool = false;
     const IS_OPTIONAL: bool = true;
+    const IS_FILTER: bool = false;
 
     #[inline(always)]
     fn populate_term(term: &mut sys::ecs_term_t) {
@@ -329,6 +341,147 @@ where
     }
 }
 
+/// Query tuple term that only matches entities that have `T`, without
+/// providing its data. The term yields `()` in the callback.
+///
+/// This is the type-level equivalent of [`with()`](QueryBuilderImpl::with) and
+/// also works for tags and pairs.
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// #[derive(Component)]
+/// struct Frozen;
+///
+/// #[derive(Component)]
+/// struct Dead;
+///
+/// let world = World::new();
+///
+/// world.entity().set(Position { x: 1.0, y: 2.0 }).add(Frozen);
+/// world
+///     .entity()
+///     .set(Position { x: 3.0, y: 4.0 })
+///     .add(Frozen)
+///     .add(Dead);
+/// world.entity().set(Position { x: 5.0, y: 6.0 });
+///
+/// let query = world.new_query::<(&Position, With<Frozen>, Without<Dead>)>();
+///
+/// let mut count = 0;
+/// query.each(|(pos, (), ())| {
+///     assert_eq!(pos.x, 1.0);
+///     count += 1;
+/// });
+///
+/// assert_eq!(count, 1);
+/// ```
+///
+/// # See also
+///
+/// * [`Without`]
+/// * [`QueryBuilderImpl::with()`]
+pub struct With<T>(PhantomData<T>);
+
+/// Query tuple term that only matches entities that don't have `T`. The term
+/// yields `()` in the callback.
+///
+/// This is the type-level equivalent of [`without()`](QueryBuilderImpl::without).
+/// See [`With`] for an example.
+///
+/// # See also
+///
+/// * [`With`]
+/// * [`QueryBuilderImpl::without()`]
+pub struct Without<T>(PhantomData<T>);
+
+impl<T> IterableTypeOperation for With<T>
+where
+    T: ComponentOrPairId,
+{
+    type CastType = *const ();
+    type ActualType<'w> = ();
+    type SliceType<'w> = ();
+    type OnlyType = T;
+    type OnlyPairType = <T as ComponentOrPairId>::CastType;
+    const IS_IMMUTABLE: bool = true;
+    const IS_OPTIONAL: bool = false;
+    const IS_FILTER: bool = true;
+
+    #[inline(always)]
+    fn populate_term(term: &mut sys::ecs_term_t) {
+        term.inout = InOutKind::None as i16;
+    }
+
+    #[inline(always)]
+    fn field_ptr(_it: &sys::ecs_iter_t, _index: i8) -> *mut u8 {
+        core::ptr::null_mut()
+    }
+
+    #[inline(always)]
+    fn create_tuple_data<'a>(
+        _array_components_data: *mut u8,
+        _index: usize,
+    ) -> Self::ActualType<'a> {
+    }
+
+    #[inline(always)]
+    fn create_tuple_with_ref_data<'a>(
+        _array_components_data: *mut u8,
+        _is_ref: bool,
+        _index: usize,
+    ) -> Self::ActualType<'a> {
+    }
+}
+
+impl<T> IterableTypeOperation for Without<T>
+where
+    T: ComponentOrPairId,
+{
+    type CastType = *const ();
+    type ActualType<'w> = ();
+    type SliceType<'w> = ();
+    type OnlyType = T;
+    type OnlyPairType = <T as ComponentOrPairId>::CastType;
+    const IS_IMMUTABLE: bool = true;
+    const IS_OPTIONAL: bool = false;
+    const IS_FILTER: bool = true;
+
+    #[inline(always)]
+    fn populate_term(term: &mut sys::ecs_term_t) {
+        term.inout = InOutKind::None as i16;
+        term.oper = OperKind::Not as i16;
+    }
+
+    #[inline(always)]
+    fn field_ptr(_it: &sys::ecs_iter_t, _index: i8) -> *mut u8 {
+        core::ptr::null_mut()
+    }
+
+    #[inline(always)]
+    fn create_tuple_data<'a>(
+        _array_components_data: *mut u8,
+        _index: usize,
+    ) -> Self::ActualType<'a> {
+    }
+
+    #[inline(always)]
+    fn create_tuple_with_ref_data<'a>(
+        _array_components_data: *mut u8,
+        _is_ref: bool,
+        _index: usize,
+    ) -> Self::ActualType<'a> {
+    }
+}
+
 pub trait QueryTuple: Sized {
     type Pointers: ComponentPointers<Self>;
     type TupleType<'a>;
@@ -406,8 +559,8 @@ where
 {
     type Pointers = ComponentsData<A, 1>;
     type TupleType<'w> = A::ActualType<'w>;
-    const CONTAINS_ANY_TAG_TERM: bool = <<A::OnlyPairType as ComponentId>::UnderlyingType as ComponentInfo>::IS_TAG;
-    const IS_SPARSE_QUERY: bool = !<<A::OnlyPairType as ComponentId>::UnderlyingType as ComponentInfo>::IS_TAG
+    const CONTAINS_ANY_TAG_TERM: bool = !A::IS_FILTER && <<A::OnlyPairType as ComponentId>::UnderlyingType as ComponentInfo>::IS_TAG;
+    const IS_SPARSE_QUERY: bool = !A::IS_FILTER && !<<A::OnlyPairType as ComponentId>::UnderlyingType as ComponentInfo>::IS_TAG
         && <<A::OnlyPairType as ComponentId>::UnderlyingType as ComponentInfo>::IS_DONT_FRAGMENT
         && !matches!(<<A::OnlyPairType as ComponentId>::UnderlyingType as ComponentInfo>::ON_INSTANTIATE, OnInstantiatePolicy::Inherit);
     const COUNT : i32 = 1;
@@ -422,7 +575,7 @@ where
 
         let id = <A::OnlyType as ComponentOrPairId>::get_id(query.world());
 
-        if <A::OnlyType as ComponentOrPairId>::IS_PAIR {
+        if !A::IS_FILTER && <A::OnlyType as ComponentOrPairId>::IS_PAIR {
             ecs_assert!(
                 unsafe { sys::ecs_get_typeid(_world_ptr, id) } != 0,
                 FlecsErrorCode::InvalidOperation,
@@ -461,6 +614,13 @@ where
         indexes: &mut [i8],
         #[cfg(feature = "flecs_safety_locks")] table_records: &mut [TableColumnSafety],
     ) -> IsAnyArray {
+        if A::IS_FILTER {
+            return IsAnyArray {
+                a_ref: false,
+                a_row: false,
+            };
+        }
+
         #[cfg(feature = "flecs_safety_locks")]
         let tr = unsafe { table_records.get_unchecked_mut(0) };
         #[cfg(feature = "flecs_safety_locks")]
@@ -485,7 +645,7 @@ where
                 tr.component_id = unsafe { *it.ids.add(0) };
             }
         } else {
-            components[0] = flecs_field::<A::OnlyPairType>(it, 0) as *mut u8 ;
+            components[0] = A::field_ptr(it, 0);
             is_ref[0] = unsafe { *it.sources.add(0) != 0 };
         };
 
@@ -502,6 +662,10 @@ where
         #[cfg(feature = "flecs_safety_locks")] table_records: &mut [TableColumnSafety],
 
     ) {
+        if A::IS_FILTER {
+            return;
+        }
+
         #[cfg(feature = "flecs_safety_locks")]
         {
             let tr = unsafe { table_records.get_unchecked_mut(0) };
@@ -509,7 +673,7 @@ where
             tr.table = table;
             tr.column = column;
         }
-        components[0] = flecs_field::<A::OnlyPairType>(it, 0) as *mut u8 ;
+        components[0] = A::field_ptr(it, 0);
     }
 
     #[inline(always)]
@@ -580,10 +744,11 @@ macro_rules! impl_iterable {
                 $t::ActualType<'w>,
             )*);
 
-            const CONTAINS_ANY_TAG_TERM: bool = $(<<$t::OnlyPairType as ComponentId>::UnderlyingType as ComponentInfo>::IS_TAG ||)* false;
+            const CONTAINS_ANY_TAG_TERM: bool = $((!$t::IS_FILTER && <<$t::OnlyPairType as ComponentId>::UnderlyingType as ComponentInfo>::IS_TAG) ||)* false;
 
             const IS_SPARSE_QUERY: bool = (tuple_count!($($t),*) > 0) && $((
-                !<<$t::OnlyPairType as ComponentId>::UnderlyingType as ComponentInfo>::IS_TAG
+                !$t::IS_FILTER
+                && !<<$t::OnlyPairType as ComponentId>::UnderlyingType as ComponentInfo>::IS_TAG
                 && <<$t::OnlyPairType as ComponentId>::UnderlyingType as ComponentInfo>::IS_DONT_FRAGMENT
                 && !matches!(<<$t::OnlyPairType as ComponentId>::UnderlyingType as ComponentInfo>::ON_INSTANTIATE, OnInstantiatePolicy::Inherit)
             ) &&)* true;
@@ -603,7 +768,7 @@ macro_rules! impl_iterable {
                 $(
                     let id = <$t::OnlyType as ComponentOrPairId>::get_id(_world);
 
-                    if <$t::OnlyType as ComponentOrPairId>::IS_PAIR {
+                    if !$t::IS_FILTER && <$t::OnlyType as ComponentOrPairId>::IS_PAIR {
                         ecs_assert!(
                             unsafe { sys::ecs_get_typeid(_world_ptr, id) } != 0,
                             FlecsErrorCode::InvalidOperation,
@@ -663,7 +828,7 @@ macro_rules! impl_iterable {
                     let tr = unsafe { table_records.get_unchecked_mut(*idx) };
 
                     #[cfg(feature = "flecs_safety_locks")]
-                    {
+                    if !$t::IS_FILTER {
                         let (table, column) = unsafe { flecs_field_table_column(it, index) };
                         tr.table = table;
                         tr.column = column;
@@ -679,7 +844,9 @@ macro_rules! impl_iterable {
                     let val = 1u32 << index;
                     #[cfg(feature = "flecs_term_count_64")]
                     let val = 1u64 << index;
-                    if (it.row_fields & val) != 0 {
+                    if $t::IS_FILTER {
+                        // filter terms provide no data
+                    } else if (it.row_fields & val) != 0 {
                         // Need to fetch the value with flecs_field_at()
                         is_ref[index] =  true;
                         is_row[index] = true;
@@ -709,8 +876,7 @@ macro_rules! impl_iterable {
                             tr.component_id = row_id;
                         }
                     } else {
-                        components[index] =
-                            flecs_field::<$t::OnlyPairType>(it, index as i8) as *mut u8;
+                        components[index] = $t::field_ptr(it, index as i8);
                         let is_ref_val = unsafe { *it.sources.add(index ) != 0 };
                         is_ref[index] = is_ref_val;
                         any_ref |= is_ref_val;
@@ -756,8 +922,7 @@ macro_rules! impl_iterable {
                 #[cfg(feature = "flecs_safety_locks")]
                 let mut index_optional_mutable : usize = const { Self::COUNT_IMMUTABLE + Self::COUNT_MUTABLE + Self::COUNT_OPTIONAL_IMMUTABLE };
                 $(
-                    components[index] =
-                        flecs_field::<$t::OnlyPairType>(it, index as i8) as *mut u8;
+                    components[index] = $t::field_ptr(it, index as i8);
                     _mut_flags[index] = !$t::IS_IMMUTABLE;
                     let _ptr = components[index];
                     if _needs_alias_check && !_ptr.is_null() {
@@ -781,10 +946,12 @@ macro_rules! impl_iterable {
                             (false, true)  => &mut index_optional_mutable,
                         };
 
-                        let tr = unsafe { table_records.get_unchecked_mut(*idx) };
-                        let (table, column) = unsafe { flecs_field_table_column(it, index) };
-                        tr.table = table;
-                        tr.column = column;
+                        if !$t::IS_FILTER {
+                            let tr = unsafe { table_records.get_unchecked_mut(*idx) };
+                            let (table, column) = unsafe { flecs_field_table_column(it, index) };
+                            tr.table = table;
+                            tr.column = column;
+                        }
                         *idx += 1;
                     }
                     index += 1;
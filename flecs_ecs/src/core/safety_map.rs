@@ -492,7 +492,7 @@ fn __internal_do_read_write_locks<
 }
 
 #[inline(always)]
-fn component_id_from_table_column(table: *mut sys::ecs_table_t, column: i16) -> u64 {
+pub(crate) fn component_id_from_table_column(table: *mut sys::ecs_table_t, column: i16) -> u64 {
     unsafe {
         *(*sys::ecs_table_get_type(table))
             .array
@@ -1231,6 +1231,38 @@ impl FieldUntyped {
         }
     }
 
+    /// Pointer to the first element of the column.
+    ///
+    /// For shared fields this points to the single shared value.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *const c_void {
+        self.array
+    }
+
+    /// Size of the component type in bytes.
+    #[inline(always)]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Get the number of elements in the column.
+    ///
+    /// This is 1 for shared fields.
+    #[inline(always)]
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// whether the column / component is shared.
+    ///
+    /// # Returns
+    /// `true` if the column is shared, `false` otherwise.
+    #[inline(always)]
+    pub fn is_shared(&self) -> bool {
+        self.is_shared
+    }
+
     /// Get a pointer to the element at the specified index.
     ///
     /// # Panics
@@ -1323,6 +1355,49 @@ impl FieldUntypedMut {
         }
     }
 
+    /// Pointer to the first element of the column.
+    ///
+    /// For shared fields this points to the single shared value.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *const c_void {
+        self.array
+    }
+
+    /// Mutable pointer to the first element of the column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the field is shared.
+    #[inline(always)]
+    pub fn as_mut_ptr(&self) -> *mut c_void {
+        assert!(!self.is_shared, "Column is shared, cannot access mutably");
+        self.array
+    }
+
+    /// Size of the component type in bytes.
+    #[inline(always)]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Get the number of elements in the column.
+    ///
+    /// This is 1 for shared fields.
+    #[inline(always)]
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// whether the column / component is shared.
+    ///
+    /// # Returns
+    /// `true` if the column is shared, `false` otherwise.
+    #[inline(always)]
+    pub fn is_shared(&self) -> bool {
+        self.is_shared
+    }
+
     /// Get a pointer to the element at the specified index.
     ///
     /// # Panics
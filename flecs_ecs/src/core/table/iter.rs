@@ -202,6 +202,55 @@ where
         self.count
     }
 
+    /// Return whether the iterator has no entities for the current table.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Iterate the rows of the current table, yielding the row index together with
+    /// the entity at that row.
+    ///
+    /// This is shorthand for looping over `0..it.count()` and calling
+    /// [`TableIter::entity()`] for every row. The returned iterator borrows the
+    /// `TableIter`, so it cannot outlive the current table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world.entity().set(Position { x: 1.0, y: 2.0 });
+    ///
+    /// let query = world.new_query::<&Position>();
+    ///
+    /// query.run(|mut it| {
+    ///     while it.next() {
+    ///         let pos = it.field::<Position>(0);
+    ///         for (i, entity) in it.iter_rows() {
+    ///             assert_eq!(entity, e);
+    ///             assert_eq!(pos[i].x, 1.0);
+    ///         }
+    ///     }
+    /// });
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`TableIter::iter()`]
+    /// * [`TableIter::entity()`]
+    pub fn iter_rows(&self) -> impl ExactSizeIterator<Item = (usize, EntityView<'a>)> + '_ {
+        (0..self.count).map(move |row| (row, self.entity(row)))
+    }
+
     /// Return the delta time stored in the iterator.
     ///
     /// This is the time since the last frame.
@@ -239,6 +288,20 @@ where
         NonNull::new(self.iter.other_table).map(|ptr| unsafe { Table::new(self.real_world(), ptr) })
     }
 
+    /// Return the id of the current table.
+    ///
+    /// Table ids are unique for as long as the table exists, and entities keep the same
+    /// table while their archetype doesn't change, which makes the id usable as a key for
+    /// per-archetype caches. Returns 0 if the iterator has no table.
+    ///
+    /// # See also
+    ///
+    /// * [`TableIter::group_id()`]
+    /// * [`TableIter::table()`]
+    pub fn table_id(&self) -> u64 {
+        self.table().map_or(0, |table| table.id())
+    }
+
     pub fn range(&self) -> Option<TableRange<'a>> {
         self.table()
             .map(|t| TableRange::new(t, self.iter.offset, self.count as i32))
@@ -399,21 +462,95 @@ where
         unsafe { sys::ecs_field_size(self.iter, index) }
     }
 
-    /// Obtain field source (0 if This).
+    /// Obtain the entity that provided the data of a field.
+    ///
+    /// For fields matched on the iterated entities themselves this returns
+    /// entity `0`. For fields matched through traversal, such as components
+    /// inherited from a prefab or matched with `up`/`cascade`, this returns the
+    /// entity the component was found on.
     ///
     /// # Arguments
     ///
     /// * `index` - The field index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not smaller than the iterator's field count.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// #[flecs(traits((OnInstantiate, Inherit)))]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Velocity {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let base = world.prefab().set(Position { x: 1.0, y: 2.0 });
+    /// world
+    ///     .entity()
+    ///     .is_a(base)
+    ///     .set(Velocity { x: 0.5, y: 0.5 });
+    ///
+    /// world
+    ///     .new_query::<(&Position, &Velocity)>()
+    ///     .run(|mut it| {
+    ///         while it.next() {
+    ///             assert_eq!(it.src(0), base);
+    ///             assert_eq!(it.src(1), 0);
+    ///         }
+    ///     });
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`TableIter::is_self()`]
+    /// * [`TableIter::id()`]
+    /// * C API: `ecs_field_src`
     pub fn src(&self, index: usize) -> EntityView<'a> {
+        assert!(
+            index < self.iter.field_count as usize,
+            "field index {index} out of range (field_count = {})",
+            self.iter.field_count
+        );
         unsafe { EntityView::new_from(self.world(), sys::ecs_field_src(self.iter, index as i8)) }
     }
 
-    /// Obtain id matched for field.
+    /// Obtain the id matched for a field.
+    ///
+    /// For terms with wildcards this is the concrete id that was matched, for
+    /// example `(Likes, Apples)` for a `(Likes, *)` term.
     ///
     /// # Arguments
     ///
     /// * `index` - The field index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is negative or not smaller than the iterator's field count.
+    ///
+    /// # See also
+    ///
+    /// * [`TableIter::src()`]
+    /// * [`TableIter::pair()`]
+    /// * C API: `ecs_field_id`
     pub fn id(&self, index: i8) -> IdView<'a> {
+        assert!(
+            index >= 0 && index < self.iter.field_count,
+            "field index {index} out of range (field_count = {})",
+            self.iter.field_count
+        );
         unsafe { IdView::new_from_id(self.world(), sys::ecs_field_id(self.iter, index)) }
     }
 
@@ -1088,13 +1225,46 @@ where
 
     /// Get immutable access to untyped field data.
     ///
+    /// Use this when the component type isn't known at compile time, for example to
+    /// serialize arbitrary matched components. The returned [`FieldUntyped`] exposes
+    /// the column pointer, element size, element count and whether the field is shared.
+    /// The field is treated as a read for the safety checks; use
+    /// [`field_untyped_mut()`](Self::field_untyped_mut) for write access.
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.entity().set(Position { x: 1.0, y: 2.0 });
+    ///
+    /// world.new_query::<&Position>().run(|mut it| {
+    ///     while it.next() {
+    ///         let field = it.field_untyped(0);
+    ///         assert_eq!(field.size(), core::mem::size_of::<Position>());
+    ///         assert_eq!(field.len(), it.count());
+    ///         assert!(!field.is_shared());
+    ///     }
+    /// });
+    /// ```
+    ///
     /// # Arguments
     ///
-    /// * `field_handle` - The field handle.
+    /// * `index` - The field index.
     ///
     /// # Returns
     ///
     /// Returns a column object that can be used to access the field data.
+    ///
+    /// # See also
+    ///
+    /// * [`TableIter::size()`]
     #[inline(always)]
     pub fn field_untyped(&self, index: i8) -> FieldUntyped {
         #[cfg(any(debug_assertions, feature = "flecs_force_enable_ecs_asserts"))]
@@ -1625,14 +1795,19 @@ where
         unsafe { sys::ecs_iter_skip(self.iter) };
     }
 
-    /// # Returns
+    /// Return the group id of the current table.
     ///
-    /// Return group id for current table
+    /// The group id is the value returned by the `group_by` callback of the query for the
+    /// current table. Together with [`TableIter::table_id()`] it can be used to key
+    /// per-group or per-archetype caches.
     ///
-    /// # Safety
+    /// Only valid while iterating a cached query, which queries that use `group_by`
+    /// always are.
+    ///
+    /// # See also
     ///
-    /// grouped queries only
-    // TODO: this should be &self after I upgrade flecs
+    /// * [`QueryBuilderImpl::group_by()`]
+    /// * C API: `ecs_iter_get_group`
     pub fn group_id(&self) -> sys::ecs_id_t {
         unsafe { sys::ecs_iter_get_group(self.iter) }
     }
@@ -2502,3 +2677,27 @@ pub(crate) fn table_unlock(_world_ptr: *mut sys::ecs_world_t, _table_ptr: *mut s
         sys::ecs_table_unlock(_world_ptr, _table_ptr);
     }
 }
+
+/// Locks a table on construction and unlocks it on drop, so the table is
+/// unlocked even when the user callback unwinds.
+pub(crate) struct TableLockGuard {
+    world: *mut sys::ecs_world_t,
+    table: *mut sys::ecs_table_t,
+}
+
+impl TableLockGuard {
+    /// # Safety
+    ///
+    /// `world` and `table` must be valid pointers for the lifetime of the guard.
+    pub(crate) unsafe fn lock(world: *mut sys::ecs_world_t, table: *mut sys::ecs_table_t) -> Self {
+        unsafe { sys::ecs_table_lock(world, table) };
+        Self { world, table }
+    }
+}
+
+impl Drop for TableLockGuard {
+    fn drop(&mut self) {
+        // SAFETY: the caller of `lock` guaranteed the pointers outlive the guard.
+        unsafe { sys::ecs_table_unlock(self.world, self.table) };
+    }
+}
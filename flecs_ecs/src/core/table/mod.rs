@@ -111,6 +111,7 @@ pub(crate) use field::{flecs_field, flecs_field_with_size};
 pub use multi_src_get::*;
 
 pub use flags::TableFlags;
+pub(crate) use iter::TableLockGuard;
 pub use iter::{FieldError, TableIter};
 #[cfg(any(debug_assertions, feature = "flecs_force_enable_ecs_asserts"))]
 pub(crate) use iter::{table_lock, table_unlock};
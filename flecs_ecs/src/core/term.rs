@@ -314,7 +314,18 @@ pub trait TermBuilderImpl<'a>: Sized + WorldProvider<'a> + internals::QueryConfi
         Entity(id)
     }
 
-    /// The self flag indicates the term identifier itself is used
+    /// The self flag indicates the term identifier itself is used.
+    ///
+    /// On the source of a term this means the component must be owned by the
+    /// matched entity. Combine it with [`up()`](TermBuilderImpl::up) or
+    /// [`up_id()`](TermBuilderImpl::up_id) to match the component either on the
+    /// entity or, if it doesn't have it, on an ancestor. This is the same as the
+    /// `self up` modifiers in the query DSL.
+    ///
+    /// # See also
+    ///
+    /// * [`TermBuilderImpl::up()`]
+    /// * C API: `EcsSelf`
     fn self_(&mut self) -> &mut Self {
         self.term_ref_mut().id |= ECS_SELF;
         self
@@ -495,6 +506,42 @@ pub trait TermBuilderImpl<'a>: Sized + WorldProvider<'a> + internals::QueryConfi
     /// * initialize with id or
     /// * initialize it with name. If name starts with a $
     ///   the name is interpreted as a variable.
+    ///
+    /// Terms that use the same variable are joined on it. A variable can also be
+    /// used as the source of a term with [`set_src()`](Self::set_src).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct LocatedIn;
+    ///
+    /// #[derive(Component)]
+    /// struct HasClimate;
+    ///
+    /// let world = World::new();
+    ///
+    /// let tropical = world.entity();
+    /// let jungle = world.entity().add((HasClimate::id(), tropical));
+    /// let parrot = world.entity().add((LocatedIn::id(), jungle));
+    ///
+    /// // (LocatedIn, $x), (HasClimate, tropical)($x)
+    /// let q = world
+    ///     .query::<()>()
+    ///     .term()
+    ///     .set_first(LocatedIn::id())
+    ///     .set_second("$x")
+    ///     .term()
+    ///     .set_first(HasClimate::id())
+    ///     .set_second(tropical)
+    ///     .set_src("$x")
+    ///     .build();
+    ///
+    /// assert_eq!(q.count(), 1);
+    /// assert_eq!(q.first_entity(), parrot);
+    /// ```
     fn set_second<'s, T: SingleAccessArg<'s>>(&mut self, id: T) -> &mut Self
     where
         Access<'s>: FromAccessArg<T>,
@@ -530,6 +577,45 @@ pub trait TermBuilderImpl<'a>: Sized + WorldProvider<'a> + internals::QueryConfi
     /// The up flag indicates that the term identifier may be substituted by
     /// traversing a relationship upwards. For example: substitute the identifier
     /// with its parent by traversing the `ChildOf` relationship.
+    ///
+    /// Without [`self_()`](TermBuilderImpl::self_) the term only matches the
+    /// component on an ancestor, never on the entity itself; entities that own the
+    /// component still match if an ancestor has it too. When no relationship is
+    /// provided, `ChildOf` is traversed. This is the same as the `up` modifier in
+    /// the query DSL.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let parent = world.entity().set(Position { x: 1.0, y: 2.0 });
+    /// let child = world.entity().child_of(parent);
+    ///
+    /// // only matches entities whose parent has Position
+    /// let q = world.query::<&Position>().term_at(0).up().build();
+    /// assert_eq!(q.count(), 1);
+    /// assert_eq!(q.first_entity(), child);
+    ///
+    /// // matches Position on the entity itself or on its parent
+    /// let q = world.query::<&Position>().term_at(0).self_().up().build();
+    /// assert_eq!(q.count(), 2);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`TermBuilderImpl::up_id()`]
+    /// * [`TermBuilderImpl::self_()`]
+    /// * [`TermBuilderImpl::cascade()`]
+    /// * C API: `EcsUp`
     #[inline]
     fn up(&mut self) -> &mut Self {
         ecs_assert!(
@@ -551,9 +637,19 @@ pub trait TermBuilderImpl<'a>: Sized + WorldProvider<'a> + internals::QueryConfi
     /// traversing a relationship upwards. For example: substitute the identifier
     /// with its parent by traversing the `ChildOf` relationship.
     ///
+    /// Like [`up()`](TermBuilderImpl::up), but traverses `traverse_relationship`
+    /// instead of `ChildOf`, e.g. [`flecs::IsA`] to match components inherited from a
+    /// prefab. The relationship must have the [`flecs::Traversable`] trait. This is the
+    /// same as `up Relationship` in the query DSL.
+    ///
     /// # Arguments
     ///
     /// * `traverse_relationship` - The relationship to traverse.
+    ///
+    /// # See also
+    ///
+    /// * [`TermBuilderImpl::up()`]
+    /// * [`TermBuilderImpl::self_()`]
     fn up_id(&mut self, traverse_relationship: impl IntoEntity) -> &mut Self {
         ecs_assert!(
             self.current_term_ref_mode() == TermRefMode::Src,
@@ -851,7 +947,40 @@ pub trait TermBuilderImpl<'a>: Sized + WorldProvider<'a> + internals::QueryConfi
         self.set_oper(OperKind::NotFrom)
     }
 
-    /// Query terms are not triggered on by observers
+    /// Mark the current term as a filter.
+    ///
+    /// Filter terms participate in matching but are not accessed, and observers are not
+    /// triggered by events for filter terms. In the DSL macros this is written as `[filter] T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Bullet;
+    ///
+    /// #[derive(Component)]
+    /// struct Networked;
+    ///
+    /// let world = World::new();
+    ///
+    /// // fires when `Bullet` is added to an entity that has `Networked`,
+    /// // but not when `Networked` is added to an entity that has `Bullet`
+    /// world
+    ///     .observer::<flecs::OnAdd, ()>()
+    ///     .with(Bullet::id())
+    ///     .with(Networked::id())
+    ///     .filter()
+    ///     .each_entity(|e, _| {
+    ///         println!("networked bullet {}", e.name());
+    ///     });
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Self::set_inout_none`]
+    /// * [`InOutKind`]
     #[inline(always)]
     fn filter(&mut self) -> &mut Self {
         self.current_term_mut().inout = InOutKind::Filter as i16;
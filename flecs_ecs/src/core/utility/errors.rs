@@ -160,57 +160,33 @@ macro_rules! ecs_assert {
 #[allow(unused_macros)]
 macro_rules! ecs_abort {
     ($error_code:expr $(,)?) => {
-        #[cfg(feature = "std")]
-        extern crate std;
-
-        extern crate alloc;
-
-        let file = file!();
-        let line = line!();
-
-        eprintln!("{}:{}: {}", file, line, $error_code);
-
-        //unsafe {
-        //    if let Some(abort_func) = ecs_os_api.abort_ {
-        //        abort_func();
-        //    }
-        //};
-
-        std::process::abort();
+        $crate::core::utility::std_compat::abort(format_args!(
+            "{}:{}: {}",
+            file!(),
+            line!(),
+            $error_code
+        ))
     };
     ($error_code:expr, $msg:expr $(,)?) => {
-        eprintln!("{}: {}", $error_code, $msg);
-        //unsafe {
-        //    if let Some(abort_func) = ecs_os_api.abort_ {
-        //        abort_func();
-        //    }
-        //};
-        std::process::abort();
+        $crate::core::utility::std_compat::abort(format_args!("{}: {}", $error_code, $msg))
     };
     ($error_code:expr, $arg:ident: *const c_char $(,)?) => {
-        eprintln!("{}: {}",
+        $crate::core::utility::std_compat::abort(format_args!(
+            "{}: {}",
             $error_code,
             if $arg.is_null() {
                 "<null>"
             } else {
-                unsafe { CStr::from_ptr($arg).to_str().unwrap_or("<invalid>") }
+                unsafe { core::ffi::CStr::from_ptr($arg).to_str().unwrap_or("<invalid>") }
             }
-        );
-        //unsafe {
-        //    if let Some(abort_func) = ecs_os_api.abort_ {
-        //        abort_func();
-        //    }
-        //};
-        std::process::abort();
+        ))
     };
     ($error_code:expr, $fmt:expr, $($arg:tt)+) => {
-        eprintln!("{}: {}", $error_code, format!($fmt, $($arg)+));
-        //unsafe {
-        //    if let Some(abort_func) = ecs_os_api.abort_ {
-        //        abort_func();
-        //    }
-        //};
-        std::process::abort();
+        $crate::core::utility::std_compat::abort(format_args!(
+            "{}: {}",
+            $error_code,
+            format_args!($fmt, $($arg)+)
+        ))
     };
 }
 
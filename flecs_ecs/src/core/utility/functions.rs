@@ -501,11 +501,10 @@ pub fn ecs_bit_cond(flags: &mut u32, bit: u32, cond: bool) {
 
 /// Copies the given Rust &str to a C string and returns a pointer to the C string.
 /// this is intended to be used when the C code needs to take ownership of the string.
-///
-/// # Note
-///
-/// This function isn't being used anymore and might be removed in the future.
-#[expect(dead_code, reason = "possibly used in the future")]
+#[cfg_attr(
+    not(feature = "flecs_rest"),
+    expect(dead_code, reason = "only used by the REST addon")
+)]
 pub(crate) fn copy_and_allocate_c_char_from_rust_str(data: &str) -> *mut c_char {
     ecs_assert!(
         data.is_ascii(),
@@ -8,6 +8,7 @@ pub(crate) mod id_map;
 mod log;
 #[cfg(feature = "flecs_safety_locks")]
 pub(crate) mod safety;
+pub(crate) mod std_compat;
 pub mod traits;
 pub mod types;
 
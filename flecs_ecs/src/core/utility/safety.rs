@@ -16,6 +16,11 @@ pub(crate) fn rw_locking<T: GetTuple, Return, const MULTITHREADED: bool>(
         0 // stage_id is not used in single-threaded mode
     };
 
+    // The column locks are per stage, so borrows on different worker threads are checked
+    // against each other by address. Registered first so a conflict holds no column locks.
+    let _borrows =
+        MULTITHREADED.then(|| MultithreadedBorrows::begin(&world, components, safety_info));
+
     for (index, si) in safety_info.iter().enumerate() {
         use crate::core::SafetyInfo;
 
@@ -90,6 +95,87 @@ pub(crate) fn rw_locking<T: GetTuple, Return, const MULTITHREADED: bool>(
     ret
 }
 
+/// Borrows of component data registered with the world for the duration of a `get`
+/// callback on a worker thread. Released on drop, so a panicking callback doesn't leave
+/// them behind.
+struct MultithreadedBorrows<'a> {
+    world_ctx: &'a WorldCtx,
+    components: &'a [*mut core::ffi::c_void],
+    safety_info: &'a [SafetyInfo],
+    registered: usize,
+}
+
+impl<'a> MultithreadedBorrows<'a> {
+    fn begin(
+        world: &'a WorldRef,
+        components: &'a [*mut core::ffi::c_void],
+        safety_info: &'a [SafetyInfo],
+    ) -> Self {
+        let mut borrows = Self {
+            world_ctx: world.world_ctx(),
+            components,
+            safety_info,
+            registered: 0,
+        };
+        for (index, si) in safety_info.iter().enumerate() {
+            let ptr = components[index];
+            if !ptr.is_null() {
+                let (target, write) = match si {
+                    SafetyInfo::Read(target) => (target, false),
+                    SafetyInfo::Write(target) => (target, true),
+                };
+                if !borrows
+                    .world_ctx
+                    .multithreaded_borrow_begin(ptr as usize, write)
+                {
+                    panic!(
+                        "Cannot {}: component {} of this entity is already {} by another thread",
+                        if write { "set write" } else { "increment read" },
+                        lock_target_name(world, target),
+                        if write {
+                            "borrowed"
+                        } else {
+                            "borrowed mutably"
+                        },
+                    );
+                }
+            }
+            borrows.registered = index + 1;
+        }
+        borrows
+    }
+}
+
+impl Drop for MultithreadedBorrows<'_> {
+    fn drop(&mut self) {
+        for (index, si) in self.safety_info[..self.registered].iter().enumerate() {
+            let ptr = self.components[index];
+            if !ptr.is_null() {
+                self.world_ctx
+                    .multithreaded_borrow_end(ptr as usize, matches!(si, SafetyInfo::Write(_)));
+            }
+        }
+    }
+}
+
+fn lock_target_name(world: &WorldRef, target: &sys::ecs_lock_target_t) -> String {
+    let id = if target.cr.is_null() {
+        component_id_from_table_column(target.table, target.column_index)
+    } else {
+        unsafe { sys::flecs_component_get_id(target.cr) }
+    };
+    let id = IdView::new_from_id(world, id);
+    if id.is_pair() {
+        format!(
+            "({}, {})",
+            world.entity_from_id(id.first_id()),
+            world.entity_from_id(id.second_id())
+        )
+    } else {
+        format!("{}", id.entity_view())
+    }
+}
+
 #[cfg(feature = "flecs_safety_locks")]
 #[inline(always)]
 pub(crate) fn clone_locking<const MULTITHREADED: bool>(
@@ -0,0 +1,159 @@
+//! The few parts of `std` the core modules depend on, with fallbacks built on
+//! `core` and the Flecs OS API when the `std` feature is disabled.
+
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "std")]
+pub(crate) use std::sync::{Mutex, PoisonError};
+
+#[cfg(not(feature = "std"))]
+pub(crate) use spin::{Mutex, PoisonError};
+
+/// Identifies the thread a world was created on.
+#[cfg(feature = "std")]
+pub(crate) type ThreadId = std::thread::ThreadId;
+
+/// Identifies the thread a world was created on, as returned by the
+/// `thread_self_` callback of the Flecs OS API.
+#[cfg(not(feature = "std"))]
+pub(crate) type ThreadId = crate::sys::ecs_os_thread_id_t;
+
+/// Returns the id of the current thread.
+///
+/// Without `std` this uses the `thread_self_` callback of the Flecs OS API. If the
+/// OS API has no threading support all threads get the same id, which is fine since
+/// the world can then only be used from a single thread.
+#[inline]
+pub(crate) fn current_thread_id() -> ThreadId {
+    #[cfg(feature = "std")]
+    {
+        std::thread::current().id()
+    }
+
+    #[cfg(not(feature = "std"))]
+    {
+        // SAFETY: reading a field of the OS API struct, which is only written while
+        // the OS API is initialized, before any world exists.
+        let thread_self = unsafe { crate::sys::ecs_os_api.thread_self_ };
+        // SAFETY: the callback is provided by the OS API implementation and takes no arguments.
+        thread_self.map_or(0, |thread_self| unsafe { thread_self() })
+    }
+}
+
+/// Returns whether the current thread is unwinding because of a panic.
+///
+/// Without `std` there is no way to tell, so this always returns `false`. `no_std`
+/// targets typically abort on panic, in which case nothing is dropped anyway.
+#[inline]
+pub(crate) fn thread_panicking() -> bool {
+    #[cfg(feature = "std")]
+    {
+        std::thread::panicking()
+    }
+
+    #[cfg(not(feature = "std"))]
+    {
+        false
+    }
+}
+
+/// Prints `message` and aborts the process.
+///
+/// Without `std` there is no standard error stream or process to abort, so this
+/// panics with `message` instead and leaves it to the panic handler.
+#[cold]
+#[allow(clippy::print_stderr, reason = "last words before process abort")]
+pub(crate) fn abort(message: core::fmt::Arguments) -> ! {
+    #[cfg(feature = "std")]
+    {
+        std::eprintln!("{message}");
+        std::process::abort()
+    }
+
+    #[cfg(not(feature = "std"))]
+    {
+        panic!("{message}")
+    }
+}
+
+/// A spin lock with the subset of the `std::sync::Mutex` API used by the crate.
+#[cfg(not(feature = "std"))]
+mod spin {
+    use core::cell::UnsafeCell;
+    use core::ops::{Deref, DerefMut};
+    use core::sync::atomic::{AtomicBool, Ordering};
+
+    pub(crate) struct Mutex<T> {
+        locked: AtomicBool,
+        data: UnsafeCell<T>,
+    }
+
+    // SAFETY: access to `data` is serialized by `locked`, like `std::sync::Mutex`.
+    unsafe impl<T: Send> Send for Mutex<T> {}
+    // SAFETY: access to `data` is serialized by `locked`, like `std::sync::Mutex`.
+    unsafe impl<T: Send> Sync for Mutex<T> {}
+
+    impl<T> Mutex<T> {
+        pub(crate) const fn new(value: T) -> Self {
+            Self {
+                locked: AtomicBool::new(false),
+                data: UnsafeCell::new(value),
+            }
+        }
+
+        /// Spins until the lock is acquired. A spin lock can't be poisoned, so this
+        /// never returns an error.
+        pub(crate) fn lock(&self) -> Result<MutexGuard<'_, T>, PoisonError<MutexGuard<'_, T>>> {
+            while self
+                .locked
+                .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+                .is_err()
+            {
+                core::hint::spin_loop();
+            }
+            Ok(MutexGuard { mutex: self })
+        }
+    }
+
+    pub(crate) struct MutexGuard<'a, T> {
+        mutex: &'a Mutex<T>,
+    }
+
+    impl<T> Deref for MutexGuard<'_, T> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            // SAFETY: the guard holds the lock.
+            unsafe { &*self.mutex.data.get() }
+        }
+    }
+
+    impl<T> DerefMut for MutexGuard<'_, T> {
+        fn deref_mut(&mut self) -> &mut T {
+            // SAFETY: the guard holds the lock, and `&mut self` makes this the only reference.
+            unsafe { &mut *self.mutex.data.get() }
+        }
+    }
+
+    impl<T> Drop for MutexGuard<'_, T> {
+        fn drop(&mut self) {
+            self.mutex.locked.store(false, Ordering::Release);
+        }
+    }
+
+    /// Mirrors `std::sync::PoisonError`, never constructed since a spin lock can't be poisoned.
+    pub(crate) struct PoisonError<T>(T);
+
+    impl<T> PoisonError<T> {
+        pub(crate) fn into_inner(self) -> T {
+            self.0
+        }
+    }
+
+    impl<T> core::fmt::Debug for PoisonError<T> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.debug_struct("PoisonError").finish_non_exhaustive()
+        }
+    }
+}
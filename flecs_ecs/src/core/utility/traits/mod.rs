@@ -562,7 +562,10 @@ pub(crate) fn internal_each_generic<
             } else {
                 let e = unsafe { (*iter.query).entity };
                 if e == 0 {
-                    (crate::core::Entity(0), Some("<unnamed>".to_string()))
+                    (
+                        crate::core::Entity(0),
+                        Some(alloc::string::String::from("<unnamed>")),
+                    )
                 } else {
                     let e = _world.entity_from_id(e);
                     (e.id(), e.get_name())
@@ -8,6 +8,7 @@ extern crate std;
 
 extern crate alloc;
 use alloc::string::String;
+use alloc::vec::Vec;
 
 use flecs_ecs_derive::extern_abi;
 
@@ -80,6 +81,14 @@ where
     /// The following function signatures is valid:
     ///  - func(comp1 : &mut T1, comp2 : &mut T2, ...)
     ///
+    /// # Shared fields
+    ///
+    /// A term that is matched on another entity than the iterated one, such as a component
+    /// inherited from a prefab with the `(OnInstantiate, Inherit)` trait, a parent component
+    /// matched with `up` or a singleton, is shared by all entities of a table. Such a term is
+    /// passed as a reference to the single shared value, so every entity of the table receives
+    /// the same `&T`.
+    ///
     /// # Safety
     ///
     /// When the `flecs_safety_locks` feature is enabled, this function performs runtime checks
@@ -288,6 +297,86 @@ where
         }
     }
 
+    /// Each entity iterator that only visits tables whose data changed.
+    ///
+    /// Works like [`each_entity()`](QueryAPI::each_entity), but before a table is
+    /// iterated its change state is checked. Unchanged tables are skipped with
+    /// [`TableIter::skip()`], so they are neither visited nor marked dirty by this
+    /// query. This wraps the usual `run` loop that calls [`TableIter::is_changed()`]
+    /// and [`TableIter::skip()`] for every table.
+    ///
+    /// A table counts as changed when it was not iterated by this query before, or
+    /// when one of the queried components in it was written since the last time this
+    /// query iterated it (through `set`, `modified` or a query/system with write access).
+    ///
+    /// # Panics
+    ///
+    /// The query must be created with
+    /// [`detect_changes()`](crate::core::QueryBuilderImpl::detect_changes).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Npc;
+    ///
+    /// let world = World::new();
+    ///
+    /// let player = world.entity().set(Position { x: 0.0, y: 0.0 });
+    /// world.entity().add(Npc).set(Position { x: 1.0, y: 1.0 });
+    ///
+    /// let query = world.query::<&Position>().detect_changes().build();
+    ///
+    /// let mut visited = 0;
+    /// query.each_changed(|_e, _pos| visited += 1);
+    /// assert_eq!(visited, 2);
+    ///
+    /// player.set(Position { x: 5.0, y: 0.0 });
+    ///
+    /// let mut visited = Vec::new();
+    /// query.each_changed(|e, _pos| visited.push(e.id()));
+    /// assert_eq!(visited, [player.id()]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Query::is_changed()`]
+    /// * [`TableIter::is_changed()`]
+    /// * [`TableIter::skip()`]
+    fn each_changed(&self, mut func: impl FnMut(EntityView, T::TupleType<'_>)) {
+        let world = self.world();
+        let mut iter = self.retrieve_iter();
+        #[cfg(feature = "flecs_safety_locks")]
+        let has_row_fields = iter.row_fields != 0;
+
+        while self.iter_next(&mut iter) {
+            // SAFETY: `iter` is a live query iterator positioned on the table that was just
+            // returned by `iter_next`, which is what `ecs_iter_changed` and `ecs_iter_skip` expect.
+            if !unsafe { sys::ecs_iter_changed(&mut iter) } {
+                unsafe { sys::ecs_iter_skip(&mut iter) };
+                continue;
+            }
+
+            #[cfg(not(feature = "flecs_safety_locks"))]
+            internal_each_entity_iter_next::<T, false, false>(&mut iter, &world, &mut func);
+
+            #[cfg(feature = "flecs_safety_locks")]
+            if has_row_fields {
+                internal_each_entity_iter_next::<T, false, true>(&mut iter, &world, &mut func);
+            } else {
+                internal_each_entity_iter_next::<T, false, false>(&mut iter, &world, &mut func);
+            }
+        }
+    }
+
     /// Each iterator. This variant of `each` provides access to the [`TableIter`] object,
     /// which contains more information about the object being iterated.
     /// The `usize` argument contains the index of the entity being iterated,
@@ -439,6 +528,26 @@ where
     /// The following function signatures is valid:
     ///  - func(comp1 : &mut T1, comp2 : &mut T2, ...)
     ///
+    /// Iteration stops at the first entity for which `func` returns true; the remaining
+    /// tables are not visited and the iterator is finalized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Health(i32);
+    ///
+    /// let world = World::new();
+    ///
+    /// world.entity().set(Health(100));
+    /// let hurt = world.entity().set(Health(10));
+    ///
+    /// let found = world.new_query::<&Health>().find(|h| h.0 < 50);
+    /// assert_eq!(found.map(|e| e.id()), Some(hurt.id()));
+    /// ```
+    ///
     /// # Returns
     ///
     /// * `Some(EntityView<'_>)` if the entity was found, `None` if no entity was found.
@@ -459,6 +568,10 @@ where
                         world_ptr,
                         &world,
                     );
+                    if entity.is_some() {
+                        sys::ecs_iter_fini(&mut iter);
+                        break;
+                    }
                 }
             } else {
                 while self.iter_next(&mut iter) {
@@ -469,6 +582,10 @@ where
                         world_ptr,
                         &world,
                     );
+                    if entity.is_some() {
+                        sys::ecs_iter_fini(&mut iter);
+                        break;
+                    }
                 }
             }
 
@@ -482,6 +599,10 @@ where
                         world_ptr,
                         &world,
                     );
+                    if entity.is_some() {
+                        sys::ecs_iter_fini(&mut iter);
+                        break;
+                    }
                 }
             }
             entity
@@ -494,6 +615,9 @@ where
     /// The following function signatures is valid:
     ///  - func(entity : Entity, comp1 : &mut T1, comp2 : &mut T2, ...)
     ///
+    /// Iteration stops at the first entity for which `func` returns true; the remaining
+    /// tables are not visited and the iterator is finalized.
+    ///
     /// # Returns
     ///
     /// * `Some(EntityView<'_>)` if the entity was found, `None` if no entity was found.
@@ -517,6 +641,10 @@ where
                         world_ptr,
                         &world,
                     );
+                    if entity_result.is_some() {
+                        sys::ecs_iter_fini(&mut iter);
+                        break;
+                    }
                 }
             } else {
                 while self.iter_next(&mut iter) {
@@ -527,6 +655,10 @@ where
                         world_ptr,
                         &world,
                     );
+                    if entity_result.is_some() {
+                        sys::ecs_iter_fini(&mut iter);
+                        break;
+                    }
                 }
             }
 
@@ -540,12 +672,97 @@ where
                         world_ptr,
                         &world,
                     );
+                    if entity_result.is_some() {
+                        sys::ecs_iter_fini(&mut iter);
+                        break;
+                    }
                 }
             }
             entity_result
         }
     }
 
+    /// Drain iterator.
+    ///
+    /// Invokes `func` for each matching entity that has component `C`, with the
+    /// component value moved out of storage, and removes `C` from the entity.
+    /// The removals are deferred until iteration is done, so archetypes don't
+    /// change while the query is being iterated. Matched entities without `C`
+    /// (for example when `C` is not a term of the query) are skipped.
+    ///
+    /// The value is taken with [`core::mem::take`], which leaves a default value
+    /// in storage until the deferred removal drops it. `OnRemove` observers and
+    /// hooks for `C` therefore see that default value, not the drained one. The
+    /// drained value itself is owned by `func` and dropped exactly once, wherever
+    /// `func` lets it go.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `C` - The component to drain.
+    ///
+    /// # Arguments
+    ///
+    /// * `func` - The callback receiving the entity and the drained value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component, Default)]
+    /// struct Command {
+    ///     damage: i32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Health(i32);
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world
+    ///     .entity()
+    ///     .set(Health(100))
+    ///     .set(Command { damage: 30 });
+    ///
+    /// world.new_query::<&Command>().drain(|e, cmd: Command| {
+    ///     e.get::<&mut Health>(|health| health.0 -= cmd.damage);
+    /// });
+    ///
+    /// assert!(!e.has(Command::id()));
+    /// e.get::<&Health>(|health| assert_eq!(health.0, 70));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`QueryAPI::each_entity()`]
+    /// * [`World::defer()`]
+    fn drain<C>(&self, mut func: impl FnMut(EntityView, C))
+    where
+        C: ComponentId + DataComponent + Default,
+    {
+        let world = self.world();
+        let world_ptr = world.world_ptr_mut();
+        let id = C::entity_id(world);
+
+        // ends the defer block even when `func` unwinds, after the iterator is finalized
+        let _defer_guard = DeferGuard::new(world);
+        let mut iter = IterFiniGuard(self.retrieve_iter());
+        while self.iter_next(&mut iter.0) {
+            for i in 0..iter.0.count as usize {
+                // SAFETY: `i` is within the `count` entities of the current result.
+                let entity = EntityView::new_from(world, unsafe { *iter.0.entities.add(i) });
+                // the value is taken through `try_get` so the component locks are respected.
+                // The default value left behind is dropped by the deferred removal.
+                let Some(value) = entity.try_get::<&mut C>(|c| core::mem::take(c)) else {
+                    continue;
+                };
+                // SAFETY: removals are queued while deferred and applied after iteration.
+                unsafe { sys::ecs_remove_id(world_ptr, *entity.id, id) };
+                func(entity, value);
+            }
+        }
+    }
+
     /// Run iterator.
     ///
     /// The "run" iterator accepts a function that is invoked for each matching
@@ -615,6 +832,15 @@ where
     /// //  Entity name:  -- id: 512 -- archetype: flecs_ecs.main.Tag, flecs_ecs.main.Position, flecs_ecs.main.Velocity: Position { x: 0, y: 0 }
     /// //  end operations
     /// ```
+    ///
+    /// # Shared fields
+    ///
+    /// Queries are always instanced: a result contains all entities of a table, also when some
+    /// of its terms are matched on another entity, such as a component inherited from a prefab
+    /// with the `(OnInstantiate, Inherit)` trait, a parent component matched with `up` or a
+    /// singleton. The field of such a term holds a single value shared by all entities of the
+    /// result, which can be checked with [`Field::is_shared()`] or [`TableIter::is_self()`];
+    /// index it with `0` instead of the row. There is no separate `instanced` query option.
     fn run(&self, mut func: impl FnMut(TableIter<true, P>))
     where
         P: ComponentId,
@@ -864,6 +1090,74 @@ where
         }
     }
 
+    /// Table iterator.
+    ///
+    /// The "iter" iterator accepts a function that is invoked once for each
+    /// matching table. Unlike [`run`](QueryAPI::run), the query drives the
+    /// `next()` loop itself, so the iterator is always iterated to completion and
+    /// the table is locked only while `func` runs for it. This makes it the
+    /// recommended way to process whole component columns at once, for example
+    /// to let the compiler vectorize the loop.
+    ///
+    /// Fields are accessed by index with [`TableIter::field()`] and
+    /// [`TableIter::field_mut()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Debug, Component)]
+    /// pub struct Position {
+    ///     pub x: f32,
+    ///     pub y: f32,
+    /// }
+    ///
+    /// #[derive(Debug, Component)]
+    /// pub struct Velocity {
+    ///     pub x: f32,
+    ///     pub y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world
+    ///     .entity()
+    ///     .set(Position { x: 0.0, y: 0.0 })
+    ///     .set(Velocity { x: 1.0, y: 2.0 });
+    ///
+    /// let query = world.new_query::<(&mut Position, &Velocity)>();
+    ///
+    /// query.iter(|it| {
+    ///     let mut p = it.field_mut::<Position>(0);
+    ///     let v = it.field::<Velocity>(1);
+    ///     for i in it.iter() {
+    ///         p[i].x += v[i].x;
+    ///         p[i].y += v[i].y;
+    ///     }
+    /// });
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`QueryAPI::run()`]
+    /// * [`QueryAPI::each_iter()`]
+    fn iter(&self, mut func: impl FnMut(TableIter<false, P>))
+    where
+        P: ComponentId,
+    {
+        let world = self.world();
+        let mut iter = self.retrieve_iter();
+
+        while self.iter_next(&mut iter) {
+            // SAFETY: `iter` was just advanced to a valid table by `iter_next`,
+            // and the world and table outlive this iteration.
+            let _table_lock = unsafe { TableLockGuard::lock(iter.world, iter.table) };
+            // SAFETY: `iter` outlives the `TableIter` passed to `func`.
+            func(unsafe { TableIter::new(&mut iter, world) });
+        }
+    }
+
     /// Get the entity of the current query
     ///
     /// # Arguments
@@ -973,6 +1267,52 @@ where
         QueryIter::new(self.retrieve_iter(), self.iter_next_func())
     }
 
+    /// Return an iterator that iterates the query against a specific stage.
+    ///
+    /// Structural changes made through the entities and iterators yielded by the returned
+    /// iterator, such as `add`, `remove` or setting a new component, are enqueued in the
+    /// command queue of `stage` and applied when the stage is merged. Component reads still
+    /// come from the shared world storage.
+    ///
+    /// # Arguments
+    ///
+    /// * `stage` - The stage to iterate with, e.g. from [`World::stage()`] or the world of
+    ///   an entity or iterator in a multithreaded system.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Moved;
+    ///
+    /// let world = World::new();
+    /// let e = world.entity().set(Position { x: 1.0, y: 2.0 });
+    ///
+    /// let query = world.new_query::<&Position>();
+    ///
+    /// world.readonly_begin(false);
+    /// let stage = world.stage(0);
+    /// query.iter_stage(stage).each_entity(|e, _| {
+    ///     e.add(Moved);
+    /// });
+    /// world.readonly_end();
+    ///
+    /// assert!(e.has(Moved));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`QueryHandle::iter_stage()`]
+    /// * [`World::stage()`]
+    /// * [`World::readonly_begin()`]
     fn iter_stage(&'a self, stage: impl WorldProvider<'a>) -> QueryIter<'a, P, T> {
         QueryIter::new(self.retrieve_iter_stage(stage), self.iter_next_func())
     }
@@ -989,15 +1329,60 @@ where
         self.iterable().page(offset, limit)
     }
 
-    /// Return a worker iterator.
+    /// Return the iterator for worker `index` out of `count` workers.
     ///
-    /// Worker iterators evenly distribute matched entities across N workers.
+    /// This is the query counterpart of
+    /// [`System::run_worker()`](crate::addons::system::System::run_worker): every matched table
+    /// is split into `count` contiguous slices and worker `index` only visits its own slice, so
+    /// running all workers visits each matched entity exactly once. The split is deterministic
+    /// for a given set of matched tables.
+    ///
+    /// Component access goes through the same table column locks as [`QueryAPI::each`]
+    /// when the `flecs_safety_locks` feature is enabled: workers that only read a component
+    /// may overlap, while a worker writing a component conflicts with any other worker
+    /// accessing that component in the same table.
     ///
     /// # Arguments
     ///
     /// * `index` - Zero-based index of this worker (0..count-1).
     /// * `count` - Total number of workers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Mass(u32);
+    ///
+    /// let world = World::new();
+    ///
+    /// for i in 0..10 {
+    ///     world.entity().set(Mass(i));
+    /// }
+    ///
+    /// let query = world.new_query::<&Mass>();
+    ///
+    /// let mut total = 0;
+    /// for index in 0..4 {
+    ///     query.worker(index, 4).each(|mass| {
+    ///         total += mass.0;
+    ///     });
+    /// }
+    ///
+    /// assert_eq!(total, 45);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`QueryAPI::iter_stage()`]
+    /// * [`System::run_worker()`](crate::addons::system::System::run_worker)
     fn worker(&'a self, index: i32, count: i32) -> ChainedIter<'a, P, T> {
+        ecs_assert!(
+            index >= 0 && index < count,
+            FlecsErrorCode::InvalidParameter,
+            "worker index must be in the range 0..count"
+        );
         self.iterable().worker(index, count)
     }
 
@@ -1338,6 +1723,58 @@ where
         }
     }
 
+    /// Runs the callback once for the single entity matched by the query, for
+    /// singleton-style access such as a global config component.
+    ///
+    /// With debug assertions (or `flecs_force_enable_ecs_asserts`) this behaves like
+    /// [`Query::first_only`] and checks that exactly one entity matches. In release
+    /// builds the check is skipped and this behaves like [`Query::first`], so no
+    /// extra iteration is done to look for a second match.
+    ///
+    /// # Returns
+    ///
+    /// The result of the callback function.
+    ///
+    /// # Panics
+    ///
+    /// if there are no entities, or (with debug assertions) if there is more than one entity.
+    ///
+    /// ```rust
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component, Debug)]
+    /// struct Config {
+    ///     max_speed: i32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let query = world.new_query::<&Config>();
+    ///
+    /// world.entity().set(Config { max_speed: 10 });
+    ///
+    /// let max_speed = query.single(|cfg| cfg.max_speed);
+    ///
+    /// assert_eq!(max_speed, 10);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Query::try_first_entity`]
+    /// * [`Query::first`]
+    /// * [`Query::first_only`]
+    fn single<R>(&self, func: impl FnOnce(T::TupleType<'_>) -> R) -> R {
+        #[cfg(any(debug_assertions, feature = "flecs_force_enable_ecs_asserts"))]
+        {
+            self.first_only(func)
+        }
+
+        #[cfg(all(not(debug_assertions), not(feature = "flecs_force_enable_ecs_asserts")))]
+        {
+            self.first(func)
+        }
+    }
+
     /// Returns true if iterator yields at least once result.
     fn is_true(&self) -> bool {
         let mut it = self.retrieve_iter();
@@ -1363,6 +1800,93 @@ where
         result
     }
 
+    /// Return the number of tables with results.
+    ///
+    /// Useful to measure how fragmented the matched entities are across archetypes. Only
+    /// tables that currently have matching entities are counted, see
+    /// [`QueryAPI::matched_table_sizes()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Frozen;
+    ///
+    /// let world = World::new();
+    /// let q = world.new_query::<&Position>();
+    ///
+    /// world.entity().set(Position { x: 1.0, y: 2.0 });
+    /// let e = world.entity().set(Position { x: 3.0, y: 4.0 });
+    /// assert_eq!(q.table_count(), 1);
+    ///
+    /// e.add(Frozen);
+    /// assert_eq!(q.table_count(), 2);
+    /// ```
+    fn table_count(&self) -> i32 {
+        self.matched_table_sizes().len() as i32
+    }
+
+    /// Return the number of matched entities for each table with results.
+    ///
+    /// Tables are listed in the order the query first returns them. Results that don't
+    /// match entities from a table, such as queries without a `$this` term, are not
+    /// included. An entity is counted once per table even if it matches more than once,
+    /// as with wildcard queries, and entities skipped because of a disabled
+    /// [`flecs::CanToggle`] component are not counted.
+    ///
+    /// # Returns
+    ///
+    /// The entity count of each table, summing to [`QueryAPI::count()`] unless entities
+    /// match more than once.
+    fn matched_table_sizes(&self) -> Vec<i32> {
+        let mut it = self.retrieve_iter();
+        let mut index = hashbrown::HashMap::<*mut sys::ecs_table_t, usize>::new();
+        // matched entity count and disjoint (first row, end row) ranges of rows counted per table
+        let mut tables: Vec<(i32, Vec<(i32, i32)>)> = Vec::new();
+        while self.iter_next(&mut it) {
+            if it.table.is_null() || it.count == 0 {
+                continue;
+            }
+            let (start, end) = (it.offset, it.offset + it.count);
+            let (count, counted) = match index.entry(it.table) {
+                hashbrown::hash_map::Entry::Occupied(entry) => &mut tables[*entry.get()],
+                hashbrown::hash_map::Entry::Vacant(entry) => {
+                    entry.insert(tables.len());
+                    tables.push((0, Vec::new()));
+                    tables.last_mut().unwrap()
+                }
+            };
+
+            // rows are only returned more than once when they match more than once (wildcards),
+            // while toggled off rows in between separate results are not matched at all
+            let overlap: i32 = counted
+                .iter()
+                .map(|&(s, e)| (end.min(e) - start.max(s)).max(0))
+                .sum();
+            *count += end - start - overlap;
+
+            let (mut merged_start, mut merged_end) = (start, end);
+            counted.retain(|&(s, e)| {
+                let touches = s <= end && start <= e;
+                if touches {
+                    merged_start = merged_start.min(s);
+                    merged_end = merged_end.max(e);
+                }
+                !touches
+            });
+            counted.push((merged_start, merged_end));
+        }
+        tables.into_iter().map(|(count, _)| count).collect()
+    }
+
     /// Returns a new iterator limited to tables with the specified group id (grouped queries only).
     ///
     /// Does not modify `self`. To constrain an existing [`QueryIter`] in place,
@@ -1445,6 +1969,46 @@ where
     }
 
     /// Serialize iterator result to JSON.
+    ///
+    /// Iterates the query and serializes the matched entities with the values of their fields,
+    /// in the result format of the Flecs REST API. Field values can only be serialized for
+    /// components with reflection data, e.g. registered with `#[flecs(meta)]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `desc` - The serialization parameters, or `None` for the flecs defaults.
+    ///
+    /// # Returns
+    ///
+    /// The JSON string, or `None` if serialization failed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// #[flecs(meta)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.entity_named("foo").set(Position { x: 10.0, y: 20.0 });
+    ///
+    /// let json = world.new_query::<&Position>().to_json(None).unwrap();
+    /// assert_eq!(
+    ///     json,
+    ///     r#"{"results":[{"name":"foo", "fields":{"values":[{"x":10, "y":20}]}}]}"#
+    /// );
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::to_json()`]
+    /// * C API: `ecs_iter_to_json`
     #[cfg(feature = "flecs_json")]
     fn to_json(&self, desc: Option<&crate::prelude::json::IterToJsonDesc>) -> Option<String> {
         let desc_ptr = desc
@@ -1852,3 +2416,17 @@ fn _determine_ids_plus_indices_for_wildcard_terms(
     }
     read_write
 }
+
+/// Finalizes an iterator that was not iterated until completion, so its
+/// resources are released even when a callback unwinds.
+struct IterFiniGuard(sys::ecs_iter_t);
+
+impl Drop for IterFiniGuard {
+    fn drop(&mut self) {
+        // an iterator that ran to completion was already finalized by `next`
+        if self.0.flags & sys::EcsIterIsValid != 0 {
+            // SAFETY: the iterator is valid and has not been finalized yet.
+            unsafe { sys::ecs_iter_fini(&mut self.0) };
+        }
+    }
+}
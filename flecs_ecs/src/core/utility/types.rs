@@ -65,7 +65,7 @@ pub(crate) struct ObserverEntityBindingCtx {
 
 impl Drop for ObserverEntityBindingCtx {
     fn drop(&mut self) {
-        if std::thread::panicking() {
+        if crate::core::utility::std_compat::thread_panicking() {
             return;
         }
 
@@ -1,4 +1,5 @@
 use super::*;
+use alloc::ffi::CString;
 
 /// Component mixin implementation
 impl World {
@@ -15,7 +16,16 @@ impl World {
         Component::<T::UnderlyingType>::new(self)
     }
 
-    /// Find or register component.
+    /// Find or register component under an explicit name.
+    ///
+    /// The name replaces the default name derived from the Rust type path, and only
+    /// applies to this world. If the component is already registered with this world,
+    /// the existing component is returned and its name is left unchanged.
+    ///
+    /// The same Rust type can be registered under a different name in each world.
+    /// The per-type `index()` only identifies a slot in each world's own component
+    /// id table, so registering the type in a second world (with whatever name)
+    /// never changes the id or name the type has in the first world.
     ///
     /// # Type Parameters
     ///
@@ -28,6 +38,31 @@ impl World {
     /// # Returns
     ///
     /// The found or registered component.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let client = World::new();
+    /// let server = World::new();
+    ///
+    /// let c = client.component_named::<Position>("ClientPosition");
+    /// let s = server.component_named::<Position>("ServerPosition");
+    ///
+    /// assert_eq!(c.name(), "ClientPosition");
+    /// assert_eq!(s.name(), "ServerPosition");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::component()`]
     pub fn component_named<'a, T: ComponentId>(
         &'a self,
         name: &str,
@@ -35,6 +70,118 @@ impl World {
         Component::<T::UnderlyingType>::new_named(self, name)
     }
 
+    /// Register a set of components up front.
+    ///
+    /// Components are otherwise registered lazily, the first time they are used
+    /// with this world. Registering them at startup gives them their ids in a
+    /// predictable order, and sets their lifecycle hooks (ctor, dtor, clone, ...)
+    /// before any entity uses them. Components that are already registered are
+    /// left as is.
+    ///
+    /// To register a single component and get a handle to it, use [`World::component()`].
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - A tuple of component types.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Velocity {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.register_components::<(Position, Velocity)>();
+    ///
+    /// assert!(Position::is_registered_with_world(&world));
+    /// assert!(Velocity::is_registered_with_world(&world));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::component()`]
+    pub fn register_components<T: ComponentTuple>(&self) {
+        T::__register_components(self);
+    }
+
+    /// Set a prefix that is removed from names at registration.
+    ///
+    /// Components registered under a path name, such as with
+    /// [`World::component_named()`], create an entity for every segment of the
+    /// path. Setting a prefix strips it from the name before the entity is
+    /// created, so `my_crate::physics::Position` is registered as `Position`.
+    /// The symbol of the component is not affected and remains the full Rust
+    /// type path, which keeps it unique.
+    ///
+    /// Both the `::` and the `.` separator are accepted in `prefix`. The prefix is
+    /// only removed if the remaining name starts with an uppercase character or
+    /// an underscore. Pass an empty string to clear the prefix.
+    ///
+    /// The prefix applies to every named entity created afterwards, not only to
+    /// components. Components registered without an explicit name already use
+    /// the short type name and are not affected.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The prefix to remove from names.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `prefix` contains a NUL byte.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.set_component_name_prefix("my_crate.physics.");
+    ///
+    /// let pos = world.component_named::<Position>("my_crate::physics::Position");
+    ///
+    /// assert_eq!(pos.name(), "Position");
+    /// assert_eq!(world.lookup("Position"), pos.entity());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_set_name_prefix`
+    pub fn set_component_name_prefix(&self, prefix: &str) {
+        let prefix = if prefix.is_empty() {
+            None
+        } else {
+            Some(
+                CString::new(prefix.replace('.', "::"))
+                    .expect("component name prefix must not contain a NUL byte"),
+            )
+        };
+        let prefix_ptr = prefix.as_ref().map_or(core::ptr::null(), |p| p.as_ptr());
+        // SAFETY: the prefix string is owned by the world context below, so it
+        // outlives its use by flecs until it is replaced or the world is destroyed.
+        unsafe { sys::ecs_set_name_prefix(self.world_ptr_mut(), prefix_ptr) };
+        // Drop the previous prefix only after flecs no longer points to it.
+        drop(self.world_ctx().name_prefix.replace(prefix));
+    }
+
     /// Create new untyped component.
     pub fn component_untyped(&self) -> UntypedComponent<'_> {
         UntypedComponent::new(self)
@@ -58,6 +205,100 @@ impl World {
         UntypedComponent::new_from(self, id)
     }
 
+    /// Iterate all components registered in the world.
+    ///
+    /// This visits every entity with the [`flecs::Component`] component, which
+    /// includes the builtin Flecs components and tags registered as components.
+    /// Rust types are only registered once they are first used with this world.
+    ///
+    /// # Arguments
+    ///
+    /// * `func` - The function invoked for each component.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.component::<Position>();
+    ///
+    /// world.components(|c| {
+    ///     println!("{}: size {}, tag {}", c.name(), c.size(), c.is_tag());
+    /// });
+    /// ```
+    pub fn components(&self, mut func: impl FnMut(UntypedComponent)) {
+        self.each_entity::<&flecs::Component>(|e, _| {
+            func(UntypedComponent::new_from(self, e));
+        });
+    }
+
+    /// Iterate the components registered in a scope, such as a module.
+    ///
+    /// Only components that are direct children of `scope` are visited. Components
+    /// in nested scopes, like the components of a module imported by the module,
+    /// are not. Modules are components themselves, so a module is listed in
+    /// the scope it is created in.
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - The scope to list the components of.
+    /// * `func` - The function invoked for each component in the scope.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Physics;
+    ///
+    /// impl Module for Physics {
+    ///     fn module(world: &World) {
+    ///         world.module::<Physics>("physics");
+    ///         world.component::<Position>();
+    ///     }
+    /// }
+    ///
+    /// let world = World::new();
+    /// let physics = world.import::<Physics>();
+    ///
+    /// let mut names = Vec::new();
+    /// world.components_in_scope(physics, |c| names.push(c.name()));
+    ///
+    /// assert_eq!(names, ["Position"]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::components()`]
+    /// * [`World::import()`]
+    pub fn components_in_scope(
+        &self,
+        scope: impl IntoEntity,
+        mut func: impl FnMut(UntypedComponent),
+    ) {
+        let scope = EntityView::new_from(self, scope.into_entity(self));
+        scope.each_child(|child| {
+            if child.has(flecs::Component::id()) {
+                func(UntypedComponent::new_from(self, child));
+            }
+        });
+    }
+
     /// Convert enum constant to entity
     ///
     /// # Type Parameters
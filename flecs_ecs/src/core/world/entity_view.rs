@@ -67,10 +67,29 @@ impl World {
     /// assert_eq!(entity.get_name(), Some("Foo".to_string()));
     /// ```
     ///
+    /// A path resolves to the existing entity, or creates it together with any
+    /// missing parents. Intermediate parents are plain named entities:
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let c = world.entity_named("a::b::c");
+    /// assert_eq!(c.path(), Some("::a::b::c".to_string()));
+    ///
+    /// let b = c.parent().unwrap();
+    /// assert_eq!(b, world.lookup("a::b"));
+    ///
+    /// // resolving the path again returns the same entity
+    /// assert_eq!(world.entity_named("a::b::c"), c);
+    /// ```
+    ///
     /// # See also
     ///
     /// * [`World::entity()`]
     /// * [`World::entity_named_cstr()`]
+    /// * [`World::lookup()`]
     pub fn entity_named(&self, name: &str) -> EntityView<'_> {
         EntityView::new_named(self, name)
     }
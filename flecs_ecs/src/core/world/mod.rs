@@ -11,6 +11,7 @@ use crate::sys;
 extern crate std;
 
 extern crate alloc;
+use alloc::vec::Vec;
 
 pub(crate) type FlecsArray = Vec<u64>;
 
@@ -28,6 +29,9 @@ mod singleton;
 mod system;
 mod world;
 
+pub use operations::MemoryStats;
+#[cfg(feature = "flecs_pipeline")]
+pub use pipeline::{ExclusiveAccessGuard, FrameStats};
 pub use singleton::*;
 pub use world::*;
 
@@ -78,4 +78,142 @@ impl World {
     {
         ObserverBuilder::<Event, Components>::new_named(self, name)
     }
+
+    /// Get the observers that fire for an event on a component.
+    ///
+    /// An observer is returned if it listens for `event` (or for any event with
+    /// [`flecs::Wildcard`]) and one of its terms matches `id`, either directly or
+    /// through a wildcard such as `(Likes, *)`. This is meant for debugging, for
+    /// example to find out whether an observer was registered at all or whether
+    /// several observers react to the same change. Disabled observers are
+    /// included, check [`EntityView::is_enabled_self()`] to tell them apart.
+    ///
+    /// Only the event and the term ids are compared. Observers that listen for
+    /// the event but are filtered out by other terms, sources or traversal are
+    /// still returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The event, such as [`flecs::OnSet`].
+    /// * `id` - The component or pair the event is emitted for.
+    ///
+    /// # Returns
+    ///
+    /// The matching observers, ordered by entity id.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let observer = world
+    ///     .observer::<flecs::OnSet, &Position>()
+    ///     .each(|_| {});
+    ///
+    /// let observers = world.observers_for(flecs::OnSet, Position::id());
+    /// assert_eq!(observers, [observer]);
+    ///
+    /// assert_eq!(world.observer_count(flecs::OnRemove, Position::id()), 0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::observer_count()`]
+    pub fn observers_for(&self, event: impl IntoEntity, id: impl IntoId) -> Vec<Observer<'_>> {
+        let mut observers = Vec::new();
+        self.each_observer_for(*event.into_entity(self), *id.into_id(self), |o| {
+            observers.push(o);
+        });
+        observers
+    }
+
+    /// Count the observers that fire for an event on a component.
+    ///
+    /// Counts the same observers that [`World::observers_for()`] returns.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The event, such as [`flecs::OnSet`].
+    /// * `id` - The component or pair the event is emitted for.
+    ///
+    /// # Returns
+    ///
+    /// The number of matching observers.
+    ///
+    /// # See also
+    ///
+    /// * [`World::observers_for()`]
+    pub fn observer_count(&self, event: impl IntoEntity, id: impl IntoId) -> usize {
+        let mut count = 0;
+        self.each_observer_for(*event.into_entity(self), *id.into_id(self), |_| count += 1);
+        count
+    }
+
+    fn each_observer_for<'a>(
+        &'a self,
+        event: sys::ecs_entity_t,
+        id: sys::ecs_id_t,
+        mut func: impl FnMut(Observer<'a>),
+    ) {
+        // Iterate the `(Poly, Observer)` tables directly: unlike a query this
+        // doesn't skip disabled observers or observers in prefabs.
+        let mut entities = Vec::new();
+        // SAFETY: the world pointer is valid for the lifetime of `self`, and the
+        // entity arrays are only read while the iterator is alive.
+        unsafe {
+            let mut it = sys::ecs_each_id(
+                self.world_ptr(),
+                ecs_pair(flecs::Poly::ID, flecs::Observer::ID),
+            );
+            while sys::ecs_each_next(&mut it) {
+                if it.count > 0 {
+                    entities.extend_from_slice(core::slice::from_raw_parts(
+                        it.entities,
+                        it.count as usize,
+                    ));
+                }
+            }
+        }
+        entities.sort_unstable();
+
+        for entity in entities {
+            // SAFETY: the entity has `(Poly, Observer)`, so it is a live observer.
+            let observer = unsafe { sys::ecs_observer_get(self.world_ptr(), entity) };
+            if observer.is_null() {
+                continue;
+            }
+
+            // SAFETY: `observer` was null-checked above and points to a live
+            // `ecs_observer_t`. If it has a query, the query has `term_count` terms.
+            let matches = unsafe {
+                let observer = &*observer;
+                let events = &observer.events[..observer.event_count.max(0) as usize];
+                let listens = events
+                    .iter()
+                    .any(|&ev| ev == event || ev == flecs::Wildcard::ID);
+
+                // observers for a single trivial term don't keep a query
+                listens
+                    && match observer.query.as_ref() {
+                        Some(query) => (0..query.term_count.max(0) as usize)
+                            .any(|i| sys::ecs_id_match(id, (*query.terms.add(i)).id)),
+                        None => sys::ecs_id_match(id, sys::ecs_rust_observer_register_id(observer)),
+                    }
+            };
+
+            if matches {
+                func(Observer::new_from_existing(EntityView::new_from(
+                    self, entity,
+                )));
+            }
+        }
+    }
 }
@@ -2,10 +2,27 @@ use core::ffi::c_void;
 use core::marker::PhantomData;
 use core::ptr::NonNull;
 
+use alloc::boxed::Box;
+
 use super::*;
 
 use flecs_ecs_derive::extern_abi;
 
+/// Memory used by the tables of a world, returned by [`World::memory_stats()`].
+///
+/// Byte counts only include component column storage.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryStats {
+    /// Number of tables, including empty tables.
+    pub table_count: usize,
+    /// Number of alive entities.
+    pub entity_count: usize,
+    /// Bytes allocated for component columns, including unused capacity.
+    pub allocated_bytes: usize,
+    /// Bytes of component columns in use by entities.
+    pub used_bytes: usize,
+}
+
 #[extern_abi]
 unsafe fn c_run_post_frame(world: *mut sys::ecs_world_t, ctx: *mut ::core::ffi::c_void) {
     // ctx is a Box<fn(WorldRef)> created in run_post_frame. Flecs invokes this
@@ -15,6 +32,16 @@ unsafe fn c_run_post_frame(world: *mut sys::ecs_world_t, ctx: *mut ::core::ffi::
     (action)(world);
 }
 
+/// Storage of a context set with [`World::set_ctx`].
+type TypedWorldCtx = core::cell::RefCell<Box<dyn core::any::Any>>;
+
+#[extern_abi]
+unsafe fn c_free_typed_ctx(ctx: *mut ::core::ffi::c_void) {
+    // ctx is a Box<TypedWorldCtx> created in set_ctx. Flecs invokes this when the world
+    // is destroyed, set_ctx reclaims it itself when the context is replaced.
+    drop(unsafe { Box::from_raw(ctx as *mut TypedWorldCtx) });
+}
+
 #[extern_abi]
 unsafe fn c_on_destroyed(world: *mut sys::ecs_world_t, ctx: *mut ::core::ffi::c_void) {
     // ctx is a Box<fn(WorldRef)> created in on_destroyed. Flecs invokes atfini
@@ -65,20 +92,41 @@ impl World {
 
     /// Get the world's info. See [`sys::WorldInfo`] for what information you can retrieve.
     ///
+    /// This is a snapshot of the world's statistics and the main entry point for
+    /// diagnostics. Commonly used fields are:
+    ///
+    /// - `frame_count_total`, `delta_time`, `delta_time_raw`, `world_time_total`: frame timing
+    /// - `tag_id_count`, `component_id_count`, `pair_id_count`: number of ids per category
+    /// - `table_count`, `table_create_total`, `table_delete_total`: table statistics
+    /// - `system_time_total`, `merge_time_total`, `frame_time_total`: time spent per
+    ///   activity, only measured once time measurement is enabled (see
+    ///   [`World::progress_timed()`])
+    /// - `systems_ran_total`, `merge_count_total`, `cmd`: operation counters
+    ///
+    /// The number of entities isn't part of the info; use [`World::count()`] to count
+    /// the entities with a specific id.
+    ///
     /// # Example
     /// ```
     /// use flecs_ecs::prelude::*;
     ///
     /// let world = World::new();
     ///
-    /// world.progress();
+    /// world.progress_time(0.5);
     ///
     /// let world_info = world.info();
     ///
-    /// assert!(world_info.delta_time > 0.0);
-    /// //assert!(world_info.world_time_total_raw > 0.0); //BUG TODO
-    /// //assert!(world_info.systems_ran_frame == 0);
+    /// assert_eq!(world_info.frame_count_total, 1);
+    /// assert!((world_info.delta_time - 0.5).abs() < f32::EPSILON);
+    /// assert!(world_info.world_time_total_raw > 0.0);
+    /// assert!(world_info.component_id_count > 0);
     /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::delta_time()`]
+    /// * [`World::progress_timed()`]
+    /// * C API: `ecs_get_world_info`
     pub fn info(&self) -> sys::WorldInfo {
         // SAFETY: The pointer is valid for the lifetime of the world.
         unsafe { *sys::ecs_get_world_info(self.raw_world.as_ptr()) }
@@ -87,6 +135,9 @@ impl World {
     /// Signals the application to quit.
     ///
     /// After calling this function, the next call to [`World::progress()`] returns false.
+    /// Systems can request a shutdown by calling `it.world().quit()`, in which case the
+    /// [`World::progress()`] call running the system already returns false. A custom main loop
+    /// can also observe the request with [`World::should_quit()`].
     ///
     /// # Example
     /// ```
@@ -344,7 +395,7 @@ impl World {
     ///
     /// Leaves staging mode. After this operation, the world may be directly mutated again.
     /// By default, this operation also merges data back into the world, unless auto-merging
-    /// was disabled explicitly.
+    /// was disabled explicitly. Stages are merged in the same order as [`World::merge()`].
     ///
     /// # Thread Safety
     /// This function should only be run from the main thread.
@@ -367,6 +418,51 @@ impl World {
         }
     }
 
+    /// Begin readonly mode for the lifetime of the returned guard.
+    ///
+    /// Same as [`World::readonly_begin()`] with `multi_threaded` set to `false`, except that
+    /// readonly mode is ended with [`World::readonly_end()`] when the returned [`ReadonlyGuard`]
+    /// is dropped, also when unwinding. Operations on the world and its stages are deferred while
+    /// the guard is held and are merged into the world when it is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world.entity();
+    ///
+    /// {
+    ///     let _readonly = world.readonly_scope();
+    ///     assert!(world.is_readonly());
+    ///
+    ///     e.mut_current_stage(world.stage(0))
+    ///         .set(Position { x: 10, y: 20 });
+    ///
+    ///     assert!(!e.has(Position::id()));
+    /// }
+    ///
+    /// assert!(!world.is_readonly());
+    /// assert!(e.has(Position::id()));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::readonly_begin()`]
+    /// * [`World::readonly_end()`]
+    /// * [`World::is_readonly()`]
+    pub fn readonly_scope(&self) -> ReadonlyGuard<'_> {
+        ReadonlyGuard::new(self)
+    }
+
     /// Test whether the current world object is readonly.
     ///
     /// This function allows the code to test whether the currently used world
@@ -412,7 +508,8 @@ impl World {
     /// This operation is thread safe.
     ///
     /// # Returns
-    /// Whether the operation was successful.
+    /// `true` if this call started the outermost defer scope, `false` if deferring
+    /// was already active (a nested scope) or is suspended.
     ///
     /// # Example
     ///
@@ -456,12 +553,39 @@ impl World {
     /// # Thread Safety
     /// This operation is thread safe.
     ///
+    /// Defer scopes nest: only the `defer_end` matching the outermost
+    /// [`World::defer_begin()`] flushes the queued commands, inner calls just leave
+    /// their scope. Library code that defers on its own can use the return value to
+    /// tell whether its changes are visible yet or will be applied by an enclosing
+    /// scope, e.g. the one of a running system.
+    ///
     /// # Returns
-    /// Whether the operation was successful.
+    /// `true` if the queued commands were flushed, `false` if this ended a nested
+    /// scope (or deferring is suspended) and the commands are still queued.
     ///
     /// # Example
     ///
-    /// see [`World::defer_begin`]
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Tag;
+    ///
+    /// let world = World::new();
+    /// let e = world.entity();
+    ///
+    /// world.defer_begin();
+    /// world.defer_begin();
+    /// e.add(Tag);
+    ///
+    /// // inner scope: the command stays queued
+    /// assert!(!world.defer_end());
+    /// assert!(!e.has(Tag));
+    ///
+    /// // outermost scope: the queue is flushed
+    /// assert!(world.defer_end());
+    /// assert!(e.has(Tag));
+    /// ```
     ///
     /// # See also
     ///
@@ -476,6 +600,12 @@ impl World {
 
     /// Test whether deferring is enabled.
     ///
+    /// Operations are deferred inside [`World::defer()`] scopes, between
+    /// [`World::defer_begin()`] and [`World::defer_end()`], and while systems and
+    /// observers run. Library code that can be called from either context can use
+    /// this to decide whether changes are visible yet. Flecs does not expose the
+    /// nesting depth of defer scopes; a suspended defer scope reports `false`.
+    ///
     /// # Returns
     ///
     /// Whether deferring is enabled.
@@ -505,6 +635,8 @@ impl World {
     /// * [`World::defer_end()`]
     /// * [`World::defer_suspend()`]
     /// * [`World::defer_resume()`]
+    /// * [`World::is_defer_suspended()`]
+    /// * C API: `ecs_is_deferred`
     pub fn is_deferred(&self) -> bool {
         unsafe { sys::ecs_is_deferred(self.raw_world.as_ptr()) }
     }
@@ -764,6 +896,21 @@ impl World {
     ///
     /// This operation may be called on an already merged stage or world.
     ///
+    /// # Merge order
+    ///
+    /// Merging the world applies the command queues of its stages in stage id
+    /// order, starting at stage 0, and the commands of each stage in the order they
+    /// were recorded. The order does not depend on which thread recorded into which
+    /// stage first, so the same commands recorded into the same stages always merge
+    /// to the same result. When stages write the same component of the same entity,
+    /// the stage with the highest id wins.
+    ///
+    /// One exception: setting a component the entity already has, when the
+    /// component has no hooks or observers that need to run on set, writes the
+    /// value in place as soon as it is recorded. Such writes are ordered by when
+    /// they are recorded, not by stage id. To keep them deterministic, make sure
+    /// different stages don't set the same existing component of the same entity.
+    ///
     /// # Example
     ///
     /// ```
@@ -813,6 +960,17 @@ impl World {
     /// it can be passed transparently to the existing API functions, vs. having to
     /// create a dedicated API for threading.
     ///
+    /// The returned [`WorldRef`] implements [`WorldProvider`], so entity builders and
+    /// other world operations can be pointed at the stage. Between [`World::readonly_begin()`]
+    /// and [`World::readonly_end()`] every operation on a stage is recorded into that stage's
+    /// command queue instead of being applied, and the queues are merged in stage order when
+    /// readonly mode ends (or when [`World::merge()`] is called).
+    ///
+    /// Reading component data through a stage while other stages are recording commands only
+    /// observes the state from before the frame started, and must not be combined with mutable
+    /// access from other threads to the same data. Treat stages as write-only command buffers
+    /// until the commands have been merged.
+    ///
     /// # Arguments
     ///
     /// * `stage_id` - The index of the stage to retrieve.
@@ -839,6 +997,35 @@ impl World {
     /// assert_eq!(stage.stage_id(), 3);
     /// ```
     ///
+    /// Recording commands into multiple stages:
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.set_stage_count(2);
+    ///
+    /// world.readonly_begin(false);
+    ///
+    /// for i in 0..world.get_stage_count() {
+    ///     world.stage(i).entity().set(Position { x: i, y: i });
+    /// }
+    ///
+    /// // nothing is applied until the stages are merged
+    /// assert_eq!(world.count(Position::id()), 0);
+    ///
+    /// world.readonly_end();
+    ///
+    /// assert_eq!(world.count(Position::id()), 2);
+    /// ```
+    ///
     /// # See also
     ///
     /// * [`World::get_stage_count()`]
@@ -988,7 +1175,12 @@ impl World {
     /// Set world context.
     ///
     /// Set a context value that can be accessed by anyone that has a reference
-    /// to the world.
+    /// to the world. A context set with [`World::set_ctx()`] is replaced and dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a context set with [`World::set_ctx()`] is currently borrowed, or is replaced
+    /// from a thread other than the one that owns the world.
     ///
     /// # Arguments
     ///
@@ -1025,7 +1217,26 @@ impl World {
         reason = "this doesn't actually deref the pointer and controls lifetime"
     )]
     pub fn set_context(&self, ctx: *mut c_void, ctx_free: sys::ecs_ctx_free_t) {
+        let world = self.real_world();
+        let old = world.typed_ctx();
+
+        if let Some(old) = old {
+            world.check_typed_ctx_thread();
+            // SAFETY: `typed_ctx` only returns pointers to a live context created by `set_ctx`.
+            assert!(
+                unsafe { old.as_ref() }.try_borrow_mut().is_ok(),
+                "cannot replace the world context while it is borrowed"
+            );
+        }
+
         unsafe { sys::ecs_set_ctx(self.raw_world.as_ptr(), ctx, ctx_free) }
+
+        if let Some(old) = old {
+            world.world_ctx().typed_ctx.set(core::ptr::null_mut());
+            // SAFETY: the typed context was replaced above, so flecs no longer owns it, and it
+            // is not borrowed.
+            drop(unsafe { Box::from_raw(old.as_ptr()) });
+        }
     }
 
     /// Get world context.
@@ -1045,6 +1256,159 @@ impl World {
         unsafe { sys::ecs_get_ctx(self.raw_world.as_ptr()) }
     }
 
+    /// Set a typed world context.
+    ///
+    /// Stores `value` on the world, so that it can be accessed by anyone with a reference to
+    /// the world, for example through [`TableIter::world()`] in systems. The value is dropped when
+    /// it is replaced by another call to `set_ctx` or when the world is destroyed.
+    ///
+    /// This uses the same storage as [`World::set_context()`], setting one replaces the other.
+    ///
+    /// The context is not required to be [`Send`] or [`Sync`], so it can only be accessed from
+    /// the thread that owns the world. Multi-threaded systems must not access it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the current context is borrowed by [`World::get_ctx()`] or [`World::get_ctx_mut()`],
+    /// or when called from a thread other than the one that owns the world.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// struct Assets {
+    ///     loaded: Vec<String>,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.set_ctx(Assets { loaded: vec![] });
+    ///
+    /// world.get_ctx_mut::<Assets, _>(|assets| assets.loaded.push("player.png".to_string()));
+    ///
+    /// assert_eq!(world.get_ctx::<Assets, _>(|assets| assets.loaded.len()), Some(1));
+    /// assert_eq!(world.get_ctx::<u32, _>(|_| ()), None);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::get_ctx()`]
+    /// * [`World::get_ctx_mut()`]
+    /// * C API: `ecs_set_ctx`
+    pub fn set_ctx<T: 'static>(&self, value: T) {
+        let world = self.real_world();
+        world.check_typed_ctx_thread();
+        let old = world.typed_ctx();
+
+        if let Some(old) = old {
+            // SAFETY: `typed_ctx` only returns pointers to a live context created by `set_ctx`.
+            let old = unsafe { old.as_ref() };
+            assert!(
+                old.try_borrow_mut().is_ok(),
+                "cannot replace the world context while it is borrowed"
+            );
+        }
+
+        let ctx: Box<TypedWorldCtx> = Box::new(core::cell::RefCell::new(Box::new(value)));
+        let ctx = Box::into_raw(ctx) as *mut c_void;
+
+        // SAFETY: the world pointer is valid; the free callback matches the boxed type.
+        unsafe { sys::ecs_set_ctx(world.world_ptr_mut(), ctx, Some(c_free_typed_ctx)) };
+        world.world_ctx().typed_ctx.set(ctx);
+
+        if let Some(old) = old {
+            // SAFETY: the old context was replaced above, so flecs no longer owns it, and it
+            // is not borrowed.
+            drop(unsafe { Box::from_raw(old.as_ptr()) });
+        }
+    }
+
+    /// Get the typed world context.
+    ///
+    /// Calls `callback` with the context set with [`World::set_ctx()`].
+    ///
+    /// # Returns
+    ///
+    /// The value returned by `callback`, or `None` if no context of type `T` is set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the context is mutably borrowed by [`World::get_ctx_mut()`], or when called from
+    /// a thread other than the one that owns the world.
+    ///
+    /// # Example
+    ///
+    /// See [`World::set_ctx()`].
+    ///
+    /// # See also
+    ///
+    /// * [`World::set_ctx()`]
+    /// * [`World::get_ctx_mut()`]
+    /// * C API: `ecs_get_ctx`
+    pub fn get_ctx<T: 'static, Return>(
+        &self,
+        callback: impl FnOnce(&T) -> Return,
+    ) -> Option<Return> {
+        let world = self.real_world();
+        world.check_typed_ctx_thread();
+        let ctx = world.typed_ctx()?;
+        // SAFETY: `typed_ctx` only returns pointers to a live context created by `set_ctx`,
+        // which can't be dropped while it is borrowed.
+        let ctx = unsafe { ctx.as_ref() }
+            .try_borrow()
+            .expect("the world context is already mutably borrowed");
+        ctx.downcast_ref::<T>().map(callback)
+    }
+
+    /// Get mutable access to the typed world context.
+    ///
+    /// Calls `callback` with the context set with [`World::set_ctx()`].
+    ///
+    /// # Returns
+    ///
+    /// The value returned by `callback`, or `None` if no context of type `T` is set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the context is already borrowed by [`World::get_ctx()`] or [`World::get_ctx_mut()`],
+    /// or when called from a thread other than the one that owns the world.
+    ///
+    /// # Example
+    ///
+    /// See [`World::set_ctx()`].
+    ///
+    /// # See also
+    ///
+    /// * [`World::set_ctx()`]
+    /// * [`World::get_ctx()`]
+    /// * C API: `ecs_get_ctx`
+    pub fn get_ctx_mut<T: 'static, Return>(
+        &self,
+        callback: impl FnOnce(&mut T) -> Return,
+    ) -> Option<Return> {
+        let world = self.real_world();
+        world.check_typed_ctx_thread();
+        let ctx = world.typed_ctx()?;
+        // SAFETY: `typed_ctx` only returns pointers to a live context created by `set_ctx`,
+        // which can't be dropped while it is borrowed.
+        let mut ctx = unsafe { ctx.as_ref() }
+            .try_borrow_mut()
+            .expect("the world context is already borrowed");
+        ctx.downcast_mut::<T>().map(callback)
+    }
+
+    /// Returns the context set with [`World::set_ctx()`], unless it was replaced by
+    /// [`World::set_context()`].
+    fn typed_ctx(&self) -> Option<NonNull<TypedWorldCtx>> {
+        // SAFETY: the world pointer is valid.
+        let ctx = unsafe { sys::ecs_get_ctx(self.raw_world.as_ptr()) };
+        if ctx.is_null() || ctx != self.world_ctx().typed_ctx.get() {
+            return None;
+        }
+        NonNull::new(ctx as *mut TypedWorldCtx)
+    }
+
     #[expect(dead_code, reason = "possibly used in the future")]
     pub(crate) fn get_context(world: *mut sys::ecs_world_t) -> *mut WorldCtx {
         unsafe { sys::ecs_get_binding_ctx(world) as *mut WorldCtx }
@@ -1122,6 +1486,85 @@ impl World {
         unsafe { sys::ecs_dim(self.raw_world.as_ptr(), entity_count) };
     }
 
+    /// Get a summary of the memory used by tables and entities.
+    ///
+    /// Walks every table in the world, including empty tables and tables with
+    /// prefabs or disabled entities, and adds up the size of their component
+    /// columns. Tables keep their capacity when entities are removed, so
+    /// [`MemoryStats::allocated_bytes`] can stay high after a burst of entity
+    /// churn. Call [`World::shrink_memory()`] to trim it.
+    ///
+    /// Only component column storage is counted. Memory used by the entity
+    /// index, queries, observers and other bookkeeping is not included, use the
+    /// stats addon for a full breakdown.
+    ///
+    /// # Returns
+    ///
+    /// The [`MemoryStats`] of the world.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.entity().set(Position { x: 1.0, y: 2.0 });
+    ///
+    /// let stats = world.memory_stats();
+    ///
+    /// assert!(stats.table_count > 0);
+    /// assert!(stats.used_bytes >= core::mem::size_of::<Position>());
+    /// assert!(stats.allocated_bytes >= stats.used_bytes);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::shrink_memory()`]
+    pub fn memory_stats(&self) -> MemoryStats {
+        // SAFETY: the world pointer is valid for the lifetime of `self`.
+        let entities = unsafe { sys::ecs_get_entities(self.raw_world.as_ptr()) };
+        let mut stats = MemoryStats {
+            entity_count: entities.alive_count.max(0) as usize,
+            ..Default::default()
+        };
+
+        self.query::<()>()
+            .with(flecs::Any)
+            .query_flags(
+                QueryFlags::MatchEmptyTables | QueryFlags::MatchPrefab | QueryFlags::MatchDisabled,
+            )
+            .build()
+            .run(|mut it| {
+                while it.next() {
+                    let Some(table) = it.table() else {
+                        continue;
+                    };
+                    let table = table.table_ptr_mut();
+                    stats.table_count += 1;
+
+                    // SAFETY: the table is alive while it is being iterated.
+                    unsafe {
+                        let count = sys::ecs_table_count(table).max(0) as usize;
+                        let size = sys::ecs_table_size(table).max(0) as usize;
+                        for column in 0..sys::ecs_table_column_count(table) {
+                            let column_size = sys::ecs_table_get_column_size(table, column);
+                            stats.used_bytes += column_size * count;
+                            stats.allocated_bytes += column_size * size;
+                        }
+                    }
+                }
+            });
+
+        stats
+    }
+
     /// Free unused memory.
     ///
     /// This operation frees allocated memory that is no longer in use by the world.
@@ -1134,6 +1577,15 @@ impl World {
     /// not evaluated by this function, which means that the memory reported by the
     /// OS may not go down. For this reason, this function is most effective when
     /// combined with `FLECS_USE_OS_ALLOC`, which disables internal allocators.
+    ///
+    /// Tables that still have entities have their column capacity trimmed to
+    /// the number of entities, which can be observed with
+    /// [`World::memory_stats()`]. Empty tables are deleted.
+    ///
+    /// # See also
+    ///
+    /// * [`World::memory_stats()`]
+    /// * C API: `ecs_shrink`
     pub fn shrink_memory(&self) {
         unsafe { sys::ecs_shrink(self.raw_world.as_ptr()) };
     }
@@ -1218,6 +1670,46 @@ impl World {
         })
     }
 
+    /// Create and activate an entity id range in one call.
+    ///
+    /// Shorthand for [`World::entity_range_new()`] followed by
+    /// [`World::entity_range_set()`]. This is useful for partitioning ids between
+    /// e.g. a server and its clients, so ids created on either side never collide.
+    ///
+    /// Unlike older Flecs versions there is no separate range check toggle: the
+    /// active range always bounds the ids handed out by [`World::entity()`], and
+    /// creating ranges that overlap with an existing range asserts.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The first entity id in the range (inclusive).
+    /// * `max` - The last entity id in the range (inclusive, 0 = unlimited).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// world.set_entity_range(1000, 2000);
+    ///
+    /// let e = world.entity();
+    ///
+    /// assert_eq!(e.id(), 1000);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::entity_range_new()`]
+    /// * [`World::entity_range_set()`]
+    /// * [`World::entity_range_get()`]
+    pub fn set_entity_range(&self, min: u32, max: u32) -> EntityRange<'_> {
+        let range = self.entity_range_new(min, max);
+        self.entity_range_set(range);
+        range
+    }
+
     /// Get the largest issued entity ID (not counting generation).
     pub fn max_id(&self) -> Entity {
         Entity::new(unsafe { sys::ecs_get_max_id(self.raw_world.as_ptr()) })
@@ -1313,33 +1805,44 @@ impl World {
         })
     }
 
-    /// Sets the search path for entity lookup operations.
+    /// Run `f` with a custom search path for entity lookup operations.
     ///
-    /// This function configures the search path used for looking up an entity.
-    ///
-    /// # Best Practices
-    ///
-    /// * It's advisable to restore the previous search path after making temporary changes.
+    /// Lookups that don't match an entity from the current scope search the scopes of
+    /// `path` in order, so the first scope that contains a matching child wins, similar
+    /// to a namespace `use`. The previous search path is restored when `f` returns.
     ///
     /// # Default Behavior
     ///
     /// * The default search path includes `flecs.core`.
     ///
-    /// # Overwriting
-    ///
-    /// * Providing a custom search path will overwrite the existing search path.
-    ///
     /// # Considerations
     ///
-    /// * If the custom search path doesn't include `flecs.core`, operations that rely on looking up names from `flecs.core` may fail.
+    /// * The search path replaces the previous one. If it doesn't include `flecs.core`,
+    ///   operations that rely on looking up names from `flecs.core` may fail.
     ///
     /// # Arguments
     ///
-    /// * `search_path` - The entity to set as the search path.
+    /// * `path` - The scopes to search, in order of precedence.
+    /// * `f` - The function to run.
     ///
-    /// # Returns
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
     ///
-    /// Returns the current search path after the operation.
+    /// let math = world.entity_named("math");
+    /// let vec3 = world.entity_named("Vec3").child_of(math);
+    ///
+    /// assert!(world.try_lookup("Vec3").is_none());
+    ///
+    /// world.set_lookup_path(&[math], |world| {
+    ///     assert_eq!(world.lookup("Vec3"), vec3);
+    /// });
+    ///
+    /// assert!(world.try_lookup("Vec3").is_none());
+    /// ```
     ///
     /// # See also
     ///
@@ -1347,12 +1850,26 @@ impl World {
     /// * [`World::lookup_recursive()`]
     /// * [`World::try_lookup()`]
     /// * [`World::try_lookup_recursive()`]
+    /// * [`World::scope()`]
     /// * C API: `sys::ecs_set_lookup_path`
-    // TODO we need to improve this function somehow, it's not very ergonomic
-    pub fn set_lookup_path(&self, search_path: impl IntoEntity) -> *mut sys::ecs_entity_t {
-        unsafe {
-            sys::ecs_set_lookup_path(self.raw_world.as_ptr(), &*search_path.into_entity(self))
-        }
+    pub fn set_lookup_path(&self, path: &[impl IntoEntity + Copy], f: impl FnOnce(&World)) {
+        // flecs keeps a pointer to the zero-terminated array until it is replaced, and
+        // searches it back to front, so reverse it to give the first scope precedence.
+        let path: Vec<sys::ecs_entity_t> = path
+            .iter()
+            .rev()
+            .map(|&e| *e.into_entity(self))
+            .chain(core::iter::once(0))
+            .collect();
+        let world = self.raw_world.as_ptr();
+        // SAFETY: raw_world is a valid, live world pointer; `path` outlives the call to `f`
+        // and the guard restores the previous path before it is dropped, even if `f`
+        // unwinds.
+        let _guard = RestoreLookupPathGuard {
+            world,
+            prev: unsafe { sys::ecs_set_lookup_path(world, path.as_ptr()) },
+        };
+        f(self);
     }
 
     /// Lookup an entity by name.
@@ -1497,6 +2014,77 @@ impl World {
         self.try_lookup_impl(name, false)
     }
 
+    /// Lookup an entity by a path that may contain entity ids.
+    ///
+    /// Path segments of the form `#<number>` are interpreted as entity ids, following the
+    /// convention Flecs uses for unnamed entities in paths and JSON output. The remainder of
+    /// the path is resolved by name relative to the last id segment, so `#4294967297::child`
+    /// looks up `child` in the scope of entity `4294967297`. Ids are taken as-is, including
+    /// the generation, and must be alive. Name segments before the first id segment are
+    /// resolved from the current scope, like [`World::try_lookup()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to lookup, using `::` as separator.
+    ///
+    /// # Returns
+    ///
+    /// The entity if found, otherwise `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let parent = world.entity();
+    /// let child = world.entity_named("child").child_of(parent);
+    ///
+    /// let path = format!("#{}::child", parent.id());
+    /// assert_eq!(world.try_lookup_by_id_path(&path).unwrap(), child);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::try_lookup()`]
+    /// * [`World::lookup()`]
+    pub fn try_lookup_by_id_path(&self, path: &str) -> Option<EntityView<'_>> {
+        let separator = SEPARATOR.to_str().unwrap_or("::");
+        let mut current: sys::ecs_entity_t = 0;
+
+        for segment in path.split(separator) {
+            let id = segment
+                .strip_prefix('#')
+                .filter(|digits| !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()))
+                .map(str::parse::<u64>);
+
+            current = match id {
+                Some(Ok(id)) if id != 0 && self.is_alive(id) => id,
+                Some(_) => return None,
+                None => {
+                    let name = compact_str::format_compact!("{}\0", segment);
+                    unsafe {
+                        sys::ecs_lookup_path_w_sep(
+                            self.raw_world.as_ptr(),
+                            current,
+                            name.as_ptr() as *const _,
+                            SEPARATOR.as_ptr(),
+                            SEPARATOR.as_ptr(),
+                            false,
+                        )
+                    }
+                }
+            };
+
+            if current == 0 {
+                return None;
+            }
+        }
+
+        Some(EntityView::new_from(self, current))
+    }
+
     /// Sets a singleton component of type `T` on the world.
     ///
     /// # Arguments
@@ -1664,6 +2252,44 @@ impl World {
         if ptr.is_null() { None } else { Some(ptr) }
     }
 
+    /// Return the size, alignment and registered lifecycle hooks of an id.
+    ///
+    /// Returns `None` when no type info is available for the provided id, such as
+    /// for tags or entities that are not components.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the component or pair.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let ti = world.type_info(world.component_id::<Position>()).unwrap();
+    /// assert_eq!(ti.size, core::mem::size_of::<Position>());
+    /// assert_eq!(ti.alignment, core::mem::align_of::<Position>());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::type_info_from()`]
+    /// * C API: `ecs_get_type_info`
+    pub fn type_info(&self, id: impl IntoId) -> Option<TypeInfo> {
+        // SAFETY: a non-null pointer returned by ecs_get_type_info points to type info
+        // owned by the world, which outlives this call.
+        self.type_info_from(id)
+            .map(|ti| TypeInfo::from_raw(unsafe { &*ti }))
+    }
+
     /// Iterate entities in root of world
     ///
     /// # Arguments
@@ -1825,47 +2451,73 @@ impl World {
     /// All entities created in function are created in scope. All operations
     /// called in function (such as lookup) are relative to scope.
     ///
+    /// The previous scope is restored when `func` returns, also when it panics.
+    ///
     /// # Arguments
     ///
     /// * `parent_id` - The id of the scope to use.
     /// * `func` - The function to run.
-    pub fn run_in_scope_with(&self, parent_id: impl IntoEntity, mut func: impl FnMut()) {
-        let world = self.world();
-        let prev: sys::ecs_id_t =
-            unsafe { sys::ecs_set_scope(self.raw_world.as_ptr(), *parent_id.into_entity(world)) };
-        func();
-        unsafe {
-            sys::ecs_set_scope(self.raw_world.as_ptr(), prev);
-        }
+    ///
+    /// # See also
+    ///
+    /// * [`World::scope()`]
+    /// * [`EntityView::run_in_scope()`]
+    pub fn run_in_scope_with(&self, parent_id: impl IntoEntity, func: impl FnOnce()) {
+        EntityView::new_from(self, parent_id.into_entity(self)).run_in_scope(func);
     }
 
-    /// Use provided scope for operations ran on returned world.
-    /// Operations need to be ran in a single statement
+    /// Run `f` with the provided entity as scope.
+    ///
+    /// All entities created in `f` are created as children of the scope, and lookups are
+    /// relative to it. The previous scope is restored when `f` returns, also when it panics,
+    /// so scopes can be nested.
     ///
     /// # Arguments
     ///
     /// * `parent_id` - The id of the scope to use.
+    /// * `f` - The function to run.
     ///
-    /// # Returns
+    /// # Example
     ///
-    /// A scoped world.
-    pub fn scope(&self, parent_id: impl IntoId, mut f: impl FnMut(&World)) {
-        let previous_scope = self.set_scope(parent_id);
-        f(self);
-        self.set_scope(previous_scope);
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let parent = world.entity_named("parent");
+    ///
+    /// world.scope(parent, |world| {
+    ///     let child = world.entity_named("child");
+    ///     world.scope(child, |world| {
+    ///         world.entity_named("grandchild");
+    ///     });
+    /// });
+    ///
+    /// assert!(world.get_scope().is_none());
+    /// assert!(world.try_lookup("parent::child::grandchild").is_some());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::scope_name()`]
+    /// * [`World::set_scope()`]
+    /// * [`EntityView::scope()`]
+    pub fn scope(&self, parent_id: impl IntoId, f: impl FnOnce(&World)) {
+        let parent = EntityView::new_from(self, *parent_id.into_id(self));
+        parent.run_in_scope(|| f(self));
     }
 
-    /// Use provided scope of name for operations ran on returned world.
-    /// Operations need to be ran in a single statement
+    /// Run `f` with the entity of the provided name as scope, creating it if it doesn't exist.
     ///
     /// # Arguments
     ///
     /// * `name` - The name of the scope to use.
+    /// * `f` - The function to run.
     ///
-    /// # Returns
+    /// # See also
     ///
-    /// A scoped world.
-    pub fn scope_name(&self, name: &str, f: impl FnMut(&World)) {
+    /// * [`World::scope()`]
+    pub fn scope_name(&self, name: &str, f: impl FnOnce(&World)) {
         self.scope(EntityView::new_named(self, name).id, f);
     }
 
@@ -2091,8 +2743,11 @@ impl World {
     }
 
     /// Ensures that entity with provided generation is alive.
-    /// This operation will fail if an entity exists with the same id and a
-    /// different, non-zero generation.
+    ///
+    /// This can be used to recreate entities with an exact id and generation,
+    /// for example when restoring a snapshot or replicating entities over the
+    /// network. The id may include a generation, in which case the entity is
+    /// made alive with that generation.
     ///
     /// # Arguments
     ///
@@ -2101,9 +2756,43 @@ impl World {
     /// # Returns
     ///
     /// The entity with the provided generation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an entity with the same id but a different generation is alive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world.make_alive(5000).set(Health(100));
+    ///
+    /// assert_eq!(e, 5000);
+    /// assert!(world.is_alive(5000));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::is_alive()`]
+    /// * [`World::get_alive()`]
+    /// * C API: `ecs_make_alive`
     pub fn make_alive(&self, entity: impl Into<Entity>) -> EntityView<'_> {
         let entity = *entity.into();
         // SAFETY: raw_world is a valid, live world pointer.
+        let alive = unsafe {
+            sys::ecs_get_alive(self.raw_world.as_ptr(), sys::ecs_strip_generation(entity))
+        };
+        assert!(
+            alive == 0 || alive == entity,
+            "cannot make entity {entity} alive: id is in use by alive entity {alive} with a different generation"
+        );
+        // SAFETY: raw_world is a valid, live world pointer.
         unsafe { sys::ecs_make_alive(self.raw_world.as_ptr(), entity) };
         EntityView::new_from(self, entity)
     }
@@ -2123,3 +2812,18 @@ impl World {
         }
     }
 }
+
+struct RestoreLookupPathGuard {
+    world: *mut sys::ecs_world_t,
+    prev: *mut sys::ecs_entity_t,
+}
+
+impl Drop for RestoreLookupPathGuard {
+    fn drop(&mut self) {
+        // SAFETY: `world` is a live world pointer captured from the `World` borrowed by
+        // `set_lookup_path`, and `prev` is the path that was active before it.
+        unsafe {
+            sys::ecs_set_lookup_path(self.world, self.prev);
+        }
+    }
+}
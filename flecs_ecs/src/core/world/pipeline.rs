@@ -4,6 +4,37 @@ use crate::addons::pipeline::PipelineBuilder;
 
 use super::*;
 
+/// Timing of a single frame, returned by [`World::progress_timed()`].
+///
+/// All values are in seconds and are computed from the world info counters,
+/// so they are available without the stats module.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FrameStats {
+    /// Total time spent processing the frame.
+    pub frame_time: FTime,
+    /// Time spent running systems, excluding merges.
+    pub system_time: FTime,
+    /// Time spent merging deferred commands.
+    pub merge_time: FTime,
+}
+
+/// Keeps exclusive thread access to the world, created with
+/// [`World::exclusive_access_scope()`].
+///
+/// Exclusive access is ended when the guard is dropped, after which other threads can
+/// access the world again.
+#[derive(Debug)]
+#[must_use = "exclusive access ends as soon as the guard is dropped"]
+pub struct ExclusiveAccessGuard<'a> {
+    world: &'a World,
+}
+
+impl Drop for ExclusiveAccessGuard<'_> {
+    fn drop(&mut self) {
+        self.world.exclusive_access_end(false);
+    }
+}
+
 /// Pipeline mixin implementation
 impl World {
     /// Create a new [`Pipeline`](crate::addons::pipeline::Pipeline).
@@ -134,6 +165,104 @@ impl World {
         unsafe { sys::ecs_progress(self.raw_world.as_ptr(), delta_time) }
     }
 
+    /// Progress world by delta time and return how long the frame took.
+    ///
+    /// Behaves like [`World::progress_time()`], but enables frame and system time
+    /// measurement and returns the difference in the world info counters before
+    /// and after the frame. Measurement stays enabled after the call returns.
+    ///
+    /// # Arguments
+    ///
+    /// * `delta_time` - The time to progress the world by. Pass 0.0 for automatic time measurement.
+    ///
+    /// # Returns
+    ///
+    /// The [`FrameStats`] for the frame that was just run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let stats = world.progress_timed(0.0);
+    ///
+    /// assert!(stats.frame_time >= 0.0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::progress_time()`]
+    /// * [`World::info()`]
+    pub fn progress_timed(&self, delta_time: FTime) -> FrameStats {
+        let world = self.raw_world.as_ptr();
+        // SAFETY: the world pointer is valid for the lifetime of `self`.
+        unsafe {
+            sys::ecs_measure_frame_time(world, true);
+            sys::ecs_measure_system_time(world, true);
+        }
+
+        let before = self.info();
+        self.progress_time(delta_time);
+        let after = self.info();
+
+        FrameStats {
+            frame_time: after.frame_time_total - before.frame_time_total,
+            system_time: after.system_time_total - before.system_time_total,
+            merge_time: after.merge_time_total - before.merge_time_total,
+        }
+    }
+
+    /// Progress world until a predicate holds.
+    ///
+    /// Runs [`World::progress_time()`] in a loop and checks `predicate` after each frame,
+    /// stopping as soon as it returns `true` or after `max_frames` frames. The loop also
+    /// stops when [`World::quit()`] has been called.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_frames` - The maximum number of frames to run.
+    /// * `delta_time` - The time to progress the world by each frame. Pass 0.0 for automatic time measurement.
+    /// * `predicate` - The condition to check after each frame.
+    ///
+    /// # Returns
+    ///
+    /// True if the predicate became true, false if the frame limit was reached or the world quit first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// assert!(world.progress_until(100, 1.0, |world| world.info().frame_count_total == 5));
+    /// assert!(!world.progress_until(3, 1.0, |_| false));
+    /// assert_eq!(world.info().frame_count_total, 8);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::progress_time()`]
+    pub fn progress_until(
+        &self,
+        max_frames: u32,
+        delta_time: FTime,
+        mut predicate: impl FnMut(&World) -> bool,
+    ) -> bool {
+        for _ in 0..max_frames {
+            let running = self.progress_time(delta_time);
+            if predicate(self) {
+                return true;
+            }
+            if !running {
+                return false;
+            }
+        }
+        false
+    }
+
     /// Run pipeline.
     /// Runs all systems in the specified pipeline. Can be invoked from multiple
     /// threads if staging is disabled, managing staging and, if needed, thread
@@ -196,6 +325,9 @@ impl World {
 
     /// Set time scale. Increase or decrease simulation speed by the provided multiplier.
     ///
+    /// The scale is applied to the delta time passed to systems by [`World::progress()`].
+    /// A scale of 0 pauses time: frames still run, but systems see a delta time of 0.
+    ///
     /// # Arguments
     ///
     /// * `mul` - The multiplier to set the time scale to.
@@ -229,6 +361,45 @@ impl World {
         self.info().time_scale
     }
 
+    /// Get the delta time of the last frame.
+    ///
+    /// This is the time passed to systems during the last [`World::progress()`] call, after
+    /// the time scale has been applied.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// world.set_time_scale(0.5);
+    /// world.progress_time(2.0);
+    ///
+    /// assert_eq!(world.delta_time(), 1.0);
+    /// assert_eq!(world.delta_time_raw(), 2.0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::delta_time_raw()`]
+    /// * [`World::set_time_scale()`]
+    /// * [`TableIter::delta_time()`]
+    #[inline(always)]
+    pub fn delta_time(&self) -> FTime {
+        self.info().delta_time
+    }
+
+    /// Get the delta time of the last frame before the time scale was applied.
+    ///
+    /// # See also
+    ///
+    /// * [`World::delta_time()`]
+    #[inline(always)]
+    pub fn delta_time_raw(&self) -> FTime {
+        self.info().delta_time_raw
+    }
+
     /// Get target frames per second (FPS).
     ///
     /// Retrieves the target FPS for the world. This value is used to calculate
@@ -262,14 +433,34 @@ impl World {
     /// when there is surplus time within a frame. This accounts for time consumed both
     /// within Flecs and in external operations.
     ///
+    /// This does not require the `App` wrapper. The
+    /// sleep is inserted in every frame, but the delta time passed to systems
+    /// is only measured (and includes the time slept) when the frame is run with
+    /// [`World::progress()`] or with a `delta_time` of 0. A non-zero `delta_time`
+    /// passed to [`World::progress_time()`] is used as is.
+    ///
     /// # Arguments
     ///
-    /// * `world` - The world context.
-    /// * `fps` - The desired target FPS as a floating-point number.
+    /// * `target_fps` - The desired target FPS as a floating-point number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// world.set_target_fps(60.0);
+    ///
+    /// assert_eq!(world.get_target_fps(), 60.0);
+    ///
+    /// world.progress();
+    /// ```
     ///
     /// # See also
     ///
     /// * [`World::get_target_fps()`]
+    /// * C API: `ecs_set_target_fps`
     #[inline(always)]
     pub fn set_target_fps(&self, target_fps: FTime) {
         unsafe {
@@ -414,6 +605,53 @@ impl World {
         }
     }
 
+    /// Begin exclusive thread access for the lifetime of the returned guard.
+    ///
+    /// Same as [`World::exclusive_access_begin()`], except that exclusive access is ended
+    /// with [`World::exclusive_access_end()`] when the returned [`ExclusiveAccessGuard`] is
+    /// dropped, also when unwinding. The guard must be dropped on the thread that created
+    /// it, which is the only thread that can access the world while it is held.
+    ///
+    /// # Panics
+    ///
+    /// Like [`World::exclusive_access_begin()`], this panics if another thread has
+    /// exclusive access, or if the current thread already has it.
+    ///
+    /// # Note
+    ///
+    /// This feature only works in builds where asserts are enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `thread_name` - Name of the thread obtaining exclusive access, used in debug messages.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// {
+    ///     let _access = world.exclusive_access_scope(Some(c"loader"));
+    ///     world.entity_named("level");
+    /// }
+    ///
+    /// // other threads can access the world again
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::exclusive_access_begin()`]
+    /// * [`World::exclusive_access_end()`]
+    pub fn exclusive_access_scope(
+        &self,
+        thread_name: Option<&'static CStr>,
+    ) -> ExclusiveAccessGuard<'_> {
+        self.exclusive_access_begin(thread_name);
+        ExclusiveAccessGuard { world: self }
+    }
+
     /// End exclusive thread access to the world.
     ///
     /// # Panics
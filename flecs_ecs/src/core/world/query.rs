@@ -72,6 +72,12 @@ impl World {
 
     /// Create a new named [`QueryBuilder`].
     ///
+    /// The built query is associated with an entity with the provided name, so it can be
+    /// found with [`World::lookup()`] and shows up under its name in the explorer and in
+    /// statistics. This makes the query a retained world resource: dropping the returned
+    /// [`Query`] doesn't delete it, it lives until [`Query::destruct()`] is called or the
+    /// world is deleted. [`World::query_from()`] returns a handle to it from its entity.
+    ///
     /// # Type Parameters
     ///
     /// * `Components` - The components to match on.
@@ -84,6 +90,25 @@ impl World {
     ///
     /// A new query builder.
     ///
+    /// # Example
+    ///
+    /// ```
+    /// # use flecs_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.entity().set(Position { x: 1.0, y: 2.0 });
+    ///
+    /// world.query_named::<&Position>("VisibleEntities").build();
+    ///
+    /// let entity = world.lookup("VisibleEntities");
+    /// assert_eq!(world.query_from(entity).count(), 1);
+    /// ```
+    ///
     /// # See also
     ///
     /// * [`World::new_query()`]
@@ -149,16 +174,53 @@ impl World {
 
     /// Create and iterate an uncached query.
     ///
-    /// This function creates a query and immediately iterates it.
+    /// This function creates a query and immediately iterates it. It is a
+    /// shorthand for one-off iterations; the query is created on every call, so
+    /// for repeated iteration retaining a query from [`World::query()`] or
+    /// [`World::new_query()`] is more efficient.
+    ///
+    /// # Arguments
+    ///
+    /// * `func`: The callback invoked for each matching entity.
     ///
     /// # Returns
     ///
-    /// The query.
+    /// The query, which can be iterated again.
     ///
     /// # Type Parameters
     ///
     /// * `Components`: The components to match on.
     ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Velocity {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world
+    ///     .entity()
+    ///     .set(Position { x: 0.0, y: 0.0 })
+    ///     .set(Velocity { x: 1.0, y: 2.0 });
+    ///
+    /// world.each::<(&mut Position, &Velocity)>(|(p, v)| {
+    ///     p.x += v.x;
+    ///     p.y += v.y;
+    /// });
+    /// ```
+    ///
     /// # See also
     ///
     /// * [`QueryAPI::each()`]
@@ -172,18 +234,43 @@ impl World {
         query
     }
 
-    /// Create and iterate an uncached query.
+    /// Create and iterate an uncached query, passing the matched entity.
+    ///
+    /// Like [`World::each()`], the query is created on every call, so for
+    /// repeated iteration a retained query is more efficient.
+    ///
+    /// # Arguments
     ///
-    /// This function creates a query and immediately iterates it.
+    /// * `func`: The callback invoked for each matching entity.
     ///
     /// # Returns
     ///
-    /// The query.
+    /// The query, which can be iterated again.
     ///
     /// # Type Parameters
     ///
     /// * `Components`: The components to match on.
     ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.entity_named("a").set(Position { x: 1.0, y: 2.0 });
+    ///
+    /// world.each_entity::<&Position>(|e, p| {
+    ///     println!("{}: ({}, {})", e.name(), p.x, p.y);
+    /// });
+    /// ```
+    ///
     /// # See also
     ///
     /// * [`QueryAPI::each_entity()`]
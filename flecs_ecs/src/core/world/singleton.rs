@@ -359,9 +359,14 @@ impl World {
     /// * `first` - The first element of the pair for which to retrieve the target.
     /// * `index` - The index (0 for the first instance of the relationship).
     ///
+    /// # Returns
+    ///
+    /// The target entity, or entity `0` if the singleton has no such target.
+    ///
     /// # See also
     ///
-    /// * [`World::target()`]
+    /// * [`World::try_target()`]
+    /// * [`EntityView::target()`]
     pub fn target(&self, relationship: impl IntoEntity, index: Option<usize>) -> EntityView<'_> {
         let relationship = *relationship.into_entity(self);
         EntityView::new_from(self, unsafe {
@@ -374,6 +379,53 @@ impl World {
         })
     }
 
+    /// Retrieves the target for a given pair from a singleton entity, if it has one.
+    ///
+    /// A relationship added to the world with [`World::add()`] is stored on the
+    /// relationship entity itself, which acts as the holder of the singleton.
+    /// This reads the target back from that entity, like [`EntityView::target()`]
+    /// does for regular entities.
+    ///
+    /// # Arguments
+    ///
+    /// * `relationship` - The first element of the pair for which to retrieve the target.
+    /// * `index` - The index (`None` or 0 for the first instance of the relationship).
+    ///
+    /// # Returns
+    ///
+    /// The target entity, or `None` if the singleton has no such target.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct CurrentLevel;
+    ///
+    /// let world = World::new();
+    ///
+    /// assert!(world.try_target(CurrentLevel::id(), None).is_none());
+    ///
+    /// let level = world.entity_named("level3");
+    /// world.add((CurrentLevel::id(), level));
+    ///
+    /// assert_eq!(world.try_target(CurrentLevel::id(), None), Some(level));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::target()`]
+    /// * [`EntityView::target()`]
+    pub fn try_target(
+        &self,
+        relationship: impl IntoEntity,
+        index: Option<usize>,
+    ) -> Option<EntityView<'_>> {
+        let relationship = relationship.into_entity(self);
+        EntityView::new_from(self, relationship).target(relationship, index.unwrap_or(0) as i32)
+    }
+
     /// Check if world has the provided id.
     ///
     /// # Arguments
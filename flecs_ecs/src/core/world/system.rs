@@ -73,4 +73,106 @@ impl World {
     {
         SystemBuilder::<Components>::new_from_desc(self, desc)
     }
+
+    /// Runs an ad-hoc system once over all entities matching `Components`.
+    ///
+    /// A transient system is built from `func`, run a single time and then
+    /// destroyed, also when `func` panics. The system is not assigned to a phase, so it never runs as
+    /// part of [`World::progress()`]. Because it runs through the regular system
+    /// machinery, structural changes made from `func` (adding, removing or
+    /// deleting) are deferred while the system runs and merged when it returns.
+    ///
+    /// If `func` panics, the remaining entities are skipped and the panic is resumed
+    /// once the system has been destructed.
+    ///
+    /// This is useful for one-off setup or migration passes that should behave
+    /// like a system without having to register and clean one up by hand.
+    ///
+    /// # Type Parameters
+    /// - `Components`: The components to match on. Must implement the `QueryTuple` trait.
+    ///
+    /// # Arguments
+    /// * `func` - The callback invoked for every matched entity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Initialized;
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world.entity().set(Position { x: 1.0, y: 2.0 });
+    ///
+    /// world.run_once::<&Position>(|e, _pos| {
+    ///     // deferred until the system has finished running
+    ///     e.add(Initialized);
+    ///     assert!(!e.has(Initialized));
+    /// });
+    ///
+    /// assert!(e.has(Initialized));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::system()`]
+    /// * [`System::run()`]
+    pub fn run_once<Components>(
+        &self,
+        func: impl FnMut(EntityView, Components::TupleType<'_>) + 'static,
+    ) where
+        Components: QueryTuple,
+    {
+        // A panic unwinding through the system run would skip finalizing its iterator and
+        // releasing its locks, so it's caught here and resumed once the system is destructed.
+        #[cfg(feature = "std")]
+        let (func, panic) = {
+            let mut func = func;
+            let panic = alloc::rc::Rc::new(core::cell::Cell::new(None));
+            let panic_c = panic.clone();
+            let func = move |e: EntityView, components: Components::TupleType<'_>| {
+                let payload = panic_c.take();
+                if payload.is_some() {
+                    // skip the remaining entities
+                    panic_c.set(payload);
+                    return;
+                }
+                let result =
+                    std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| func(e, components)));
+                panic_c.set(result.err());
+            };
+            (func, panic)
+        };
+
+        let system = self
+            .system::<Components>()
+            .kind(Entity::null())
+            .each_entity(func);
+        {
+            let _guard = DestructGuard(system.entity);
+            system.run();
+        }
+
+        #[cfg(feature = "std")]
+        if let Some(payload) = panic.take() {
+            std::panic::resume_unwind(payload);
+        }
+    }
+}
+
+/// Destructs the transient system of [`World::run_once()`], also when the run unwinds.
+struct DestructGuard<'a>(EntityView<'a>);
+
+impl Drop for DestructGuard<'_> {
+    fn drop(&mut self) {
+        self.0.destruct();
+    }
 }
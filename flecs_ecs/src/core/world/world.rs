@@ -1,5 +1,7 @@
 use core::ffi::c_void;
 use core::ptr::NonNull;
+
+use alloc::boxed::Box;
 use flecs_ecs_sys as sys;
 
 use crate::core::{
@@ -99,7 +101,7 @@ impl Default for World {
 
 impl Drop for World {
     fn drop(&mut self) {
-        if std::thread::panicking() {
+        if crate::core::utility::std_compat::thread_panicking() {
             return;
         }
 
@@ -169,6 +171,19 @@ impl World {
         world
     }
 
+    /// Returns a [`WorldBuilder`] to configure a world before it's created.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use flecs_ecs::core::World;
+    /// let world = World::builder().with_stages(2).build();
+    /// assert_eq!(world.get_stage_count(), 2);
+    /// ```
+    pub fn builder() -> WorldBuilder {
+        WorldBuilder::default()
+    }
+
     /// Explicitly release this world handle, decrementing the ref count.
     /// If this is the last handle, the world is finalized (`ecs_fini`).
     ///
@@ -223,6 +238,109 @@ impl World {
     }
 }
 
+/// Builder for a [`World`] with initial configuration, created with [`World::builder()`].
+///
+/// Options that aren't set keep the defaults of [`World::new()`].
+///
+/// # Example
+///
+/// ```
+/// # use flecs_ecs::core::World;
+/// let world = World::builder()
+///     .with_stages(4)
+///     .preallocate_entity_count(1000)
+///     .build();
+///
+/// assert_eq!(world.get_stage_count(), 4);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WorldBuilder {
+    mini: bool,
+    stages: Option<i32>,
+    #[cfg(feature = "flecs_pipeline")]
+    threads: Option<i32>,
+    entity_count: Option<i32>,
+}
+
+impl WorldBuilder {
+    /// Create the world without the builtin modules, like [`World::new_mini()`].
+    ///
+    /// The system, pipeline, timer and meta addons are not loaded, so the world has no
+    /// builtin pipeline to run systems with.
+    pub fn without_builtin_pipeline(&mut self) -> &mut Self {
+        self.mini = true;
+        self
+    }
+
+    /// Set the number of stages, see [`World::set_stage_count()`].
+    ///
+    /// Overridden by [`WorldBuilder::with_threads()`], which sets one stage per thread.
+    pub fn with_stages(&mut self, stages: i32) -> &mut Self {
+        self.stages = Some(stages);
+        self
+    }
+
+    /// Set the number of worker threads, see [`World::set_threads()`].
+    ///
+    /// This also sets the number of stages. It is applied after
+    /// [`WorldBuilder::with_stages()`], so the thread count overrides the stage count.
+    ///
+    /// # Panics
+    ///
+    /// [`WorldBuilder::build()`] panics if this is combined with
+    /// [`WorldBuilder::without_builtin_pipeline()`].
+    #[cfg(feature = "flecs_pipeline")]
+    pub fn with_threads(&mut self, threads: i32) -> &mut Self {
+        self.threads = Some(threads);
+        self
+    }
+
+    /// Preallocate memory for a number of entities, see
+    /// [`World::preallocate_entity_count()`].
+    pub fn preallocate_entity_count(&mut self, entity_count: i32) -> &mut Self {
+        self.entity_count = Some(entity_count);
+        self
+    }
+
+    /// Create the world with the configured options.
+    ///
+    /// The stage count is set first, then the thread count, after which entities are
+    /// preallocated.
+    ///
+    /// # Panics
+    ///
+    /// Panics before the world is created if [`WorldBuilder::with_threads()`] is combined
+    /// with [`WorldBuilder::without_builtin_pipeline()`].
+    pub fn build(&self) -> World {
+        #[cfg(feature = "flecs_pipeline")]
+        assert!(
+            !(self.mini && self.threads.is_some()),
+            "worker threads require the builtin pipeline, remove `without_builtin_pipeline()`"
+        );
+
+        let world = if self.mini {
+            World::new_mini()
+        } else {
+            World::new()
+        };
+
+        if let Some(stages) = self.stages {
+            world.set_stage_count(stages);
+        }
+
+        #[cfg(feature = "flecs_pipeline")]
+        if let Some(threads) = self.threads {
+            world.set_threads(threads);
+        }
+
+        if let Some(entity_count) = self.entity_count {
+            world.preallocate_entity_count(entity_count);
+        }
+
+        world
+    }
+}
+
 /// Owning handle to an asynchronous stage created with [`World::async_stage()`].
 ///
 /// The underlying stage is freed when this handle is dropped. See
@@ -261,7 +379,7 @@ impl<'a> AsyncStage<'a> {
 
 impl Drop for AsyncStage<'_> {
     fn drop(&mut self) {
-        if std::thread::panicking() {
+        if crate::core::utility::std_compat::thread_panicking() {
             return;
         }
         // SAFETY: this handle exclusively owns the stage and the lifetime `'a`
@@ -269,3 +387,45 @@ impl Drop for AsyncStage<'_> {
         unsafe { sys::ecs_stage_free(self.stage.raw_world.as_ptr()) };
     }
 }
+
+/// Keeps the world in readonly mode, created with [`World::readonly_scope()`].
+///
+/// Readonly mode is ended when the guard is dropped, which merges the commands
+/// that were enqueued on the stages while the guard was held.
+#[derive(Debug)]
+#[must_use = "readonly mode ends as soon as the guard is dropped"]
+pub struct ReadonlyGuard<'a> {
+    world: &'a World,
+}
+
+impl<'a> ReadonlyGuard<'a> {
+    pub(crate) fn new(world: &'a World) -> Self {
+        world.readonly_begin(false);
+        Self { world }
+    }
+}
+
+impl Drop for ReadonlyGuard<'_> {
+    fn drop(&mut self) {
+        self.world.readonly_end();
+    }
+}
+
+/// Calls `defer_begin` on construction and `defer_end` on drop, so the defer
+/// block is closed even when the user callback unwinds.
+pub(crate) struct DeferGuard<'w> {
+    world: WorldRef<'w>,
+}
+
+impl<'w> DeferGuard<'w> {
+    pub(crate) fn new(world: WorldRef<'w>) -> Self {
+        world.defer_begin();
+        Self { world }
+    }
+}
+
+impl Drop for DeferGuard<'_> {
+    fn drop(&mut self) {
+        self.world.defer_end();
+    }
+}
@@ -7,9 +7,10 @@ use core::cell::Cell;
 extern crate std;
 
 extern crate alloc;
+use crate::core::utility::std_compat::{self, Mutex, PoisonError, ThreadId};
+use alloc::ffi::CString;
 use alloc::sync::Arc;
 use alloc::vec;
-use std::sync::Mutex;
 
 pub(crate) struct WorldCtx {
     query_ref_count: Cell<i32>,
@@ -17,13 +18,31 @@ pub(crate) struct WorldCtx {
     pub(crate) components_array: FlecsArray,
     // Atomic because `QueryHandle::drop` reads it from other threads.
     is_panicking: core::sync::atomic::AtomicBool,
-    owning_thread: std::thread::ThreadId,
+    owning_thread: ThreadId,
     // Shared with every `QueryHandle`. `true` once world teardown has begun;
     // a handle dropping on another thread takes the lock so its refcount
     // release can never interleave with `ecs_fini` freeing query memory.
     world_dead: Arc<Mutex<bool>>,
+    // Scope entity of the `World::import_named` call currently running, or 0.
+    #[cfg(feature = "flecs_module")]
+    pub(crate) named_import_scope: Cell<u64>,
+    // Context set with `World::set_ctx`, used to tell it apart from a raw `World::set_context`.
+    pub(crate) typed_ctx: Cell<*mut core::ffi::c_void>,
+    // Owns the string passed to `ecs_set_name_prefix` by `World::set_component_name_prefix`.
+    pub(crate) name_prefix: Cell<Option<CString>>,
+    // Bumped by every `World::enable_rest`, so a replaced `RestServer` handle can tell it no longer owns the server.
+    #[cfg(feature = "flecs_rest")]
+    pub(crate) rest_server_generation: Cell<u32>,
+    // Component pointers borrowed through `get` while the pipeline runs multi threaded,
+    // with their read count or -1 for a write. Shared by all stages, unlike the column locks,
+    // and sharded by pointer so workers borrowing different components rarely contend.
+    #[cfg(feature = "flecs_safety_locks")]
+    multithreaded_borrows: [Mutex<hashbrown::HashMap<usize, i32>>; MULTITHREADED_BORROW_SHARDS],
 }
 
+#[cfg(feature = "flecs_safety_locks")]
+const MULTITHREADED_BORROW_SHARDS: usize = 16;
+
 impl WorldCtx {
     pub(crate) fn new() -> Self {
         Self {
@@ -31,8 +50,16 @@ impl WorldCtx {
             components: Default::default(),
             components_array: vec![0; 500],
             is_panicking: core::sync::atomic::AtomicBool::new(false),
-            owning_thread: std::thread::current().id(),
+            owning_thread: std_compat::current_thread_id(),
             world_dead: Arc::new(Mutex::new(false)),
+            #[cfg(feature = "flecs_module")]
+            named_import_scope: Cell::new(0),
+            typed_ctx: Cell::new(core::ptr::null_mut()),
+            name_prefix: Cell::new(None),
+            #[cfg(feature = "flecs_rest")]
+            rest_server_generation: Cell::new(0),
+            #[cfg(feature = "flecs_safety_locks")]
+            multithreaded_borrows: core::array::from_fn(|_| Mutex::new(hashbrown::HashMap::new())),
         }
     }
 
@@ -44,11 +71,11 @@ impl WorldCtx {
         let mut dead = self
             .world_dead
             .lock()
-            .unwrap_or_else(std::sync::PoisonError::into_inner);
+            .unwrap_or_else(PoisonError::into_inner);
         *dead = true;
     }
 
-    pub(crate) fn owning_thread(&self) -> std::thread::ThreadId {
+    pub(crate) fn owning_thread(&self) -> ThreadId {
         self.owning_thread
     }
 
@@ -85,6 +112,54 @@ impl WorldCtx {
         self.query_ref_count.get() == 0
     }
 
+    /// Registers a borrow of the component data at `ptr` made while the pipeline runs
+    /// multi threaded. Returns `false`, without registering anything, if the borrow
+    /// conflicts with one held by any thread: a write conflicts with everything, a read
+    /// only with a write.
+    #[cfg(feature = "flecs_safety_locks")]
+    pub(crate) fn multithreaded_borrow_begin(&self, ptr: usize, write: bool) -> bool {
+        let mut borrows = self.multithreaded_borrow_shard(ptr);
+        match borrows.entry(ptr) {
+            hashbrown::hash_map::Entry::Occupied(entry) if write || *entry.get() < 0 => false,
+            hashbrown::hash_map::Entry::Occupied(mut entry) => {
+                *entry.get_mut() += 1;
+                true
+            }
+            hashbrown::hash_map::Entry::Vacant(entry) => {
+                entry.insert(if write { -1 } else { 1 });
+                true
+            }
+        }
+    }
+
+    /// Releases a borrow registered with [`Self::multithreaded_borrow_begin`].
+    #[cfg(feature = "flecs_safety_locks")]
+    pub(crate) fn multithreaded_borrow_end(&self, ptr: usize, write: bool) {
+        let mut borrows = self.multithreaded_borrow_shard(ptr);
+        if let hashbrown::hash_map::Entry::Occupied(mut entry) = borrows.entry(ptr) {
+            let count = entry.get_mut();
+            if write {
+                *count = 0;
+            } else {
+                *count -= 1;
+            }
+            if *count == 0 {
+                entry.remove();
+            }
+        }
+    }
+
+    #[cfg(feature = "flecs_safety_locks")]
+    fn multithreaded_borrow_shard(
+        &self,
+        ptr: usize,
+    ) -> impl core::ops::DerefMut<Target = hashbrown::HashMap<usize, i32>> + '_ {
+        // a pointer always maps to the same shard; the low bits mostly repeat for aligned data
+        self.multithreaded_borrows[(ptr >> 3) % MULTITHREADED_BORROW_SHARDS]
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
     pub(crate) fn set_is_panicking_true(&self) {
         self.is_panicking
             .store(true, core::sync::atomic::Ordering::Relaxed);
@@ -93,7 +168,7 @@ impl WorldCtx {
     pub(crate) fn is_panicking(&self) -> bool {
         self.is_panicking
             .load(core::sync::atomic::Ordering::Relaxed)
-            || std::thread::panicking()
+            || std_compat::thread_panicking()
     }
 }
 
@@ -135,9 +210,19 @@ impl World {
         }
     }
 
+    /// Asserts that the context set with `World::set_ctx` is accessed on the
+    /// thread that owns the world. The context is neither required to be
+    /// `Send` nor `Sync`, so it can't be shared with worker threads.
+    #[inline(always)]
+    pub(crate) fn check_typed_ctx_thread(&self) {
+        if std_compat::current_thread_id() != self.world_ctx().owning_thread() {
+            typed_ctx_thread_violation();
+        }
+    }
+
     #[inline(always)]
     fn assert_owning_thread<T>(&self) {
-        if std::thread::current().id() != self.world_ctx().owning_thread() {
+        if std_compat::current_thread_id() != self.world_ctx().owning_thread() {
             thread_affinity_violation(core::any::type_name::<T>());
         }
     }
@@ -151,6 +236,14 @@ fn thread_affinity_violation(type_name: &str) -> ! {
     );
 }
 
+#[cold]
+#[inline(never)]
+fn typed_ctx_thread_violation() -> ! {
+    panic!(
+        "the world context set with `World::set_ctx` can only be accessed from the thread that owns the world"
+    );
+}
+
 /// Mirrors the C-side `ecs_assert` in `flecs_new_id`, which is compiled out in
 /// release builds (`NDEBUG`): entity id allocation mutates the shared entity
 /// index without synchronization, so creating entities during the
@@ -741,6 +741,27 @@
 //!
 //! This will only match entities that have `(LocatedIn, SanFrancisco)` and not, for example, entities with `(LocatedIn, GoldenGateBridge)`.
 //!
+//! Without the `self` modifier no extra syntax is needed to match a chain transitively. Given `A` located in `B` located in `C`, the following query matches both `A` and `B`:
+//!
+//! ```rust
+//! # use flecs_ecs::prelude::*;
+//! # let world = World::new();
+//! # #[derive(Component)]
+//! # struct LocatedIn;
+//! world
+//!     .component::<LocatedIn>()
+//!     .add_trait::<flecs::Transitive>();
+//!
+//! let c = world.entity_named("C");
+//! let b = world.entity_named("B").add((LocatedIn::id(), c));
+//! let a = world.entity_named("A").add((LocatedIn::id(), b));
+//!
+//! let q = query!(world, (LocatedIn, $c)).build();
+//! assert_eq!(q.count(), 2);
+//! ```
+//!
+//! If the relationship also has the `Reflexive` trait, `C` itself is matched as well.
+//!
 //! ## Advanced
 //!
 //! ### Equality operators
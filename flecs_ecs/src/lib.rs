@@ -22,9 +22,16 @@
 //! improve throughput but removes the runtime protections and may lead to
 //! undefined behavior if the API is used in an unsafe way. This might or might not matter
 //! depending on the application.
+//!
+//! ## `no_std`
+//!
+//! The crate builds against `core` and `alloc` only when the `std` feature is
+//! disabled, e.g. with `default-features = false` and the `flecs_base_no_std`
+//! feature, since `flecs_base` enables `std`. The `flecs_app` addon requires
+//! `std`. Without `std`, panics in component lifecycle hooks can't be caught,
+//! so `no_std` targets should abort on panic.
 
-//this is commented since `no_std` is not ready yet
-//#![cfg_attr(not(feature = "std"), no_std)] // Enable `no_std` if `std` feature is disabled
+#![cfg_attr(not(feature = "std"), no_std)] // Enable `no_std` if `std` feature is disabled
 #![allow(dead_code)]
 #![allow(clippy::module_inception)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
@@ -37,9 +44,6 @@ compile_error!(
     "Features 'flecs_force_build_release_c' and 'flecs_force_build_debug_c' cannot be enabled at the same time."
 );
 
-#[cfg(not(feature = "std"))]
-const _: () = panic!("no_std is not ready yet");
-
 #[cfg(feature = "std")]
 extern crate std;
 
@@ -1,4 +1,4 @@
-//! Compile-fail tests for thread-safety guarantees.
+//! Compile-fail tests for thread-safety and component data guarantees.
 //!
 //! Each file in `tests/compile_fail/` must fail to compile; the expected
 //! compiler output lives in the matching `.stderr` file.
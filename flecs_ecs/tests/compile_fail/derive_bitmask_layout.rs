@@ -0,0 +1,18 @@
+//! `bitmask` components are reflected as a `u32`, so they must have its layout.
+
+use flecs_ecs::prelude::*;
+
+#[derive(Component)]
+#[flecs(bitmask(Read = 0x1))]
+struct NotReprC {
+    bits: u32,
+}
+
+#[derive(Component)]
+#[repr(C)]
+#[flecs(bitmask(Read = 0x1))]
+struct TooLarge {
+    bits: u64,
+}
+
+fn main() {}
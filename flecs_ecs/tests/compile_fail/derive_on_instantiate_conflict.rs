@@ -0,0 +1,13 @@
+//! A component has a single `OnInstantiate` policy.
+
+use flecs_ecs::prelude::*;
+
+#[derive(Component)]
+#[flecs(inherit, override)]
+struct Conflicting;
+
+#[derive(Component)]
+#[flecs(inherit, traits((OnInstantiate, Inherit)))]
+struct Duplicate;
+
+fn main() {}
@@ -0,0 +1,14 @@
+//! Only builtin flecs component traits can be used in `traits(...)`.
+
+use flecs_ecs::prelude::*;
+
+// `Name` is a builtin component, but not a component trait
+#[derive(Component)]
+#[flecs(traits(Name))]
+struct NotATrait;
+
+#[derive(Component)]
+#[flecs(traits(Exclusve))]
+struct Typo;
+
+fn main() {}
@@ -0,0 +1,23 @@
+//! When the first element of a pair is a component, the pair value is stored in
+//! the first element, so it can't be read with `get_second`.
+
+use flecs_ecs::prelude::*;
+
+#[derive(Component)]
+struct Position {
+    x: f32,
+}
+
+#[derive(Component)]
+struct Velocity {
+    x: f32,
+}
+
+fn main() {
+    let world = World::new();
+    let e = world
+        .entity()
+        .set_pair::<Position, Velocity>(Position { x: 1.0 });
+
+    e.get_second::<Position, Velocity>(|v| v.x);
+}
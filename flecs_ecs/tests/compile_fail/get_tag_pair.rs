@@ -0,0 +1,16 @@
+//! A pair of two tags carries no data, so it can't be read with `get`.
+
+use flecs_ecs::prelude::*;
+
+#[derive(Component)]
+struct Likes;
+
+#[derive(Component)]
+struct Apples;
+
+fn main() {
+    let world = World::new();
+    let e = world.entity().add((Likes::id(), Apples::id()));
+
+    e.get::<&(Likes, Apples)>(|_| {});
+}
@@ -143,3 +143,129 @@ fn system_observer_debug_display_eq() {
     assert!(!format!("{obs_a:?}").is_empty());
     assert!(!format!("{obs_a}").is_empty());
 }
+
+#[test]
+fn script_builder_set_var() {
+    let world = World::new();
+
+    #[derive(Component, Default, Clone)]
+    struct SpawnCount {
+        value: i32,
+    }
+
+    world.component::<SpawnCount>().member(i32::id(), "value");
+
+    world
+        .script()
+        .set_var("spawn_count", 3i32)
+        .build_from_code("spawner { SpawnCount: {$spawn_count} }");
+
+    let spawner = world.lookup("spawner");
+    spawner.get::<&SpawnCount>(|c| assert_eq!(c.value, 3));
+
+    world
+        .script()
+        .set_var("spawn_count", 5i32)
+        .build_from_code("spawner2 { SpawnCount: {$spawn_count} }");
+
+    world
+        .lookup("spawner2")
+        .get::<&SpawnCount>(|c| assert_eq!(c.value, 5));
+}
+
+/// Returns `N` distinct local ports that are free at the time of the call. Flecs treats
+/// port 0 as its default port, so the OS can't pick one for the REST server.
+#[cfg(feature = "flecs_rest")]
+fn free_ports<const N: usize>() -> [u16; N] {
+    // the listeners are kept until all ports are known, so the same port isn't returned twice
+    let listeners: Vec<_> = (0..N)
+        .map(|_| std::net::TcpListener::bind("127.0.0.1:0").expect("no free local port"))
+        .collect();
+    core::array::from_fn(|i| listeners[i].local_addr().unwrap().port())
+}
+
+#[test]
+#[cfg(feature = "flecs_rest")]
+fn rest_server_enable_and_drop() {
+    use flecs_ecs::addons::rest::RestConfig;
+
+    let world = World::new();
+
+    let [port] = free_ports();
+    let config = RestConfig {
+        port,
+        ipaddr: Some("127.0.0.1".to_string()),
+    };
+
+    let server = world.enable_rest(config.clone());
+    assert!(server.is_running());
+    world.get::<&flecs::rest::Rest>(|rest| assert_eq!(rest.port, port));
+
+    world.progress();
+    world.progress();
+
+    drop(server);
+    assert!(!world.has(flecs::rest::Rest::id()));
+
+    // the port is released, so a new server can bind to it again
+    let server = world.enable_rest(config);
+    assert!(server.is_running());
+    server.stop();
+    assert!(!world.has(flecs::rest::Rest::id()));
+}
+
+#[test]
+#[cfg(feature = "flecs_rest")]
+fn rest_server_replaced_by_second_enable() {
+    use flecs_ecs::addons::rest::RestConfig;
+
+    let world = World::new();
+
+    let [first_port, second_port] = free_ports();
+    let first = world.enable_rest(RestConfig {
+        port: first_port,
+        ipaddr: Some("127.0.0.1".to_string()),
+    });
+    let second = world.enable_rest(RestConfig {
+        port: second_port,
+        ipaddr: Some("127.0.0.1".to_string()),
+    });
+
+    assert!(!first.is_running());
+    assert!(second.is_running());
+
+    // dropping the replaced handle leaves the new server running
+    drop(first);
+    assert!(second.is_running());
+    world.get::<&flecs::rest::Rest>(|rest| assert_eq!(rest.port, second_port));
+}
+
+#[test]
+#[cfg(feature = "flecs_doc")]
+fn doc_link_and_color_on_component() {
+    use flecs_ecs::addons::doc::Doc;
+
+    let world = World::new();
+
+    let position = world.component::<Position>();
+    assert_eq!(position.doc_link(), None);
+    assert_eq!(position.doc_color(), None);
+
+    position
+        .set_doc_link("https://www.flecs.dev/flecs/")
+        .set_doc_color("#4287f5");
+
+    assert_eq!(
+        position.doc_link().as_deref(),
+        Some("https://www.flecs.dev/flecs/")
+    );
+    assert_eq!(position.doc_color().as_deref(), Some("#4287f5"));
+
+    // stored as doc components on the component entity
+    assert!(position.has((flecs::doc::Description, flecs::doc::Link)));
+    assert!(position.has((flecs::doc::Description, flecs::doc::Color)));
+    assert_eq!(
+        world.doc_link(position).as_deref(),
+        Some("https://www.flecs.dev/flecs/")
+    );
+}
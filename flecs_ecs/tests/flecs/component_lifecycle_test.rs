@@ -4194,3 +4194,46 @@ fn on_validate_hook_blocks_observer() {
     assert_eq!(validate_count.get(), 2);
     assert_eq!(observer_count.get(), 1);
 }
+
+thread_local! {
+    static EAGER_DROP_INVOKED: Cell<i32> = const { Cell::new(0) };
+}
+
+#[derive(Component, Default)]
+struct EagerDrop {
+    value: i32,
+}
+
+impl Drop for EagerDrop {
+    fn drop(&mut self) {
+        EAGER_DROP_INVOKED.with(|c| c.set(c.get() + 1));
+    }
+}
+
+#[test]
+fn register_components_sets_hooks_up_front() {
+    let world = World::new();
+
+    assert!(!EagerDrop::is_registered_with_world(&world));
+
+    world.register_components::<(EagerDrop, Position)>();
+
+    assert!(EagerDrop::is_registered_with_world(&world));
+    assert!(Position::is_registered_with_world(&world));
+
+    let ti = world.type_info(world.component_id::<EagerDrop>()).unwrap();
+    assert!(ti.has_ctor);
+    assert!(ti.has_dtor);
+    assert!(ti.has_move);
+
+    let id = world.component_id::<EagerDrop>();
+    world.register_components::<(EagerDrop,)>();
+    assert_eq!(world.component_id::<EagerDrop>(), id);
+
+    EAGER_DROP_INVOKED.with(|c| c.set(0));
+    world.entity().set(EagerDrop { value: 1 });
+    assert_eq!(EAGER_DROP_INVOKED.with(Cell::get), 0);
+
+    drop(world);
+    assert_eq!(EAGER_DROP_INVOKED.with(Cell::get), 1);
+}
@@ -223,3 +223,136 @@ fn on_component_registration_named() {
         assert_eq!(count.0, 2);
     });
 }
+
+#[test]
+fn component_named_per_world() {
+    #[derive(Component)]
+    struct NetPosition {
+        x: i32,
+        y: i32,
+    }
+
+    let world_a = World::new();
+    let world_b = World::new();
+
+    let a = world_a.component_named::<NetPosition>("ClientPosition");
+    let a_id = a.id();
+
+    // pad world_b so the type gets a different id there
+    for _ in 0..10 {
+        world_b.entity();
+    }
+
+    let b = world_b.component_named::<NetPosition>("ServerPosition");
+
+    assert_eq!(a.name(), "ClientPosition");
+    assert_eq!(b.name(), "ServerPosition");
+
+    // registering in the second world leaves the first world untouched
+    assert_eq!(world_a.component::<NetPosition>().id(), a_id);
+    assert_eq!(world_a.component::<NetPosition>().name(), "ClientPosition");
+    assert_eq!(world_b.component::<NetPosition>().name(), "ServerPosition");
+    assert!(world_a.try_lookup("ServerPosition").is_none());
+
+    // already registered: the name argument is ignored
+    let again = world_a.component_named::<NetPosition>("Other");
+    assert_eq!(again.id(), a_id);
+    assert_eq!(again.name(), "ClientPosition");
+
+    let ea = world_a.entity().set(NetPosition { x: 1, y: 2 });
+    let eb = world_b.entity().set(NetPosition { x: 3, y: 4 });
+
+    ea.get::<&NetPosition>(|p| assert_eq!((p.x, p.y), (1, 2)));
+    eb.get::<&NetPosition>(|p| assert_eq!((p.x, p.y), (3, 4)));
+}
+
+#[test]
+fn component_name_prefix_stripped_at_registration() {
+    mod physics {
+        use flecs_ecs::prelude::*;
+
+        #[derive(Component)]
+        pub struct Position {
+            pub x: i32,
+            pub y: i32,
+        }
+    }
+
+    #[derive(Component)]
+    struct Velocity {
+        x: i32,
+        y: i32,
+    }
+
+    let world = World::new();
+    world.set_component_name_prefix("my_crate.physics.components.");
+
+    let pos = world.component_named::<physics::Position>("my_crate::physics::components::Position");
+
+    assert_eq!(pos.name(), "Position");
+    assert_eq!(pos.path().unwrap(), "::Position");
+    assert_eq!(world.lookup("Position"), pos.entity());
+    assert!(
+        world
+            .try_lookup("my_crate::physics::components::Position")
+            .is_none()
+    );
+
+    // the symbol still is the full type path, so it stays unique
+    assert_eq!(pos.symbol(), core::any::type_name::<physics::Position>());
+    assert_eq!(world.component::<physics::Position>().id(), pos.id());
+
+    // clearing the prefix registers path names in full again
+    world.set_component_name_prefix("");
+    let vel = world.component_named::<Velocity>("my_crate::physics::components::Velocity");
+    assert_eq!(
+        vel.path().unwrap(),
+        "::my_crate::physics::components::Velocity"
+    );
+}
+
+#[test]
+#[should_panic(expected = "component name prefix must not contain a NUL byte")]
+fn component_name_prefix_with_nul_panics() {
+    let world = World::new();
+    world.set_component_name_prefix("my_crate\0physics.");
+}
+
+#[test]
+fn world_components_lists_registered_components() {
+    #[derive(Component)]
+    struct Velocity {
+        x: f64,
+        y: f64,
+    }
+
+    #[derive(Component)]
+    struct Frozen;
+
+    let world = World::new();
+
+    let find = |id: Entity| {
+        let mut found = None;
+        world.components(|c| {
+            if c.id() == id {
+                found = Some((c.name(), c.size(), c.alignment(), c.is_tag()));
+            }
+        });
+        found
+    };
+
+    let e = world.entity().set(Velocity { x: 1.0, y: 2.0 }).add(Frozen);
+
+    let (name, size, alignment, is_tag) = find(world.component::<Velocity>().id()).unwrap();
+    assert_eq!(name, "Velocity");
+    assert_eq!(size, core::mem::size_of::<Velocity>());
+    assert_eq!(alignment, core::mem::align_of::<Velocity>());
+    assert!(!is_tag);
+
+    let (name, size, _, is_tag) = find(world.component::<Frozen>().id()).unwrap();
+    assert_eq!(name, "Frozen");
+    assert_eq!(size, 0);
+    assert!(is_tag);
+
+    assert!(find(e.id()).is_none());
+}
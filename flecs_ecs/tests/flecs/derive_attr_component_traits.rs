@@ -39,6 +39,48 @@ mod component_traits_attributes {
     #[flecs(traits(Exclusive))]
     struct TExclusive;
 
+    // Never registered; only checks that every name the derive accepts in `traits(...)`
+    // is a `FlecsComponentTrait`.
+    #[derive(Component)]
+    #[flecs(traits(
+        Acyclic,
+        CanToggle,
+        ChildOf,
+        Delete,
+        DependsOn,
+        Disabled,
+        DontFragment,
+        DontInherit,
+        Exclusive,
+        Final,
+        Inherit,
+        Inheritable,
+        IsA,
+        NotQueryable,
+        OnDelete,
+        OnDeleteTarget,
+        OnInstantiate,
+        OneOf,
+        OrderedChildren,
+        Override,
+        PairIsTag,
+        Panic,
+        Prefab,
+        Reflexive,
+        Relationship,
+        Remove,
+        Singleton,
+        SlotOf,
+        Sparse,
+        Symmetric,
+        Target,
+        Trait,
+        Transitive,
+        Traversable,
+        With
+    ))]
+    struct TAllBuiltinTraits;
+
     #[derive(Component)]
     #[flecs(traits(Acyclic))]
     struct TAcyclic;
@@ -153,6 +195,149 @@ mod component_traits_attributes {
         assert!(c.has(flecs::Inheritable));
         assert!(c.has(flecs::Sparse));
     }
+
+    #[derive(Component)]
+    #[flecs(traits(Exclusive, Acyclic))]
+    struct Likes;
+
+    #[test]
+    fn exclusive_trait_replaces_target() {
+        let world = World::new();
+
+        let alice = world.entity();
+        let bob = world.entity();
+
+        // Likes is registered by the first add, which must already apply the traits
+        let e = world.entity().add((Likes::id(), alice));
+        e.add((Likes::id(), bob));
+
+        assert!(!e.has((Likes::id(), alice)));
+        assert!(e.has((Likes::id(), bob)));
+        assert_eq!(e.target_count::<Likes>(), Some(1));
+
+        let c = world.component::<Likes>();
+        assert!(c.has(flecs::Exclusive));
+        assert!(c.has(flecs::Acyclic));
+    }
+
+    #[derive(Component)]
+    #[flecs(can_toggle)]
+    struct Toggleable;
+
+    #[test]
+    fn can_toggle_attribute() {
+        let world = World::new();
+
+        let c = world.component::<Toggleable>();
+        assert!(c.has(flecs::CanToggle));
+
+        let e = world.entity().add(Toggleable::id());
+        e.toggle(Toggleable::id(), false);
+        assert!(!e.is_enabled(Toggleable::id()));
+        e.toggle(Toggleable::id(), true);
+        assert!(e.is_enabled(Toggleable::id()));
+    }
+
+    #[derive(Component)]
+    #[flecs(symmetric)]
+    struct Friend;
+
+    #[test]
+    fn symmetric_attribute() {
+        let world = World::new();
+
+        let c = world.component::<Friend>();
+        assert!(c.has(flecs::Symmetric));
+
+        let alice = world.entity();
+        let bob = world.entity().add((Friend::id(), alice));
+        assert!(alice.has((Friend::id(), bob)));
+
+        alice.remove((Friend::id(), bob));
+        assert!(!bob.has((Friend::id(), alice)));
+    }
+
+    #[derive(Component, Clone)]
+    #[flecs(override)]
+    struct OverrideHealth(i32);
+
+    #[derive(Component)]
+    #[flecs(inherit)]
+    struct InheritHealth(i32);
+
+    #[derive(Component)]
+    #[flecs(dont_inherit)]
+    struct DontInheritHealth(i32);
+
+    #[test]
+    fn on_instantiate_attributes() {
+        let world = World::new();
+
+        assert!(
+            world
+                .component::<OverrideHealth>()
+                .has((flecs::OnInstantiate, flecs::Override))
+        );
+        assert!(
+            world
+                .component::<InheritHealth>()
+                .has((flecs::OnInstantiate, flecs::Inherit))
+        );
+        assert!(
+            world
+                .component::<DontInheritHealth>()
+                .has((flecs::OnInstantiate, flecs::DontInherit))
+        );
+
+        let prefab = world
+            .prefab()
+            .set(OverrideHealth(10))
+            .set(InheritHealth(20))
+            .set(DontInheritHealth(30));
+        let instance = world.entity().is_a(prefab);
+
+        // override: the instance owns a copy of the prefab value
+        assert!(instance.owns(OverrideHealth::id()));
+        instance.get::<&mut OverrideHealth>(|h| h.0 += 1);
+        instance.get::<&OverrideHealth>(|h| assert_eq!(h.0, 11));
+        prefab.get::<&OverrideHealth>(|h| assert_eq!(h.0, 10));
+
+        // inherit: the instance shares the prefab value
+        assert!(instance.has(InheritHealth::id()));
+        assert!(!instance.owns(InheritHealth::id()));
+        let shared = instance.get::<&InheritHealth>(|h| h as *const InheritHealth);
+        let original = prefab.get::<&InheritHealth>(|h| h as *const InheritHealth);
+        assert_eq!(shared, original);
+
+        // dont_inherit: the instance doesn't get the component
+        assert!(!instance.has(DontInheritHealth::id()));
+    }
+
+    #[derive(Component)]
+    #[flecs(inherit, traits(Sparse))]
+    struct SparseInheritHealth(i32);
+
+    #[test]
+    fn on_instantiate_attribute_sparse() {
+        let world = World::new();
+
+        let prefab = world.prefab().set(SparseInheritHealth(20));
+        let instance = world.entity().is_a(prefab);
+
+        assert!(!instance.owns(SparseInheritHealth::id()));
+        instance.get::<&SparseInheritHealth>(|h| assert_eq!(h.0, 20));
+
+        let mut found = 0;
+        world
+            .query::<&SparseInheritHealth>()
+            .build()
+            .each_entity(|e, h| {
+                if e == instance {
+                    found = h.0;
+                }
+            });
+        assert_eq!(found, 20);
+    }
 }
 mod name_attribute {
     use super::*;
@@ -448,3 +633,60 @@ mod multi_item_and_trailing_comma_attributes {
         c.get::<&CTrail>(|v| assert_eq!(v.value, 7));
     }
 }
+
+mod custom_default_attribute {
+    use super::*;
+
+    static NEXT_TICKET: core::sync::atomic::AtomicU32 = core::sync::atomic::AtomicU32::new(1);
+
+    #[derive(Component, Debug, PartialEq)]
+    #[flecs(default = Ticket::next())]
+    struct Ticket {
+        id: u32,
+    }
+
+    impl Ticket {
+        fn next() -> Self {
+            Ticket {
+                id: NEXT_TICKET.fetch_add(1, core::sync::atomic::Ordering::Relaxed),
+            }
+        }
+    }
+
+    #[derive(Component)]
+    #[flecs(default = Scaled { value: T::from(10u8) })]
+    struct Scaled<T: From<u8> + Send + Sync + 'static> {
+        value: T,
+    }
+
+    #[test]
+    fn der_attr_custom_default() {
+        let world = World::new();
+
+        let first = world.entity().add(Ticket::id());
+        let second = world.entity().add(Ticket::id());
+
+        let first_id = first.get::<&Ticket>(|t| t.id);
+        let second_id = second.get::<&Ticket>(|t| t.id);
+        assert_ne!(first_id, 0);
+        assert_eq!(second_id, first_id + 1);
+
+        // set values are not replaced by the custom default
+        let e = world.entity().set(Ticket { id: 0 });
+        e.get::<&Ticket>(|t| assert_eq!(t.id, 0));
+    }
+
+    #[test]
+    fn der_attr_custom_default_generic() {
+        let world = World::new();
+
+        let e = world
+            .entity()
+            .add(Scaled::<u64>::id())
+            .add(Scaled::<i32>::id());
+        e.get::<(&Scaled<u64>, &Scaled<i32>)>(|(a, b)| {
+            assert_eq!(a.value, 10);
+            assert_eq!(b.value, 10);
+        });
+    }
+}
@@ -51,6 +51,294 @@ mod misc_tests {
         );
         assert_eq!(b.archetype().to_string(), first_archetype);
     }
+
+    #[test]
+    fn add_if_toggles_tag_and_pair() {
+        let world = World::new();
+
+        let e = world.entity();
+
+        for active in [true, false, true, false] {
+            e.add_if(Tag, active);
+            e.add_if((Rel::id(), Obj::id()), active);
+            assert_eq!(e.has(Tag), active);
+            assert_eq!(e.has((Rel::id(), Obj::id())), active);
+        }
+    }
+
+    #[test]
+    fn add_if_deferred_reflects_condition_after_merge() {
+        let world = World::new();
+
+        let e = world.entity().add(Tag);
+
+        world.defer_begin();
+        e.add_if(Tag, false);
+        assert!(e.has(Tag));
+        world.defer_end();
+
+        assert!(!e.has(Tag));
+    }
+
+    #[test]
+    fn delete_children_keeps_root() {
+        let world = World::new();
+
+        let root = world.entity().set(Position { x: 1, y: 2 });
+        let a = world.entity().child_of(root);
+        let b = world.entity().child_of(root);
+        let a1 = world.entity().child_of(a);
+        let a2 = world.entity().child_of(a);
+        let b1 = world.entity().child_of(b);
+        let a1x = world.entity().child_of(a1);
+        let other = world.entity().child_of(world.entity());
+
+        root.delete_children();
+
+        assert!(root.is_alive());
+        assert!(root.has(Position::id()));
+        for e in [a, b, a1, a2, b1, a1x] {
+            assert!(!e.is_alive());
+        }
+        assert!(other.is_alive());
+
+        let mut count = 0;
+        root.each_child(|_| count += 1);
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn delete_children_deferred() {
+        let world = World::new();
+
+        let root = world.entity();
+        let child = world.entity().child_of(root);
+        let grandchild = world.entity().child_of(child);
+
+        world.defer_begin();
+        root.delete_children();
+        assert!(child.is_alive());
+        world.defer_end();
+
+        assert!(root.is_alive());
+        assert!(!child.is_alive());
+        assert!(!grandchild.is_alive());
+    }
+
+    #[test]
+    fn lookup_relative_to_entity() {
+        let world = World::new();
+
+        let ship = world.entity_named("Ship");
+        let engine = world.entity_named("Engine").child_of(ship);
+        let cockpit = world.entity_named("Cockpit").child_of(ship);
+        let pilot = world.entity_named("Pilot").child_of(cockpit);
+
+        // direct child and relative path
+        assert_eq!(ship.try_lookup("Cockpit"), Some(cockpit));
+        assert_eq!(ship.try_lookup("Cockpit::Pilot"), Some(pilot));
+        assert!(ship.try_lookup("Pilot").is_none());
+
+        // siblings are only found when walking up the hierarchy
+        assert!(engine.try_lookup("Cockpit").is_none());
+        assert_eq!(engine.try_lookup_recursive("Cockpit"), Some(cockpit));
+        assert_eq!(engine.try_lookup_recursive("Cockpit::Pilot"), Some(pilot));
+        assert!(engine.try_lookup_recursive("Missing").is_none());
+    }
+
+    #[test]
+    fn lookup_sibling_from_query() {
+        let world = World::new();
+
+        let ship = world.entity_named("Ship");
+        let engine = world.entity_named("Engine").child_of(ship).add(Tag);
+        let cockpit = world.entity_named("Cockpit").child_of(ship);
+
+        let mut found = Vec::new();
+        world.query::<()>().with(Tag).build().each_entity(|e, _| {
+            found.push((e.id(), e.try_lookup_recursive("Cockpit").map(|c| c.id())));
+        });
+
+        assert_eq!(found, vec![(engine.id(), Some(cockpit.id()))]);
+    }
+
+    #[test]
+    fn id_view_display_component() {
+        let world = World::new();
+
+        let id = world.id_view_from(Position::id());
+
+        assert_eq!(id.to_string(), "Position");
+        // `Id` has no world to resolve names with
+        assert_eq!(id.id().to_string(), (*id).to_string());
+    }
+
+    #[test]
+    fn id_view_display_pair() {
+        let world = World::new();
+
+        let likes = world.entity_named("Likes");
+        let apples = world.entity_named("Apples");
+        let id = world.id_view_from((likes, apples));
+
+        assert_eq!(id.to_string(), "(Likes,Apples)");
+        assert_eq!(format!("{id}"), id.to_str());
+    }
+
+    #[test]
+    fn id_view_display_pair_wildcard() {
+        let world = World::new();
+
+        let likes = world.entity_named("Likes");
+
+        assert_eq!(
+            world.id_view_from((likes, flecs::Wildcard::ID)).to_string(),
+            "(Likes,*)"
+        );
+        assert_eq!(
+            world.id_view_from((flecs::Wildcard::ID, likes)).to_string(),
+            "(*,Likes)"
+        );
+    }
+
+    #[test]
+    fn add_by_name_component_tag_and_pair() {
+        let world = World::new();
+        world.component::<Position>();
+        world.component::<Tag>();
+
+        let parent = world.entity_named("parent");
+        let e = world
+            .entity()
+            .add_by_name("Position")
+            .unwrap()
+            .add_by_name("Tag")
+            .unwrap()
+            .add_by_name("(ChildOf, parent)")
+            .unwrap();
+
+        assert!(e.has(Position::id()));
+        assert!(e.has(Tag));
+        assert!(e.has((flecs::ChildOf::ID, parent)));
+        assert_eq!(e.parent().unwrap(), parent);
+    }
+
+    #[test]
+    fn add_by_name_unknown_errors() {
+        let world = World::new();
+
+        let e = world.entity();
+        let err = e.add_by_name("DoesNotExist").unwrap_err();
+        assert_eq!(err.expr(), "DoesNotExist");
+        assert_eq!(
+            err.to_string(),
+            "`DoesNotExist` does not resolve to a valid id"
+        );
+
+        assert!(e.add_by_name("(ChildOf, missing_parent)").is_err());
+        assert!(e.add_by_name("(").is_err());
+        assert!(e.archetype().count() == 0);
+    }
+
+    #[test]
+    fn parent_of_child_and_root() {
+        let world = World::new();
+
+        let root = world.entity();
+        let child = world.entity().child_of(root);
+        let grandchild = world.entity().child_of(child);
+
+        assert_eq!(child.parent(), Some(root));
+        assert_eq!(grandchild.parent(), Some(child));
+        assert!(child.has_parent());
+
+        assert_eq!(root.parent(), None);
+        assert!(!root.has_parent());
+
+        child.remove((flecs::ChildOf::ID, root));
+        assert!(!child.has_parent());
+    }
+
+    #[test]
+    fn remove_relationship_wildcard_clears_all_targets() {
+        let world = World::new();
+
+        let apple = world.entity();
+        let pear = world.entity();
+        let bob = world.entity();
+
+        let e = world
+            .entity()
+            .add((Likes::id(), apple))
+            .add((Likes::id(), pear))
+            .add((Likes::id(), bob))
+            .add(Tag::id());
+        assert_eq!(e.target_count::<Likes>(), Some(3));
+
+        e.remove((Likes::id(), flecs::Wildcard::ID));
+
+        assert_eq!(e.target_count::<Likes>(), None);
+        assert!(!e.has((Likes::id(), flecs::Wildcard::ID)));
+        assert!(e.has(Tag::id()));
+    }
+
+    #[test]
+    fn get_untyped_bytes_match_typed_value() {
+        let world = World::new();
+
+        let e = world.entity().set(Position { x: 10, y: 20 });
+        let id = world.component::<Position>().id();
+
+        let size = world.type_info(id).unwrap().size;
+        assert_eq!(size, core::mem::size_of::<Position>());
+
+        let ptr = e.get_untyped(id);
+        assert!(!ptr.is_null());
+        let bytes = unsafe { core::slice::from_raw_parts(ptr as *const u8, size) };
+
+        let expected = Position { x: 10, y: 20 };
+        let expected =
+            unsafe { core::slice::from_raw_parts(&expected as *const Position as *const u8, size) };
+        assert_eq!(bytes, expected);
+
+        let ptr = e.get_untyped_mut(id);
+        unsafe { (*(ptr as *mut Position)).x = 30 };
+        e.get::<&Position>(|p| assert_eq!(p.x, 30));
+
+        assert!(world.entity().get_untyped(id).is_null());
+    }
+
+    #[test]
+    fn toggle_component_skipped_by_query() {
+        let world = World::new();
+
+        world
+            .component::<Position>()
+            .add_trait::<flecs::CanToggle>();
+
+        let e1 = world.entity().set(Position { x: 1, y: 2 });
+        let e2 = world.entity().set(Position { x: 3, y: 4 });
+
+        e1.toggle(Position::id(), false);
+        assert!(e1.has(Position::id()));
+        assert!(!e1.is_enabled(Position::id()));
+        assert!(e2.is_enabled(Position::id()));
+
+        let query = world.query::<&Position>().build();
+        let mut matched = Vec::new();
+        query.each_entity(|e, _| matched.push(e.id()));
+        assert_eq!(matched, [e2.id()]);
+
+        // an optional term ignores the toggle state
+        let optional = world.query::<Option<&Position>>().build();
+        let mut seen = Vec::new();
+        optional.each_entity(|e, p| seen.push((e.id(), p.is_some())));
+        assert!(seen.contains(&(e1.id(), true)));
+        assert!(seen.contains(&(e2.id(), true)));
+
+        e1.toggle(Position::id(), true);
+        assert_eq!(query.count(), 2);
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -91,6 +379,316 @@ fn component_types(#[case] ty: ComponentType) {}
 )]
 fn component_types_no_dont_fragment(#[case] ty: ComponentType) {}
 
+mod insert_tests {
+    use super::*;
+
+    #[test]
+    fn insert_moves_to_final_table_once() {
+        let world = World::new();
+
+        world.set(Count(0));
+
+        // with chained sets Velocity and Tag would not be present yet when Position is added
+        world
+            .observer::<flecs::OnAdd, ()>()
+            .with(Position::id())
+            .each_entity(|e, _| {
+                assert!(e.has(Velocity::id()));
+                assert!(e.has(Tag));
+            });
+
+        world
+            .observer::<flecs::OnSet, &Position>()
+            .each_entity(|e, p| {
+                assert_eq!((p.x, p.y), (1, 2));
+                e.get::<&Velocity>(|v| assert_eq!((v.x, v.y), (3, 4)));
+                e.world().get::<&mut Count>(|c| c.0 += 1);
+            });
+
+        let e = world.entity().insert(|b| {
+            b.set(Position { x: 1, y: 2 })
+                .set(Velocity { x: 3, y: 4 })
+                .add(Tag);
+        });
+
+        world.get::<&Count>(|c| assert_eq!(c.0, 1));
+        assert!(e.has(Tag));
+        e.get::<(&Position, &Velocity)>(|(p, v)| {
+            assert_eq!((p.x, p.y), (1, 2));
+            assert_eq!((v.x, v.y), (3, 4));
+        });
+    }
+
+    #[test]
+    fn insert_sets_existing_component_in_place() {
+        let world = World::new();
+
+        let e = world.entity().set(Position { x: 1, y: 2 });
+
+        e.insert(|b| {
+            b.set(Position { x: 10, y: 20 })
+                .set(Velocity { x: 3, y: 4 });
+        });
+
+        e.get::<(&Position, &Velocity)>(|(p, v)| {
+            assert_eq!((p.x, p.y), (10, 20));
+            assert_eq!((v.x, v.y), (3, 4));
+        });
+    }
+
+    #[test]
+    fn insert_drop_components() {
+        #[derive(Component)]
+        struct Name(String);
+
+        #[derive(Component, Default)]
+        struct Names(Vec<String>);
+
+        let world = World::new();
+
+        let e = world.entity().insert(|b| {
+            b.set(Name("first".to_string()))
+                .set(Name("second".to_string()))
+                .set(Names(vec!["a".to_string()]));
+        });
+
+        e.get::<(&Name, &Names)>(|(name, names)| {
+            assert_eq!(name.0, "second");
+            assert_eq!(names.0, ["a"]);
+        });
+    }
+
+    #[test]
+    fn insert_while_deferred() {
+        let world = World::new();
+
+        world.defer_begin();
+        let e = world.entity().insert(|b| {
+            b.set(Position { x: 1, y: 2 }).add(Tag);
+        });
+        assert!(!e.has(Tag));
+        world.defer_end();
+
+        assert!(e.has(Tag));
+        e.get::<&Position>(|p| assert_eq!((p.x, p.y), (1, 2)));
+    }
+
+    #[test]
+    fn insert_replaces_exclusive_pair() {
+        let world = World::new();
+        world.set(Count(0));
+
+        let parent_a = world.entity();
+        let parent_b = world.entity();
+        let e = world.entity().child_of(parent_a);
+        let e_id = e.id();
+
+        world
+            .observer::<flecs::OnRemove, ()>()
+            .with((flecs::ChildOf::ID, parent_a))
+            .each_entity(move |observed, _| {
+                assert_eq!(observed.id(), e_id);
+                observed.world().get::<&mut Count>(|c| c.0 += 1);
+            });
+
+        e.insert(|b| {
+            b.set(Position { x: 1, y: 2 })
+                .add((flecs::ChildOf::ID, parent_b));
+        });
+
+        world.get::<&Count>(|c| assert_eq!(c.0, 1));
+        assert_eq!(e.parent(), Some(parent_b));
+        assert!(!e.has((flecs::ChildOf::ID, parent_a)));
+        assert!(e.has(Position::id()));
+    }
+
+    #[apply(component_types)]
+    fn insert_storage_types(ty: ComponentType) {
+        let world = World::new();
+        set_component_type::<Value>(&world, ty);
+        set_component_type::<Value2>(&world, ty);
+
+        let e = world.entity().insert(|b| {
+            b.set(Value { value: 42 })
+                .set(Value2 { value: 84 })
+                .add(Tag);
+        });
+
+        assert!(e.has(Tag));
+        e.get::<(&Value, &Value2)>(|(v, v2)| {
+            assert_eq!(v.value, 42);
+            assert_eq!(v2.value, 84);
+        });
+    }
+
+    #[derive(Component)]
+    struct Tracked(alloc::sync::Arc<core::sync::atomic::AtomicU32>, u32);
+
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, core::sync::atomic::Ordering::Relaxed);
+        }
+    }
+
+    #[test]
+    fn insert_drops_replaced_and_unwritten_values() {
+        use core::sync::atomic::{AtomicU32, Ordering};
+
+        let world = World::new();
+        let drops = alloc::sync::Arc::new(AtomicU32::new(0));
+
+        // setting a component twice drops the first value
+        let e = world.entity().insert(|b| {
+            b.set(Tracked(drops.clone(), 1))
+                .set(Tracked(drops.clone(), 2))
+                .add(Tag);
+        });
+        assert_eq!(drops.load(Ordering::Relaxed), 1);
+        e.get::<&Tracked>(|t| assert_eq!(t.1, 2));
+
+        // values still pending when the builder unwinds are dropped
+        let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+            world.entity().insert(|b| {
+                b.set(Tracked(drops.clone(), 3));
+                panic!("insert callback");
+            });
+        }));
+        assert!(result.is_err());
+        assert_eq!(drops.load(Ordering::Relaxed), 2);
+
+        e.destruct();
+        assert_eq!(drops.load(Ordering::Relaxed), 3);
+    }
+}
+
+mod get_mut_or_tests {
+    use super::*;
+
+    #[derive(Component, Debug, PartialEq)]
+    struct Label {
+        value: String,
+    }
+
+    #[test]
+    fn get_mut_or_default_absent() {
+        let world = World::new();
+
+        let e = world.entity();
+        assert!(!e.has(Position::id()));
+
+        let value = e.get_mut_or_default::<Position, _>(|p| {
+            assert_eq!((p.x, p.y), (0, 0));
+            p.x = 5;
+            p.x
+        });
+
+        assert_eq!(value, 5);
+        assert!(e.has(Position::id()));
+        e.get::<&Position>(|p| assert_eq!((p.x, p.y), (5, 0)));
+    }
+
+    #[test]
+    fn get_mut_or_default_present() {
+        let world = World::new();
+
+        let e = world.entity().set(Position { x: 1, y: 2 });
+        let table = e.table();
+
+        e.get_mut_or_default::<Position, _>(|p| {
+            assert_eq!((p.x, p.y), (1, 2));
+            p.y = 3;
+        });
+
+        assert_eq!(e.table(), table);
+        e.get::<&Position>(|p| assert_eq!((p.x, p.y), (1, 3)));
+    }
+
+    #[test]
+    fn get_mut_or_default_deferred() {
+        let world = World::new();
+
+        let e = world.entity();
+
+        world.defer_begin();
+        e.get_mut_or_default::<Position, _>(|p| p.x = 10);
+        assert!(!e.has(Position::id()));
+        world.defer_end();
+
+        e.get::<&Position>(|p| assert_eq!((p.x, p.y), (10, 0)));
+    }
+
+    #[test]
+    fn get_mut_or_insert_with_absent() {
+        let world = World::new();
+
+        let e = world.entity();
+
+        e.get_mut_or_insert_with::<Label, _>(
+            || Label {
+                value: "hello".to_string(),
+            },
+            |l| {
+                assert_eq!(l.value, "hello");
+                l.value.push_str(" world");
+            },
+        );
+
+        e.get::<&Label>(|l| assert_eq!(l.value, "hello world"));
+    }
+
+    #[test]
+    fn get_mut_or_insert_with_present() {
+        let world = World::new();
+
+        let e = world.entity().set(Label {
+            value: "hello".to_string(),
+        });
+
+        let mut init_called = false;
+        e.get_mut_or_insert_with::<Label, _>(
+            || {
+                init_called = true;
+                Label {
+                    value: "other".to_string(),
+                }
+            },
+            |l| l.value.push('!'),
+        );
+
+        assert!(!init_called);
+        e.get::<&Label>(|l| assert_eq!(l.value, "hello!"));
+    }
+
+    #[test]
+    fn get_mut_or_insert_with_default_type() {
+        let world = World::new();
+
+        let e = world.entity();
+
+        e.get_mut_or_insert_with::<Position, _>(|| Position { x: 7, y: 8 }, |p| p.x += 1);
+
+        e.get::<&Position>(|p| assert_eq!((p.x, p.y), (8, 8)));
+    }
+
+    #[test]
+    fn get_mut_or_insert_with_deferred() {
+        let world = World::new();
+
+        let e = world.entity();
+
+        world.defer_begin();
+        e.get_mut_or_insert_with::<Label, _>(
+            || Label {
+                value: "hello".to_string(),
+            },
+            |l| l.value.push('!'),
+        );
+        world.defer_end();
+
+        e.get::<&Label>(|l| assert_eq!(l.value, "hello!"));
+    }
+}
+
 mod cloned_tests {
     use super::*;
 
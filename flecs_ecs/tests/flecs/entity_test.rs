@@ -3242,6 +3242,113 @@ fn clone_to_existing() {
     });
 }
 
+#[test]
+fn targets_in_type_order() {
+    let world = World::new();
+
+    let apples = world.entity();
+    let pears = world.entity();
+    let bananas = world.entity();
+
+    // added out of id order, returned in type (id) order
+    let e = world
+        .entity()
+        .add((Likes::id(), bananas))
+        .add((Likes::id(), apples))
+        .add((Likes::id(), pears))
+        .add(Position::id());
+
+    assert_eq!(e.targets(Likes::id()), [apples, pears, bananas]);
+    assert_eq!(e.target_count::<Likes>(), Some(3));
+
+    e.remove((Likes::id(), pears));
+    assert_eq!(e.targets(Likes::id()), [apples, bananas]);
+    assert!(e.targets(Apples::id()).is_empty());
+}
+
+#[test]
+fn clone_into_existing_union() {
+    let world = World::new();
+
+    let src = world
+        .entity()
+        .add(Tag)
+        .set(Position { x: 10, y: 20 })
+        .set(Velocity { x: 3, y: 4 })
+        .add((Likes::id(), Apples::id()));
+    let dst = world
+        .entity()
+        .set(Position { x: 1, y: 2 })
+        .set(Mass { value: 50 });
+
+    let result = src.clone_into(dst, true);
+    assert_eq!(result, dst);
+
+    // dst gains src's components, overwriting the ones it already had
+    assert!(dst.has(Tag));
+    assert!(dst.has((Likes::id(), Apples::id())));
+    dst.get::<(&Position, &Velocity, &Mass)>(|(pos, vel, mass)| {
+        assert_eq!((pos.x, pos.y), (10, 20));
+        assert_eq!((vel.x, vel.y), (3, 4));
+        assert_eq!(mass.value, 50);
+    });
+
+    // src is unchanged
+    assert!(!src.has(Mass::id()));
+    src.get::<&Position>(|pos| assert_eq!((pos.x, pos.y), (10, 20)));
+}
+
+#[test]
+fn clone_into_existing_no_copy_value() {
+    let world = World::new();
+
+    let src = world
+        .entity_named("template")
+        .set(Position { x: 10, y: 20 })
+        .set(Mass { value: 50 });
+    let dst = world.entity().set(Position { x: 1, y: 2 });
+
+    src.clone_into(dst, false);
+
+    // existing values are kept, the name is not copied
+    assert!(dst.has(Mass::id()));
+    assert_eq!(dst.get_name(), None);
+    dst.get::<&Position>(|pos| assert_eq!((pos.x, pos.y), (1, 2)));
+}
+
+#[test]
+fn clone_into_no_default_heap_component() {
+    #[derive(Component, Clone)]
+    struct Label(String);
+
+    let world = World::new();
+
+    let src = world
+        .entity()
+        .set(Position { x: 10, y: 20 })
+        .set(Label("template".to_string()));
+    let dst = world.entity();
+
+    // Label can't be default constructed, so nothing is added to dst
+    let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+        src.clone_into(dst, false);
+    }));
+    assert!(result.is_err());
+    assert!(!dst.has(Position::id()));
+    assert!(!dst.has(Label::id()));
+
+    // an existing value is kept and doesn't need a default
+    dst.set(Label("own".to_string()));
+    src.clone_into(dst, false);
+    assert!(dst.has(Position::id()));
+    dst.get::<&Label>(|label| assert_eq!(label.0, "own"));
+
+    // copying the value uses Clone instead
+    let copy = world.entity();
+    src.clone_into(copy, true);
+    copy.get::<&Label>(|label| assert_eq!(label.0, "template"));
+}
+
 #[test]
 #[should_panic]
 #[ignore = "Panic test: panics in C, which isn't captured by rust"]
@@ -7702,3 +7809,39 @@ fn world_lookup_custom_sep() {
 fn world_lookup_custom_root_sep() {
     // TODO: missing API: world.lookup with custom root separator
 }
+
+#[test]
+fn entity_each_component_full_type() {
+    let world = World::new();
+
+    let apples = world.entity();
+    let e = world
+        .entity()
+        .set(Position { x: 1, y: 2 })
+        .set(Velocity { x: 3, y: 4 })
+        .add(TagA::id())
+        .add((Likes::id(), apples));
+
+    assert_eq!(e.type_count(), 4);
+
+    let mut ids = Vec::new();
+    e.each_id(|id| ids.push(id.id()));
+
+    let mut component_ids = Vec::new();
+    e.each_component(|id| component_ids.push(id.id()));
+    assert_eq!(ids, component_ids);
+
+    let mut expected = vec![
+        world.id_from(Position::id()),
+        world.id_from(Velocity::id()),
+        world.id_from(TagA::id()),
+        world.id_from((Likes::id(), apples)),
+    ];
+    ids.sort();
+    expected.sort();
+    assert_eq!(ids, expected);
+
+    let empty = world.entity();
+    assert_eq!(empty.type_count(), 0);
+    empty.each_id(|_| panic!("entity has no ids"));
+}
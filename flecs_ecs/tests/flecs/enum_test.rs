@@ -1212,3 +1212,99 @@ fn query_singleton_enum_constant_or() {
     });
     assert_eq!(count, 2);
 }
+
+#[test]
+fn enum_query_with_enum() {
+    let world = World::new();
+
+    let red = world.entity().add_enum(StandardEnum::Red);
+    world.entity().add_enum(StandardEnum::Green);
+    world.entity().add_enum(StandardEnum::Blue);
+    let red2 = world.entity().add_enum(StandardEnum::Red);
+
+    let mut matched = Vec::new();
+    world
+        .query::<()>()
+        .with_enum(StandardEnum::Red)
+        .build()
+        .each_entity(|e, _| matched.push(e.id()));
+
+    matched.sort();
+    assert_eq!(matched, vec![red.id(), red2.id()]);
+}
+
+#[test]
+fn enum_query_with_enum_dsl() {
+    let world = World::new();
+
+    let red = world.entity().add_enum(StandardEnum::Red);
+    world.entity().add_enum(StandardEnum::Green);
+    world.entity().add_enum(StandardEnum::Blue);
+    let red2 = world.entity().add_enum(StandardEnum::Red);
+
+    let mut matched = Vec::new();
+    query!(&world, variant StandardEnum::Red)
+        .build()
+        .each_entity(|e, _| matched.push(e.id()));
+
+    matched.sort();
+    assert_eq!(matched, vec![red.id(), red2.id()]);
+
+    let mut count = 0;
+    query!(&world, (StandardEnum, *), !variant StandardEnum::Red)
+        .build()
+        .each(|_| count += 1);
+
+    assert_eq!(count, 2);
+}
+
+#[derive(Component)]
+#[flecs(traits(Exclusive))]
+struct MovementState;
+
+#[test]
+fn enum_pair_target_transitions() {
+    let world = World::new();
+
+    let e = world.entity();
+    assert_eq!(e.get_enum_target::<MovementState, StandardEnum>(), None);
+
+    e.add_pair_enum::<MovementState, _>(StandardEnum::Red);
+    assert!(e.has_pair_enum::<MovementState, _>(StandardEnum::Red));
+    assert_eq!(
+        e.get_enum_target::<MovementState, StandardEnum>(),
+        Some(StandardEnum::Red)
+    );
+
+    e.add_pair_enum::<MovementState, _>(StandardEnum::Blue);
+    assert!(!e.has_pair_enum::<MovementState, _>(StandardEnum::Red));
+    assert_eq!(
+        e.get_enum_target::<MovementState, StandardEnum>(),
+        Some(StandardEnum::Blue)
+    );
+
+    e.remove((MovementState::id(), flecs::Wildcard::ID));
+    assert_eq!(e.get_enum_target::<MovementState, StandardEnum>(), None);
+}
+
+#[test]
+fn enum_pair_target_skips_other_targets() {
+    let world = World::new();
+
+    let e = world.entity();
+
+    // a target that is not a constant of the enum is not converted
+    e.add((Likes::id(), world.entity()));
+    assert_eq!(e.get_enum_target::<Likes, StandardEnum>(), None);
+
+    e.add_pair_enum::<Likes, _>(AnotherEnum::Walking);
+    e.add_pair_enum::<Likes, _>(StandardEnum::Blue);
+    assert_eq!(
+        e.get_enum_target::<Likes, StandardEnum>(),
+        Some(StandardEnum::Blue)
+    );
+    assert!(matches!(
+        e.get_enum_target::<Likes, AnotherEnum>(),
+        Some(AnotherEnum::Walking)
+    ));
+}
@@ -545,6 +545,50 @@ fn event_entity_emit_event_w_payload() {
     e.emit(&Position { x: 10, y: 20 });
 }
 
+#[test]
+fn event_entity_emit_with_payload() {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Component)]
+    struct Damage {
+        amount: i32,
+    }
+
+    impl Drop for Damage {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let world = World::new();
+
+    let e = world.entity();
+    let e_id = e.id();
+
+    let count = alloc::rc::Rc::new(core::cell::Cell::new(0i32));
+    let count_c = count.clone();
+
+    world
+        .observer::<Damage, ()>()
+        .with(flecs::Any)
+        .run(move |mut it| {
+            while it.next() {
+                assert_eq!(it.entity(0usize).id(), e_id);
+                assert_eq!(it.param().amount, 25);
+                // payload is still alive while observers run
+                assert_eq!(DROPS.load(Ordering::SeqCst), 0);
+                count_c.set(count_c.get() + 1);
+            }
+        });
+
+    e.emit_with_payload(Damage { amount: 25 });
+
+    assert_eq!(count.get(), 1);
+    assert_eq!(DROPS.load(Ordering::SeqCst), 1);
+}
+
 #[test]
 fn event_entity_emit_event_id_no_src() {
     let world = World::new();
@@ -664,6 +708,65 @@ fn event_entity_observe_generic() {
     e.observe_entity::<Evt>(|_src: &mut EntityView| {});
 }
 
+#[test]
+fn event_entity_observe_only_target() {
+    let world = World::new();
+
+    let e1 = world.entity();
+    let e2 = world.entity();
+
+    let count_1 = alloc::rc::Rc::new(core::cell::Cell::new(0i32));
+    let count_2 = alloc::rc::Rc::new(core::cell::Cell::new(0i32));
+    let count_1_c = count_1.clone();
+    let count_2_c = count_2.clone();
+
+    e1.observe::<Evt>(move || {
+        count_1_c.set(count_1_c.get() + 1);
+    });
+    e2.observe::<Evt>(move || {
+        count_2_c.set(count_2_c.get() + 1);
+    });
+
+    e1.emit(&Evt);
+
+    assert_eq!(count_1.get(), 1);
+    assert_eq!(count_2.get(), 0);
+
+    e2.emit(&Evt);
+
+    assert_eq!(count_1.get(), 1);
+    assert_eq!(count_2.get(), 1);
+}
+
+#[test]
+fn event_entity_observe_payload_entity_only_target() {
+    let world = World::new();
+
+    let e1 = world.entity();
+    let e2 = world.entity();
+    let e1_id = e1.id();
+
+    let count_1 = alloc::rc::Rc::new(core::cell::Cell::new(0i32));
+    let count_2 = alloc::rc::Rc::new(core::cell::Cell::new(0i32));
+    let count_1_c = count_1.clone();
+    let count_2_c = count_2.clone();
+
+    e1.observe_payload_entity::<Position>(move |src, p| {
+        assert_eq!(src.id(), e1_id);
+        assert_eq!(p.x, 10);
+        assert_eq!(p.y, 20);
+        count_1_c.set(count_1_c.get() + 1);
+    });
+    e2.observe_payload::<Position>(move |_p| {
+        count_2_c.set(count_2_c.get() + 1);
+    });
+
+    e1.emit(&Position { x: 10, y: 20 });
+
+    assert_eq!(count_1.get(), 1);
+    assert_eq!(count_2.get(), 0);
+}
+
 #[test]
 fn event_enqueue_event() {
     let world = World::new();
@@ -72,6 +72,67 @@ fn field_untyped_at_out_of_bounds_panics() {
     });
 }
 
+#[test]
+fn field_untyped_exposes_layout() {
+    let world = World::new();
+
+    let src = world.entity().set(Velocity { x: 5, y: 6 });
+    world.entity().set(Position { x: 1, y: 2 });
+    world.entity().set(Position { x: 3, y: 4 });
+
+    let query = world
+        .query::<(&Position, &Velocity)>()
+        .term_at(1)
+        .set_src(src)
+        .build();
+
+    let mut xs = Vec::new();
+    query.run(|mut it| {
+        while it.next() {
+            let pos = it.field_untyped(0);
+            assert_eq!(pos.size(), core::mem::size_of::<Position>());
+            assert_eq!(pos.size(), it.size(0));
+            assert_eq!(pos.len(), it.count());
+            assert!(!pos.is_shared());
+
+            for i in 0..pos.len() {
+                let ptr = unsafe { pos.as_ptr().add(i * pos.size()) };
+                let p = unsafe { &*(ptr as *const Position) };
+                xs.push(p.x);
+            }
+
+            let vel = it.field_untyped(1);
+            assert!(vel.is_shared());
+            assert_eq!(vel.len(), 1);
+            assert_eq!(unsafe { (*(vel.as_ptr() as *const Velocity)).y }, 6);
+        }
+    });
+
+    xs.sort();
+    assert_eq!(xs, [1, 3]);
+}
+
+#[test]
+#[should_panic(expected = "Column is shared, cannot access mutably")]
+fn field_untyped_mut_shared_as_mut_ptr_panics() {
+    let world = World::new();
+
+    let src = world.entity().set(Velocity { x: 5, y: 6 });
+    world.entity().set(Position { x: 1, y: 2 });
+
+    let query = world
+        .query::<(&Position, &mut Velocity)>()
+        .term_at(1)
+        .set_src(src)
+        .build();
+
+    query.run(|mut it| {
+        while it.next() {
+            core::hint::black_box(it.field_untyped_mut(1).as_mut_ptr());
+        }
+    });
+}
+
 #[test]
 fn try_field_ok() {
     let world = World::new();
@@ -390,6 +390,72 @@ fn meta_custom_i32_to_json() {
     assert_eq!(json, "10");
 }
 
+#[test]
+fn meta_opaque_as_newtype_json_round_trip() {
+    let world = World::new();
+
+    #[derive(Component, Default)]
+    struct MyId(u64);
+
+    impl From<u64> for MyId {
+        fn from(value: u64) -> Self {
+            MyId(value)
+        }
+    }
+
+    world.component::<MyId>().opaque_as::<u64>(|id: &MyId| id.0);
+
+    let mut v = MyId(42);
+    let json = world.to_json::<MyId>(&v);
+    assert_eq!(json, "42");
+
+    world.from_json::<MyId>(&mut v, "1234", None);
+    assert_eq!(v.0, 1234);
+    assert_eq!(world.to_json::<MyId>(&v), "1234");
+
+    // negative values can't be assigned to an unsigned primitive, the value is unchanged
+    world.from_json::<MyId>(&mut v, "-1", None);
+    assert_eq!(v.0, 1234);
+}
+
+#[test]
+fn meta_opaque_as_signed_and_bool() {
+    let world = World::new();
+
+    #[derive(Component, Default)]
+    struct Offset(i32);
+
+    impl From<i32> for Offset {
+        fn from(value: i32) -> Self {
+            Offset(value)
+        }
+    }
+
+    #[derive(Component, Default)]
+    struct Flag(bool);
+
+    impl From<bool> for Flag {
+        fn from(value: bool) -> Self {
+            Flag(value)
+        }
+    }
+
+    world
+        .component::<Offset>()
+        .opaque_as::<i32>(|o: &Offset| o.0);
+    world.component::<Flag>().opaque_as::<bool>(|f: &Flag| f.0);
+
+    let mut offset = Offset(-5);
+    assert_eq!(world.to_json::<Offset>(&offset), "-5");
+    world.from_json::<Offset>(&mut offset, "-17", None);
+    assert_eq!(offset.0, -17);
+
+    let mut flag = Flag(true);
+    assert_eq!(world.to_json::<Flag>(&flag), "true");
+    world.from_json::<Flag>(&mut flag, "false", None);
+    assert!(!flag.0);
+}
+
 #[test]
 fn meta_ser_deser_std_string() {
     let world = World::new();
@@ -759,6 +825,45 @@ fn meta_error_range() {
     }
 }
 
+#[test]
+fn meta_member_ranges_component() {
+    let world = World::new();
+
+    #[derive(Component)]
+    struct CpuUtilization {
+        value: f64,
+        cores: i32,
+    }
+
+    let c = world
+        .component::<CpuUtilization>()
+        .member(f64::id(), "value")
+        .range(0.0, 100.0)
+        .warning_range(10.0, 90.0)
+        .member(i32::id(), "cores")
+        .create_member_entities()
+        // member entity already exists, so this writes MemberRanges directly
+        .error_range(1.0, 64.0);
+
+    let value = c.lookup("value");
+    value.get::<&flecs::meta::MemberRanges>(|ranges| {
+        assert_eq!(ranges.value.min, 0.0);
+        assert_eq!(ranges.value.max, 100.0);
+        assert_eq!(ranges.warning.min, 10.0);
+        assert_eq!(ranges.warning.max, 90.0);
+        assert_eq!(ranges.error.min, 0.0);
+        assert_eq!(ranges.error.max, 0.0);
+    });
+
+    let cores = c.lookup("cores");
+    cores.get::<&flecs::meta::MemberRanges>(|ranges| {
+        assert_eq!(ranges.value.min, 0.0);
+        assert_eq!(ranges.value.max, 0.0);
+        assert_eq!(ranges.error.min, 1.0);
+        assert_eq!(ranges.error.max, 64.0);
+    });
+}
+
 #[test]
 fn meta_struct_member_ptr() {
     let world = World::new();
@@ -937,6 +1042,80 @@ fn meta_ecs_bitmask_macro() {
     assert!(c.has(id::<flecs::meta::Bitmask>()));
 }
 
+#[derive(Debug, Default, Component)]
+#[repr(C)]
+#[flecs(bitmask(Read = EcsBitmaskDerivePermissions::READ, Write = 0x2, Execute = 0x4))]
+struct EcsBitmaskDerivePermissions {
+    bits: u32,
+}
+
+impl EcsBitmaskDerivePermissions {
+    const READ: u32 = 0x1;
+}
+
+#[derive(Debug, Default, Component)]
+#[repr(C)]
+#[flecs(bitmask)]
+struct EcsBitmaskDeriveBare {
+    bits: u32,
+}
+
+#[test]
+fn meta_ecs_bitmask_derive() {
+    let world = World::new();
+
+    let c = world.component::<EcsBitmaskDerivePermissions>();
+    assert!(c.has(id::<flecs::meta::Bitmask>()));
+
+    let read = c.lookup("Read");
+    read.get::<&(flecs::Constant, u32)>(|value| assert_eq!(*value, 0x1));
+
+    let v = EcsBitmaskDerivePermissions { bits: 0x1 | 0x4 };
+    let json = world.to_json::<EcsBitmaskDerivePermissions>(&v);
+    assert!(json.contains("Read"));
+    assert!(json.contains("Execute"));
+    assert!(!json.contains("Write"));
+}
+
+#[test]
+fn meta_ecs_bitmask_derive_bare() {
+    let world = World::new();
+
+    let c = world
+        .component::<EcsBitmaskDeriveBare>()
+        .bit("Read", 0x1u32)
+        .bit("Write", 0x2u32);
+    assert!(c.has(id::<flecs::meta::Bitmask>()));
+
+    let v = EcsBitmaskDeriveBare { bits: 0x3 };
+    assert_eq!(world.to_json::<EcsBitmaskDeriveBare>(&v), "\"Write|Read\"");
+}
+
+#[derive(Debug, Default, Component)]
+#[repr(transparent)]
+#[flecs(bitmask(Read = 0x1, Write = 0x2))]
+struct EcsBitmaskDeriveGeneric<T: 'static + Send + Sync> {
+    bits: u32,
+    _marker: core::marker::PhantomData<T>,
+}
+
+#[test]
+fn meta_ecs_bitmask_derive_generic() {
+    let world = World::new();
+
+    let c = world.component::<EcsBitmaskDeriveGeneric<f32>>();
+    assert!(c.has(id::<flecs::meta::Bitmask>()));
+
+    let v = EcsBitmaskDeriveGeneric::<f32> {
+        bits: 0x2,
+        _marker: core::marker::PhantomData,
+    };
+    assert_eq!(
+        world.to_json::<EcsBitmaskDeriveGeneric<f32>>(&v),
+        "\"Write\""
+    );
+}
+
 #[test]
 fn meta_ecs_struct_macro_no_reflection_for_plain_struct() {
     let world = World::new();
@@ -1200,6 +1379,53 @@ fn meta_entity_to_json_w_default_desc() {
     );
 }
 
+// ── entity_to_json_builder ──
+
+#[test]
+fn meta_entity_to_json_builder() {
+    let world = World::new();
+
+    world
+        .component::<JsonPos>()
+        .member(f32::id(), "x")
+        .member(f32::id(), "y");
+
+    let e = world.entity_named("foo").set(JsonPos { x: 10.0, y: 20.0 });
+
+    // builder defaults match to_json(None)
+    assert_eq!(e.to_json_builder().build(), e.to_json(None));
+
+    let with_values = e
+        .to_json_builder()
+        .serialize_full_paths(false)
+        .serialize_values(true)
+        .build();
+    let without_values = e
+        .to_json_builder()
+        .serialize_full_paths(false)
+        .serialize_values(false)
+        .build();
+
+    assert_eq!(
+        with_values,
+        r#"{"name":"foo", "components":{"JsonPos":{"x":10, "y":20}}}"#
+    );
+    assert_eq!(
+        without_values,
+        r#"{"name":"foo", "components":{"JsonPos":null}}"#
+    );
+
+    let with_id = e
+        .to_json_builder()
+        .serialize_full_paths(false)
+        .serialize_entity_id(true)
+        .build();
+    assert!(
+        with_id.contains(&format!("\"id\":{}", e.id())),
+        "unexpected JSON: {with_id}"
+    );
+}
+
 // ── iter_to_json ──
 
 #[test]
@@ -1253,6 +1479,50 @@ fn meta_query_to_json_w_default_desc() {
     assert_eq!(json.unwrap(), "{\"results\":[{\"name\":\"foo\"}]}");
 }
 
+// ── query_to_json_w_multiple_fields ──
+
+#[test]
+fn meta_query_to_json_w_multiple_fields() {
+    #[derive(Component)]
+    #[flecs(meta)]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Component)]
+    #[flecs(meta)]
+    struct Velocity {
+        x: f32,
+        y: f32,
+    }
+
+    let world = World::new();
+
+    world
+        .entity_named("foo")
+        .set(Position { x: 10.0, y: 20.0 })
+        .set(Velocity { x: 1.0, y: 2.0 });
+    world
+        .entity_named("bar")
+        .set(Position { x: 30.0, y: 40.0 })
+        .set(Velocity { x: 3.0, y: 4.0 });
+    world
+        .entity_named("no_velocity")
+        .set(Position { x: 0.0, y: 0.0 });
+
+    let q = world.new_query::<(&Position, &Velocity)>();
+    let json = q.to_json(None).unwrap();
+
+    assert_eq!(
+        json,
+        "{\"results\":[\
+         {\"name\":\"foo\", \"fields\":{\"values\":[{\"x\":10, \"y\":20}, {\"x\":1, \"y\":2}]}}, \
+         {\"name\":\"bar\", \"fields\":{\"values\":[{\"x\":30, \"y\":40}, {\"x\":3, \"y\":4}]}}\
+         ]}"
+    );
+}
+
 // ── set_type_json ──
 
 #[test]
@@ -38,6 +38,35 @@ fn test_pos() {
     });
 }
 
+#[derive(Component, Default, Debug, PartialEq)]
+#[flecs(meta)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[test]
+fn test_expr_round_trip() {
+    let world = World::new();
+
+    let p = Point { x: 10, y: 20 };
+    let expr = world.to_expr(&p);
+    assert_eq!(expr, "{x: 10, y: 20}");
+    assert_eq!(world.from_expr::<Point>(&expr), Some(p));
+
+    // members missing from the expression keep their default value
+    assert_eq!(
+        world.from_expr::<Point>("{y: -3}"),
+        Some(Point { x: 0, y: -3 })
+    );
+
+    assert_eq!(world.from_expr::<Point>("{z: 1}"), None);
+
+    // trailing input after the expression is rejected
+    assert_eq!(world.from_expr::<Point>("{x: 1, y: 2} garbage"), None);
+    assert_eq!(world.from_expr::<Point>("{x: 1, y: 2}}"), None);
+}
+
 #[test]
 fn test_pos_skip_y() {
     let world = World::new();
@@ -290,6 +290,31 @@ fn module_import() {
     assert!(e.has(Position::id()));
 }
 
+#[test]
+fn module_components_in_scope() {
+    let world = World::new();
+
+    let simple = world.import::<ns::SimpleModule>();
+    let nested = world.lookup("ns::NestedModule");
+
+    let mut simple_components = Vec::new();
+    world.components_in_scope(simple, |c| simple_components.push(c.id()));
+    assert_eq!(simple_components, [world.component_id::<Position>()]);
+
+    // components of the nested module are only listed for its own scope
+    let mut nested_components = Vec::new();
+    world.components_in_scope(nested, |c| nested_components.push(c.id()));
+    assert_eq!(nested_components, [world.component_id::<Velocity>()]);
+
+    // modules are components, and are listed in the scope they're created in
+    let mut ns_components = Vec::new();
+    world.components_in_scope(world.lookup("ns"), |c| ns_components.push(c.id()));
+    ns_components.sort();
+    let mut expected = vec![simple.id(), nested.id()];
+    expected.sort();
+    assert_eq!(ns_components, expected);
+}
+
 #[test]
 fn module_lookup_from_scope() {
     let world = World::new();
@@ -884,3 +909,91 @@ fn entity_scopes_do_not_become_modules() {
 
     assert!(current.parent().is_none());
 }
+
+#[derive(Component)]
+struct ScopedModule;
+
+impl Module for ScopedModule {
+    fn module(world: &World) {
+        world.module::<ScopedModule>("scoped::ScopedModule");
+    }
+}
+
+#[derive(Component)]
+#[flecs(scope = ScopedModule)]
+struct ScopedComponent {
+    value: i32,
+}
+
+#[test]
+fn component_derive_scope() {
+    let world = World::new();
+
+    world.import::<ScopedModule>();
+
+    let c = world.component::<ScopedComponent>();
+    let module = world.component::<ScopedModule>();
+
+    assert_eq!(c.parent().unwrap().id(), module.id());
+    assert_eq!(c.path().unwrap(), "::scoped::ScopedModule::ScopedComponent");
+}
+
+#[test]
+fn component_derive_scope_before_import() {
+    let world = World::new();
+
+    let c = world.component::<ScopedComponent>();
+    world.import::<ScopedModule>();
+
+    let module = world.component::<ScopedModule>();
+    assert!(module.has(flecs::Module::ID));
+    assert_eq!(c.parent().unwrap().id(), module.id());
+    assert_eq!(c.path().unwrap(), "::scoped::ScopedModule::ScopedComponent");
+}
+
+#[derive(Component)]
+struct SpawnerModule;
+
+impl Module for SpawnerModule {
+    fn module(world: &World) {
+        world.module::<SpawnerModule>("SpawnerModule");
+        world.component::<Velocity>();
+        world.entity_named("Spawner");
+        world.import::<ns::NestedModule>();
+    }
+}
+
+#[test]
+fn import_named_twice() {
+    let world = World::new();
+
+    let a = world.import_named::<SpawnerModule>("instance_a");
+    let b = world.import_named::<SpawnerModule>("instance_b");
+
+    assert_ne!(a.id(), b.id());
+    assert!(a.has(flecs::Module::ID));
+    assert!(b.has(flecs::Module::ID));
+    assert_eq!(a.path().unwrap(), "::instance_a");
+    assert_eq!(b.path().unwrap(), "::instance_b");
+
+    let spawner_a = world.lookup("instance_a::Spawner");
+    let spawner_b = world.lookup("instance_b::Spawner");
+    assert_ne!(spawner_a.id(), spawner_b.id());
+    assert_eq!(spawner_a.parent().unwrap().id(), a.id());
+    assert_eq!(spawner_b.parent().unwrap().id(), b.id());
+
+    // components registered by type are shared, and live in the first import's scope
+    let velocity = world.component::<Velocity>();
+    assert_eq!(velocity.parent().unwrap().id(), a.id());
+
+    // the module type itself is never renamed by a named import
+    assert!(world.try_lookup("instance_a::SpawnerModule").is_none());
+
+    // nested imports keep their own module scope
+    assert!(world.try_lookup("ns::NestedModule").is_some());
+
+    // importing under an existing name does not run the module again
+    let again = world.import_named::<SpawnerModule>("instance_a");
+    assert_eq!(again.id(), a.id());
+    assert!(world.get_scope().is_none());
+}
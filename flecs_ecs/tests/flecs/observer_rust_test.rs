@@ -73,3 +73,179 @@ fn observer_query_on_non_observer_entity_panics() {
     let mut observer = world.observer_from(entity);
     let _ = observer.query();
 }
+
+#[test]
+fn observer_on_set_manual_modified() {
+    let world = World::new();
+
+    world.set(Count(0));
+
+    world
+        .observer::<flecs::OnSet, &Position>()
+        .each_entity(|e, _| {
+            e.world().get::<&mut Count>(|c| c.0 += 1);
+        });
+
+    let e = world.entity().add(Position::id());
+    world.get::<&Count>(|c| assert_eq!(c.0, 0));
+
+    e.get::<&mut Position>(|p| p.x = 10);
+    world.get::<&Count>(|c| assert_eq!(c.0, 0));
+
+    e.modified(Position::id());
+    world.get::<&Count>(|c| assert_eq!(c.0, 1));
+}
+
+#[test]
+fn observer_on_set_manual_modified_pair() {
+    let world = World::new();
+
+    world.set(Count(0));
+
+    world
+        .observer::<flecs::OnSet, &(Position, Tag)>()
+        .each_entity(|e, _| {
+            e.world().get::<&mut Count>(|c| c.0 += 1);
+        });
+
+    let e = world.entity().add((Position::id(), Tag::id()));
+    world.get::<&Count>(|c| assert_eq!(c.0, 0));
+
+    e.get::<&mut (Position, Tag)>(|p| p.x = 10);
+    world.get::<&Count>(|c| assert_eq!(c.0, 0));
+
+    e.modified((Position::id(), Tag::id()));
+    world.get::<&Count>(|c| assert_eq!(c.0, 1));
+}
+
+#[derive(Component)]
+struct Bullet;
+
+#[derive(Component)]
+struct Networked;
+
+#[test]
+fn observer_on_add_w_filter_term() {
+    let world = World::new();
+
+    world.set(Count(0));
+
+    world
+        .observer::<flecs::OnAdd, ()>()
+        .with(Bullet::id())
+        .with(Networked::id())
+        .filter()
+        .each_entity(|e, _| {
+            e.world().get::<&mut Count>(|c| c.0 += 1);
+        });
+
+    let local = world.entity().add(Bullet::id());
+    world.get::<&Count>(|c| assert_eq!(c.0, 0));
+
+    // the filter term matches but does not trigger the observer
+    local.add(Networked::id());
+    world.get::<&Count>(|c| assert_eq!(c.0, 0));
+
+    world.entity().add(Networked::id()).add(Bullet::id());
+    world.get::<&Count>(|c| assert_eq!(c.0, 1));
+}
+
+#[test]
+fn observer_dsl_on_add_w_filter_term() {
+    use flecs_ecs::macros::observer;
+
+    let world = World::new();
+
+    world.set(Count(0));
+
+    observer!(world, flecs::OnAdd, Bullet, [filter] Networked).each_entity(|e, _| {
+        e.world().get::<&mut Count>(|c| c.0 += 1);
+    });
+
+    world.entity().add(Bullet::id());
+    world.get::<&Count>(|c| assert_eq!(c.0, 0));
+
+    let networked = world.entity().add(Networked::id());
+    world.get::<&Count>(|c| assert_eq!(c.0, 0));
+
+    networked.add(Bullet::id());
+    world.get::<&Count>(|c| assert_eq!(c.0, 1));
+}
+
+#[test]
+fn observer_disable_enable_destruct() {
+    let world = World::new();
+
+    world.set(Count(0));
+
+    let observer = world
+        .observer::<flecs::OnSet, &Position>()
+        .each_entity(|e, _| {
+            e.world().get::<&mut Count>(|c| c.0 += 1);
+        });
+
+    world.entity().set(Position { x: 10, y: 20 });
+    world.get::<&Count>(|c| assert_eq!(c.0, 1));
+
+    observer.disable_self();
+    assert!(observer.has(flecs::Disabled::ID));
+    world.entity().set(Position { x: 10, y: 20 });
+    world.get::<&Count>(|c| assert_eq!(c.0, 1));
+
+    observer.enable_self();
+    world.entity().set(Position { x: 10, y: 20 });
+    world.get::<&Count>(|c| assert_eq!(c.0, 2));
+
+    let id = observer.id();
+    observer.destruct();
+    assert!(!world.is_alive(id));
+    world.entity().set(Position { x: 10, y: 20 });
+    world.get::<&Count>(|c| assert_eq!(c.0, 2));
+}
+
+#[test]
+fn observers_for_event_and_component() {
+    let world = World::new();
+
+    let first = world.observer::<flecs::OnSet, &Position>().each(|_| {});
+    let second = world
+        .observer::<flecs::OnSet, ()>()
+        .with(Position::id())
+        .with(Velocity::id())
+        .each_entity(|_, _| {});
+    world.observer::<flecs::OnSet, &Velocity>().each(|_| {});
+    world
+        .observer::<flecs::OnAdd, ()>()
+        .with(Position::id())
+        .each_entity(|_, _| {});
+
+    let observers = world.observers_for(flecs::OnSet, Position::id());
+    assert_eq!(observers, [first, second]);
+    assert_eq!(world.observer_count(flecs::OnSet, Position::id()), 2);
+    assert_eq!(world.observer_count(flecs::OnSet, Velocity::id()), 2);
+    assert_eq!(world.observer_count(flecs::OnRemove, Position::id()), 0);
+
+    // disabled observers are still listed
+    first.disable_self();
+    assert_eq!(world.observer_count(flecs::OnSet, Position::id()), 2);
+
+    second.destruct();
+    assert_eq!(world.observers_for(flecs::OnSet, Position::id()), [first]);
+}
+
+#[test]
+fn observers_for_wildcard_term() {
+    let world = World::new();
+
+    let observer = world
+        .observer::<flecs::OnAdd, ()>()
+        .with((Likes::id(), flecs::Wildcard::ID))
+        .each_entity(|_, _| {});
+
+    let apples = world.entity();
+    assert_eq!(
+        world.observers_for(flecs::OnAdd, (Likes::id(), apples)),
+        [observer]
+    );
+    assert_eq!(world.observer_count(flecs::OnAdd, Likes::id()), 0);
+}
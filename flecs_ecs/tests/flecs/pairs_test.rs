@@ -179,6 +179,50 @@ fn pairs_set_component_pair() {
     });
 }
 
+#[test]
+fn pairs_set_pair_first_data() {
+    let world = World::new();
+
+    let entity = world
+        .entity()
+        .set_pair_first::<PairData, LocalApples>(PairData { value: 10.0 });
+
+    assert!(entity.has((PairData::id(), LocalApples::id())));
+    assert!(!entity.has(PairData::id()));
+
+    entity.get::<&(PairData, LocalApples)>(|t| {
+        assert_eq!(t.value as i32, 10);
+    });
+}
+
+#[test]
+fn pairs_set_pair_second_data() {
+    let world = World::new();
+
+    let entity = world
+        .entity()
+        .set_pair_second::<LocalLikes, Position>(Position { x: 10, y: 20 });
+
+    assert!(entity.has((LocalLikes::id(), Position::id())));
+    assert!(!entity.has(Position::id()));
+    assert_eq!(
+        entity.archetype().to_string(),
+        Some("(LocalLikes,Position)".to_string())
+    );
+
+    entity.get::<&(LocalLikes, Position)>(|p| {
+        assert_eq!(p.x, 10);
+        assert_eq!(p.y, 20);
+    });
+
+    entity.set_pair_second::<LocalLikes, Position>(Position { x: 30, y: 40 });
+
+    entity.get::<&(LocalLikes, Position)>(|p| {
+        assert_eq!(p.x, 30);
+        assert_eq!(p.y, 40);
+    });
+}
+
 #[test]
 fn pairs_set_tag_pair() {
     let world = World::new();
@@ -1432,3 +1476,144 @@ fn pairs_modified_tag_first() {
 
     assert_eq!(*count.lock().unwrap(), 1);
 }
+
+#[derive(Component)]
+#[flecs(traits(PairIsTag))]
+struct TagRel {
+    value: i32,
+}
+
+#[test]
+fn pairs_data_tag_pair_get_set() {
+    let world = World::new();
+
+    let e = world
+        .entity()
+        .set_pair::<Position, Tag>(Position { x: 10, y: 20 });
+
+    assert_eq!(
+        world.id_view_from((Position::id(), Tag::id())).type_id(),
+        world.component_id::<Position>()
+    );
+    e.get::<&(Position, Tag)>(|p| {
+        assert_eq!(p.x, 10);
+        assert_eq!(p.y, 20);
+    });
+}
+
+#[test]
+fn pairs_tag_data_pair_get_set() {
+    let world = World::new();
+
+    let e = world
+        .entity()
+        .set_pair::<Tag, Position>(Position { x: 10, y: 20 });
+
+    assert_eq!(
+        world.id_view_from((Tag::id(), Position::id())).type_id(),
+        world.component_id::<Position>()
+    );
+    e.get::<&(Tag, Position)>(|p| {
+        assert_eq!(p.x, 10);
+        assert_eq!(p.y, 20);
+    });
+}
+
+#[test]
+fn pairs_tag_tag_pair_has_no_data() {
+    let world = World::new();
+
+    // `get`/`set_pair` on (TagA, TagB) fail to compile, the pair can only be added
+    let e = world.entity().add((TagA::id(), TagB::id()));
+
+    assert!(e.has((TagA::id(), TagB::id())));
+    assert_eq!(world.id_view_from((TagA::id(), TagB::id())).type_id(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Pair is not a (data) component")]
+fn pairs_pair_is_tag_get_panics() {
+    let world = World::new();
+
+    let e = world.entity().add((TagRel::id(), Position::id()));
+
+    assert_eq!(
+        world.id_view_from((TagRel::id(), Position::id())).type_id(),
+        0
+    );
+    e.get::<&(TagRel, Position)>(|r| {
+        let _ = r.value;
+    });
+}
+
+#[test]
+#[should_panic(expected = "Pair is not a (data) component")]
+fn pairs_pair_is_tag_set_panics() {
+    let world = World::new();
+
+    world
+        .entity()
+        .set_pair::<TagRel, Position>(TagRel { value: 10 });
+}
+
+#[test]
+fn pairs_get_first_data_first() {
+    let world = World::new();
+
+    let e = world
+        .entity()
+        .set_pair::<Position, Tag>(Position { x: 10, y: 20 });
+
+    let x = e.get_first::<Position, Tag>(|p| p.x);
+    assert_eq!(x, 10);
+    assert_eq!(e.try_get_first::<Position, Tag>(|p| p.y), Some(20));
+
+    // both elements carry data, the value is stored in the first element
+    let e = world
+        .entity()
+        .set_pair::<Position, Velocity>(Position { x: 1, y: 2 });
+    assert_eq!(e.try_get_first::<Position, Velocity>(|p| p.x), Some(1));
+}
+
+#[test]
+fn pairs_get_second_data_second() {
+    let world = World::new();
+
+    let e = world
+        .entity()
+        .set_pair::<Tag, Position>(Position { x: 10, y: 20 });
+
+    let x = e.get_second::<Tag, Position>(|p| p.x);
+    assert_eq!(x, 10);
+    assert_eq!(e.try_get_second::<Tag, Position>(|p| p.y), Some(20));
+}
+
+#[test]
+fn pairs_try_get_first_second_not_present() {
+    let world = World::new();
+
+    let e = world
+        .entity()
+        .set_pair::<Tag, Position>(Position { x: 10, y: 20 });
+
+    let mut called = false;
+    assert!(
+        e.try_get_first::<Position, Tag>(|_| called = true)
+            .is_none()
+    );
+    assert!(
+        e.try_get_second::<TagA, Position>(|_| called = true)
+            .is_none()
+    );
+    assert!(!called);
+}
+
+#[test]
+#[should_panic(expected = "not found on `EntityView::get`")]
+fn pairs_get_second_not_present_panics() {
+    let world = World::new();
+
+    let e = world.entity();
+
+    e.get_second::<Tag, Position>(|_| {});
+}
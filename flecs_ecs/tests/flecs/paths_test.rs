@@ -51,6 +51,39 @@ fn paths_path_depth_2() {
     assert_eq!(e.id(), e_world.id());
 }
 
+#[test]
+fn paths_entity_named_resolve_or_create_path() {
+    let world = World::new();
+
+    let a = world.entity_named("a");
+    let c = world.entity_named("a::b::c");
+
+    assert_eq!(c.name(), "c");
+    assert_eq!(c.path().unwrap(), "::a::b::c");
+
+    // the missing parent is created, the existing one is reused
+    let b = c.parent().unwrap();
+    assert_eq!(b.name(), "b");
+    assert_eq!(b.parent().unwrap(), a);
+    assert!(a.parent().is_none());
+    assert!(c.has((flecs::ChildOf::ID, b)));
+    assert!(b.has((flecs::ChildOf::ID, a)));
+
+    // intermediate parents are plain named entities
+    let mut components = 0;
+    b.each_component(|id| {
+        if !id.is_pair() {
+            components += 1;
+        }
+    });
+    assert_eq!(components, 0);
+
+    // resolving the path again returns the same entities
+    assert_eq!(world.entity_named("a::b::c"), c);
+    assert_eq!(world.entity_named("a::b"), b);
+    assert_eq!(world.lookup("a::b::c"), c);
+}
+
 #[test]
 fn paths_entity_lookup_name() {
     let world = World::new();
@@ -359,3 +392,68 @@ fn paths_id_from_str_invalid_pair() {
     let id = IdView::new_from_str(&world, "(Rel, Tgt");
     assert_eq!(id, 0u64);
 }
+
+#[test]
+fn paths_lookup_by_id_path_pure_id() {
+    let world = World::new();
+
+    let e = world.entity();
+
+    let path = format!("#{}", e.id());
+    assert_eq!(world.try_lookup_by_id_path(&path).unwrap(), e);
+}
+
+#[test]
+fn paths_lookup_by_id_path_pure_name() {
+    let world = World::new();
+
+    let parent = world.entity_named("parent");
+    let child = world.entity_named("child").child_of(parent);
+
+    assert_eq!(world.try_lookup_by_id_path("parent").unwrap(), parent);
+    assert_eq!(world.try_lookup_by_id_path("parent::child").unwrap(), child);
+    assert!(world.try_lookup_by_id_path("parent::missing").is_none());
+}
+
+#[test]
+fn paths_lookup_by_id_path_mixed() {
+    let world = World::new();
+
+    let parent = world.entity();
+    let child = world.entity_named("child").child_of(parent);
+    let grandchild = world.entity_named("grandchild").child_of(child);
+
+    let path = format!("#{}::child", parent.id());
+    assert_eq!(world.try_lookup_by_id_path(&path).unwrap(), child);
+
+    let path = format!("#{}::child::grandchild", parent.id());
+    assert_eq!(world.try_lookup_by_id_path(&path).unwrap(), grandchild);
+
+    let path = format!("#{}::grandchild", child.id());
+    assert_eq!(world.try_lookup_by_id_path(&path).unwrap(), grandchild);
+
+    let path = format!("#{}::grandchild", parent.id());
+    assert!(world.try_lookup_by_id_path(&path).is_none());
+}
+
+#[test]
+fn paths_lookup_by_id_path_w_generation() {
+    let world = World::new();
+
+    let e = world.entity();
+    e.destruct();
+
+    // recycled id has a non-zero generation, so it doesn't fit in 32 bits
+    let recycled = world.entity();
+    assert_eq!(*recycled.id() as u32, *e.id() as u32);
+    assert!(*recycled.id() > u32::MAX as u64);
+
+    let child = world.entity_named("child").child_of(recycled);
+
+    let path = format!("#{}::child", recycled.id());
+    assert_eq!(world.try_lookup_by_id_path(&path).unwrap(), child);
+
+    // stale id
+    let path = format!("#{}::child", e.id());
+    assert!(world.try_lookup_by_id_path(&path).is_none());
+}
@@ -3768,6 +3768,41 @@ fn world_each_query_2_components_no_entity() {
     assert_eq!(count, 3);
 }
 
+#[test]
+fn world_each_matches_query() {
+    let world = World::new();
+
+    world.entity().set(Position { x: 1, y: 2 });
+    world
+        .entity()
+        .set(Position { x: 3, y: 4 })
+        .set(Velocity { x: 1, y: 1 });
+    world.entity().set(Velocity { x: 5, y: 6 });
+    world.entity().set(Position { x: 7, y: 8 }).add(TagA::id());
+
+    let mut expect = Vec::new();
+    world
+        .query::<&Position>()
+        .build()
+        .each_entity(|e, p| expect.push((e.id(), p.x, p.y)));
+
+    let mut actual = Vec::new();
+    let q = world.each_entity::<&Position>(|e, p| actual.push((e.id(), p.x, p.y)));
+
+    assert_eq!(actual.len(), 3);
+    assert_eq!(actual, expect);
+
+    // the returned query can be iterated again
+    assert_eq!(q.count(), 3);
+
+    world.each::<&mut Position>(|p| p.x += 10);
+
+    let mut xs = Vec::new();
+    world.each::<&Position>(|p| xs.push(p.x));
+    xs.sort_unstable();
+    assert_eq!(xs, [11, 13, 17]);
+}
+
 #[test]
 fn term_after_arg() {
     let world = World::new();
@@ -4150,6 +4185,35 @@ fn with() {
     assert_eq!(count, 1);
 }
 
+#[test]
+fn with_name_runtime_component() {
+    let world = World::new();
+
+    let dynamic = world.component_untyped_named("DynamicComp");
+
+    let e1 = world.entity().set(Position { x: 10, y: 20 }).add(dynamic);
+    world.entity().set(Position { x: 0, y: 0 });
+    world.entity().add(dynamic);
+
+    let q = world.query::<&Position>().with("DynamicComp").build();
+
+    let mut count = 0;
+    q.each_entity(|e, p| {
+        count += 1;
+        assert_eq!(e, e1);
+        assert_eq!((p.x, p.y), (10, 20));
+    });
+    assert_eq!(count, 1);
+
+    assert!(
+        world
+            .query::<&Position>()
+            .with("NotAComponent")
+            .try_build()
+            .is_none()
+    );
+}
+
 #[test]
 fn with_name() {
     let world = World::new();
@@ -5459,6 +5523,46 @@ fn var_second_w_prefixed_name() {
     assert_eq!(count, 1);
 }
 
+#[test]
+fn term_pairs_joined_by_shared_variable() {
+    let world = World::new();
+
+    let located_in = world.entity();
+    let has_climate = world.entity();
+    let tropical = world.entity();
+    let arctic = world.entity();
+
+    let jungle = world.entity().add((has_climate, tropical));
+    let tundra = world.entity().add((has_climate, arctic));
+
+    let parrot = world.entity().add((located_in, jungle));
+    world.entity().add((located_in, tundra));
+
+    // (LocatedIn, $x), (HasClimate, tropical)($x)
+    let r = world
+        .query::<()>()
+        .term()
+        .set_first(located_in)
+        .set_second("$x")
+        .term()
+        .set_first(has_climate)
+        .set_second(tropical)
+        .set_src("$x")
+        .build();
+
+    let mut count = 0;
+    r.run(|mut it| {
+        while it.next() {
+            assert_eq!(it.count(), 1);
+            assert_eq!(it.get_entity(0usize).unwrap(), parrot);
+            assert_eq!(it.get_var_by_name("x"), jungle);
+            count += 1;
+        }
+    });
+
+    assert_eq!(count, 1);
+}
+
 #[test]
 fn term_w_second_var_string() {
     let world = World::new();
@@ -86,6 +86,38 @@ fn query_iter_stage() {
     world.progress();
 }
 
+#[test]
+fn query_iter_stage_defers_to_stage() {
+    let world = World::new();
+    world.set_stage_count(2);
+
+    let e1 = world.entity().set(Position { x: 1, y: 2 });
+    let e2 = world.entity().set(Position { x: 3, y: 4 });
+
+    let query = world.new_query::<&Position>();
+
+    world.readonly_begin(false);
+    let stage = world.stage(1);
+
+    let mut count = 0;
+    query.iter_stage(stage).each_entity(|e, p| {
+        e.add(Tag).set(Velocity { x: p.x, y: p.y });
+        count += 1;
+    });
+    assert_eq!(count, 2);
+
+    // structural changes are queued on the stage until it is merged
+    assert!(!e1.has(Tag));
+    assert!(!e2.has(Velocity::id()));
+
+    world.readonly_end();
+
+    assert!(e1.has(Tag));
+    assert!(e2.has(Tag));
+    e1.get::<&Velocity>(|v| assert_eq!((v.x, v.y), (1, 2)));
+    e2.get::<&Velocity>(|v| assert_eq!((v.x, v.y), (3, 4)));
+}
+
 #[test]
 fn query_handle_keeps_query_alive() {
     #[derive(Component, Debug)]
@@ -1273,6 +1305,49 @@ fn query_find_w_entity_w_match_empty_tables() {
     assert_eq!(r.unwrap(), e2);
 }
 
+// ─── find_stops_at_first_match ────────────────────────────────────────────────
+
+#[test]
+fn query_find_stops_at_first_match() {
+    let world = World::new();
+
+    world.entity().set(Position { x: 10, y: 20 });
+    let e2 = world
+        .entity()
+        .set(Position { x: 20, y: 30 })
+        .add(TagA::id());
+    world
+        .entity()
+        .set(Position { x: 20, y: 40 })
+        .add(TagB::id());
+    world
+        .entity()
+        .set(Position { x: 30, y: 50 })
+        .add(Velocity::id());
+
+    let q = world.query::<&Position>().set_cached().build();
+
+    let mut calls = 0;
+    let r = q.find(|p| {
+        calls += 1;
+        p.x == 20
+    });
+    assert_eq!(r.unwrap(), e2);
+    assert_eq!(calls, 2);
+
+    // the iterator was finalized, so tables are writable again
+    e2.set(Position { x: 1, y: 2 });
+    e2.get::<&Position>(|p| assert_eq!(p.x, 1));
+
+    let mut calls = 0;
+    let r = q.find_entity(|_e, p| {
+        calls += 1;
+        p.x == 20
+    });
+    assert_eq!(r.unwrap().get::<&Position>(|p| p.y), 40);
+    assert_eq!(calls, 3);
+}
+
 // ─── run ──────────────────────────────────────────────────────────────────────
 
 #[test]
@@ -1303,6 +1378,60 @@ fn query_run() {
     });
 }
 
+// ─── iter ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn query_iter_sum_field_per_table() {
+    let world = World::new();
+
+    world.entity().set(Mass { value: 1 });
+    world.entity().set(Mass { value: 2 });
+    world.entity().set(Mass { value: 3 }).add(Tag);
+    world.entity().set(Position { x: 1, y: 2 });
+
+    let q = world.new_query::<&Mass>();
+
+    let mut tables = 0;
+    let mut sum = 0;
+    q.iter(|it| {
+        tables += 1;
+        let m = it.field::<Mass>(0);
+        sum += m.as_slice().iter().map(|m| m.value).sum::<i32>();
+    });
+
+    assert_eq!(tables, 2);
+    assert_eq!(sum, 6);
+}
+
+#[test]
+fn query_iter_write_field() {
+    let world = World::new();
+
+    let e1 = world
+        .entity()
+        .set(Position { x: 10, y: 20 })
+        .set(Velocity { x: 1, y: 2 });
+    let e2 = world
+        .entity()
+        .set(Position { x: 30, y: 40 })
+        .set(Velocity { x: 3, y: 4 })
+        .add(Tag);
+
+    let q = world.new_query::<(&mut Position, &Velocity)>();
+
+    q.iter(|it| {
+        let mut p = it.field_mut::<Position>(0);
+        let v = it.field::<Velocity>(1);
+        for i in it.iter() {
+            p[i].x += v[i].x;
+            p[i].y += v[i].y;
+        }
+    });
+
+    e1.get::<&Position>(|p| assert_eq!((p.x, p.y), (11, 22)));
+    e2.get::<&Position>(|p| assert_eq!((p.x, p.y), (33, 44)));
+}
+
 // ─── run_const ────────────────────────────────────────────────────────────────
 
 #[test]
@@ -2126,6 +2255,84 @@ fn query_sort_by() {
     });
 }
 
+#[test]
+fn query_order_by_cmp_across_tables() {
+    let world = World::new();
+
+    for x in [7, 3, 9, 1, 5, 8, 2, 6, 4] {
+        let e = world.entity().set(Position { x, y: 0 });
+        if x % 2 == 0 {
+            e.add(Tag::id());
+        }
+    }
+
+    let q = world
+        .query::<&Position>()
+        .order_by_cmp::<Position>(|p1: &Position, p2: &Position| p1.x.cmp(&p2.x))
+        .build();
+
+    let mut xs = Vec::new();
+    q.each(|p| xs.push(p.x));
+    assert_eq!(xs, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+}
+
+// ─── disabled entities ────────────────────────────────────────────────────────
+
+#[test]
+fn query_skips_disabled_entities() {
+    let world = World::new();
+
+    let e1 = world.entity().set(Position { x: 1, y: 0 });
+    let e2 = world.entity().set(Position { x: 2, y: 0 }).disable_self();
+
+    assert!(e1.is_enabled_self());
+    assert!(!e2.is_enabled_self());
+    assert!(e2.has(flecs::Disabled::ID));
+
+    let q = world.new_query::<&Position>();
+    let mut found = Vec::new();
+    q.each_entity(|e, _| found.push(e.id()));
+    assert_eq!(found, [e1.id()]);
+
+    e2.enable_self();
+    assert!(e2.is_enabled_self());
+    assert_eq!(q.count(), 2);
+}
+
+#[test]
+fn query_with_disabled_matches_disabled_entities() {
+    let world = World::new();
+
+    let e1 = world.entity().set(Position { x: 1, y: 0 });
+    let e2 = world.entity().set(Position { x: 2, y: 0 }).disable_self();
+
+    let q = world.query::<&Position>().with_disabled().build();
+    let mut found = Vec::new();
+    q.each_entity(|e, _| found.push(e.id()));
+    found.sort();
+    assert_eq!(found, [e1.id(), e2.id()]);
+}
+
+#[test]
+fn query_with_prefabs_matches_prefabs() {
+    let world = World::new();
+
+    let e = world.entity().set(Position { x: 1, y: 0 });
+    let prefab = world.prefab().set(Position { x: 2, y: 0 });
+
+    let mut found = Vec::new();
+    world
+        .new_query::<&Position>()
+        .each_entity(|e, _| found.push(e.id()));
+    assert_eq!(found, [e.id()]);
+
+    let q = world.query::<&Position>().with_prefabs().build();
+    let mut found = Vec::new();
+    q.each_entity(|e, _| found.push(e.id()));
+    found.sort();
+    assert_eq!(found, [e.id(), prefab.id()]);
+}
+
 // ─── changed ──────────────────────────────────────────────────────────────────
 
 #[test]
@@ -2627,6 +2834,159 @@ fn query_iter_type() {
     });
 }
 
+// ─── iter_table_id / iter_group_id ────────────────────────────────────────────
+
+#[test]
+fn query_iter_table_id_stable() {
+    let world = World::new();
+
+    let e1 = world.entity().set(Position { x: 1, y: 0 });
+    let e2 = world
+        .entity()
+        .set(Position { x: 2, y: 0 })
+        .set(Velocity { x: 0, y: 0 });
+
+    let q = world.new_query::<&Position>();
+    let table_ids = || {
+        let mut ids = Vec::new();
+        q.run(|mut it| {
+            while it.next() {
+                assert_ne!(it.table_id(), 0);
+                for i in it.iter() {
+                    ids.push((it.entity(i).id(), it.table_id()));
+                }
+            }
+        });
+        ids.sort();
+        ids
+    };
+
+    let first = table_ids();
+    assert_eq!(first.len(), 2);
+    assert_ne!(first[0].1, first[1].1);
+
+    // entities that don't change archetype keep their table id
+    e1.set(Position { x: 3, y: 0 });
+    world.entity().set(Position { x: 4, y: 0 }).destruct();
+    assert_eq!(table_ids(), first);
+
+    e2.remove(Velocity::id());
+    let after = table_ids();
+    assert_eq!(after[0].1, after[1].1);
+    assert_eq!(after[0].1, first[0].1);
+}
+
+#[test]
+fn query_iter_group_id() {
+    let world = World::new();
+
+    let a = world.entity();
+    let b = world.entity();
+    world
+        .entity()
+        .set(Position { x: 1, y: 0 })
+        .add((Likes::id(), a));
+    world
+        .entity()
+        .set(Position { x: 2, y: 0 })
+        .add((Likes::id(), b))
+        .add(Tag::id());
+
+    let q = world
+        .query::<&Position>()
+        .with((Likes::id(), flecs::Wildcard::ID))
+        .group_by(Likes::id())
+        .build();
+
+    let mut groups = Vec::new();
+    q.run(|mut it| {
+        while it.next() {
+            groups.push(it.group_id());
+        }
+    });
+    groups.sort();
+    assert_eq!(groups, [*a.id(), *b.id()]);
+}
+
+// ─── table_count / matched_table_sizes ────────────────────────────────────────
+
+#[test]
+fn query_table_count() {
+    let world = World::new();
+
+    let entities: Vec<_> = (0..5)
+        .map(|i| world.entity().set(Position { x: i, y: 0 }))
+        .collect();
+    world.entity().set(Velocity { x: 0, y: 0 });
+
+    let q = world.new_query::<&Position>();
+    assert_eq!(q.table_count(), 1);
+    assert_eq!(q.matched_table_sizes(), [5]);
+
+    entities[3].add(Tag::id());
+    entities[4].add(Tag::id());
+    assert_eq!(q.table_count(), 2);
+    assert_eq!(q.matched_table_sizes(), [3, 2]);
+
+    entities[0].add(Velocity::id());
+    assert_eq!(q.table_count(), 3);
+    let mut sizes = q.matched_table_sizes();
+    sizes.sort();
+    assert_eq!(sizes, [1, 2, 2]);
+    assert_eq!(sizes.iter().sum::<i32>(), q.count());
+}
+
+#[test]
+fn query_matched_table_sizes_wildcard_counts_entities_once() {
+    let world = World::new();
+
+    let apples = world.entity();
+    let pears = world.entity();
+    world
+        .entity()
+        .add((Likes::id(), apples))
+        .add((Likes::id(), pears));
+    world.entity().add((Likes::id(), apples));
+
+    let q = world
+        .query::<()>()
+        .with((Likes::id(), flecs::Wildcard::ID))
+        .build();
+
+    assert_eq!(q.count(), 3);
+    assert_eq!(q.table_count(), 2);
+    let mut sizes = q.matched_table_sizes();
+    sizes.sort();
+    assert_eq!(sizes, [1, 1]);
+}
+
+#[test]
+fn query_matched_table_sizes_skips_toggled_off_entities() {
+    let world = World::new();
+
+    world
+        .component::<Position>()
+        .add_trait::<flecs::CanToggle>();
+
+    // enabling adds the toggle bitset, so all entities end up in the same table
+    let entities: Vec<_> = (0..3)
+        .map(|i| {
+            world
+                .entity()
+                .set(Position { x: i, y: 0 })
+                .enable(Position::id())
+        })
+        .collect();
+    entities[1].disable(Position::id());
+    assert_eq!(entities[0].table(), entities[1].table());
+
+    let q = world.new_query::<&Position>();
+
+    assert_eq!(q.count(), 2);
+    assert_eq!(q.table_count(), 1);
+    assert_eq!(q.matched_table_sizes(), [2]);
+}
+
 // ─── instanced_query_w_singleton_each ────────────────────────────────────────
 
 #[test]
@@ -2896,6 +3256,48 @@ fn query_instanced_query_w_base_iter() {
     });
 }
 
+// ─── inherited_field_is_shared ───────────────────────────────────────────────
+
+#[test]
+fn query_inherited_field_is_shared() {
+    let world = World::new();
+
+    world
+        .component::<Velocity>()
+        .add_trait::<(flecs::OnInstantiate, flecs::Inherit)>();
+
+    let base = world.entity().set(Velocity { x: 1, y: 2 });
+    world.entity().is_a(base).set(Position { x: 10, y: 20 });
+    world.entity().is_a(base).set(Position { x: 20, y: 30 });
+    world.entity().is_a(base).set(Position { x: 30, y: 40 });
+
+    let q = world.new_query::<(&Position, &Velocity)>();
+
+    // each passes the same shared value to every entity
+    let mut shared = Vec::new();
+    q.each(|(_, v)| shared.push(v as *const Velocity));
+    assert_eq!(shared.len(), 3);
+    assert!(shared.iter().all(|&v| v == shared[0]));
+
+    // run yields the whole table with a single shared element
+    let mut results = 0;
+    q.run(|mut it| {
+        while it.next() {
+            results += 1;
+            let p = it.field::<Position>(0);
+            let v = it.field::<Velocity>(1);
+            assert_eq!(it.count(), 3);
+            assert!(!p.is_shared());
+            assert!(v.is_shared());
+            assert!(!it.is_self(1));
+            assert_eq!(v.len(), 1);
+            assert_eq!(it.src(1), base);
+            assert_eq!(v[0].y, 2);
+        }
+    });
+    assert_eq!(results, 1);
+}
+
 // ─── query_each_from_component ────────────────────────────────────────────────
 
 #[test]
@@ -3241,6 +3643,90 @@ fn query_change_tracking() {
     assert_eq!(change_count, 1);
 }
 
+// ─── each_changed ─────────────────────────────────────────────────────────────
+
+#[test]
+fn query_each_changed() {
+    let world = World::new();
+
+    let qw = world.new_query::<&mut Position>();
+    let qr = world.query::<&Position>().detect_changes().build();
+
+    let e1 = world.entity().add(Tag::id()).set(Position { x: 10, y: 20 });
+    let e2 = world.entity().set(Position { x: 20, y: 30 });
+
+    // all tables are new to the query
+    let mut visited = Vec::new();
+    qr.each_changed(|e, _p| visited.push(e.id()));
+    visited.sort();
+    let mut expected = vec![e1.id(), e2.id()];
+    expected.sort();
+    assert_eq!(visited, expected);
+
+    // nothing changed since the last iteration
+    let mut count = 0;
+    qr.each_changed(|_e, _p| count += 1);
+    assert_eq!(count, 0);
+
+    // write to the table of e2 only
+    qw.run(|mut it| {
+        while it.next() {
+            if it.entity_id(0_usize) == e1.id() {
+                it.skip();
+                continue;
+            }
+            let mut p = it.field_mut::<Position>(0);
+            p[0].x += 1;
+        }
+    });
+
+    let mut visited = Vec::new();
+    qr.each_changed(|e, p| {
+        assert_eq!(p.x, 21);
+        visited.push(e.id());
+    });
+    assert_eq!(visited, [e2.id()]);
+    assert!(!qr.is_changed());
+}
+
+// ─── up_traversal_only_inherited ──────────────────────────────────────────────
+
+#[test]
+fn query_up_traversal_only_inherited() {
+    let world = World::new();
+
+    let parent = world.entity().set(Position { x: 1, y: 2 });
+    let child = world.entity().child_of(parent);
+    // owns Position, but has no parent to inherit it from
+    world.entity().set(Position { x: 5, y: 6 });
+    // owns Position and inherits it from its parent
+    let owned_child = world.entity().child_of(parent).set(Position { x: 3, y: 4 });
+
+    let q_builder = world.query::<&Position>().term_at(0).up().build();
+    let q_dsl = query!(world, &Position(up)).build();
+
+    for q in [&q_builder, &q_dsl] {
+        let mut matched = Vec::new();
+        q.run(|mut it| {
+            while it.next() {
+                assert!(!it.is_self(0));
+                let p = it.field::<Position>(0);
+                assert_eq!((p[0].x, p[0].y), (1, 2));
+                for i in it.iter() {
+                    matched.push(it.entity_id(i));
+                }
+            }
+        });
+        matched.sort();
+        let mut expected = vec![child.id(), owned_child.id()];
+        expected.sort();
+        assert_eq!(matched, expected);
+    }
+
+    let q_self_up = query!(world, &Position(self up)).build();
+    assert_eq!(q_self_up.count(), 4);
+}
+
 // ─── not_w_write ──────────────────────────────────────────────────────────────
 
 #[test]
@@ -4058,15 +4544,191 @@ fn query_pair_with_variable_src() {
     assert_eq!(is_present, 7);
 }
 
-// ─── has_entity / has_table / has_range ──────────────────────────────────────
-// TODO: missing API: query.has(entity) / query.has(table) / query.has(range)
-// ecs_query_has / ecs_query_has_table / ecs_query_has_range are in sys bindings
-// but not wrapped in the Rust high-level query API yet.
+// ─── worker ──────────────────────────────────────────────────────────────────
 
-// ─── page_iter_captured_query / worker_iter_captured_query ───────────────────
-// TODO: missing API: QueryIter::page() / QueryIter::worker() not yet implemented
-// See flecs_ecs/src/core/query_iter.rs line 159:
-// "TODO : worker_iterable and page_iterable not implemented yet"
+#[test]
+fn query_worker_sum_matches_single_threaded() {
+    let world = World::new();
+
+    for i in 0..100 {
+        let e = world.entity().set(Mass { value: i });
+        // spread the entities over multiple tables
+        if i % 3 == 0 {
+            e.add(Tag);
+        }
+        if i % 5 == 0 {
+            e.set(Position { x: i, y: i });
+        }
+    }
+
+    let q = world.new_query::<&Mass>();
+
+    let mut expected = 0;
+    q.each(|m| expected += m.value);
+
+    let mut total = 0;
+    let mut visited = 0;
+    for index in 0..4 {
+        q.worker(index, 4).each(|m| {
+            total += m.value;
+            visited += 1;
+        });
+    }
+
+    assert_eq!(total, expected);
+    assert_eq!(visited, 100);
+}
+
+#[test]
+fn query_worker_disjoint() {
+    let world = World::new();
+
+    for i in 0..10 {
+        world.entity().set(Mass { value: i });
+    }
+
+    let q = world.new_query::<&Mass>();
+
+    let mut seen = std::collections::HashSet::new();
+    for index in 0..4 {
+        q.worker(index, 4).each_entity(|e, _| {
+            assert!(
+                seen.insert(e.id()),
+                "entity visited by more than one worker"
+            );
+        });
+    }
+
+    assert_eq!(seen.len(), 10);
+}
+
+#[test]
+#[should_panic(expected = "none were found")]
+fn query_single_no_match() {
+    let world = World::new();
+
+    let q = world.new_query::<&Mass>();
+
+    assert!(q.try_first_entity().is_none());
+
+    q.single(|m| m.value);
+}
+
+#[test]
+fn query_single_one_match() {
+    let world = World::new();
+
+    let e = world.entity().set(Mass { value: 42 });
+    world.entity().set(Position { x: 1, y: 2 });
+
+    let q = world.new_query::<&Mass>();
+
+    assert_eq!(q.single(|m| m.value), 42);
+    assert_eq!(q.try_first_entity().map(|f| f.id()), Some(e.id()));
+}
+
+#[test]
+#[cfg_attr(
+    any(debug_assertions, feature = "flecs_force_enable_ecs_asserts"),
+    should_panic(expected = "found more than one")
+)]
+fn query_single_multiple_matches() {
+    let world = World::new();
+
+    world.entity().set(Mass { value: 1 });
+    world.entity().set(Mass { value: 2 });
+
+    let q = world.new_query::<&Mass>();
+
+    assert!(q.try_first_entity().is_some());
+
+    // release builds skip the exactly-one check and return the first match
+    assert_eq!(q.single(|m| m.value), 1);
+}
+
+#[test]
+fn query_table_iter_rows() {
+    let world = World::new();
+
+    let e1 = world.entity().set(Mass { value: 1 });
+    let e2 = world.entity().set(Mass { value: 2 });
+    let e3 = world.entity().set(Mass { value: 3 }).add(Tag);
+
+    let q = world.new_query::<&Mass>();
+
+    let mut seen = Vec::new();
+    q.run(|mut it| {
+        while it.next() {
+            assert!(!it.is_empty());
+            assert_eq!(it.iter_rows().len(), it.count());
+
+            let mass = it.field::<Mass>(0);
+            for (i, e) in it.iter_rows() {
+                assert_eq!(e, it.entity(i));
+                seen.push((e.id(), mass[i].value));
+            }
+        }
+    });
+
+    seen.sort_by_key(|(_, value)| *value);
+    assert_eq!(seen, vec![(e1.id(), 1), (e2.id(), 2), (e3.id(), 3)]);
+}
+
+// ─── has_entity_w_optional ───────────────────────────────────────────────────
+
+#[test]
+fn query_has_entity_w_optional() {
+    let world = World::new();
+
+    let both = world
+        .entity()
+        .set(Position { x: 1, y: 2 })
+        .set(Velocity { x: 3, y: 4 });
+    let no_optional = world.entity().set(Position { x: 5, y: 6 });
+    let no_required = world.entity().set(Velocity { x: 7, y: 8 });
+
+    let q = world
+        .query::<(&Position, Option<&Velocity>)>()
+        .without(Tag)
+        .build();
+
+    assert!(q.has(both));
+    assert!(q.has(no_optional));
+    assert!(!q.has(no_required));
+
+    both.add(Tag);
+    assert!(!q.has(both));
+}
+
+// ─── named_query_lookup ──────────────────────────────────────────────────────
+
+#[test]
+fn query_named_query_lookup() {
+    let world = World::new();
+
+    let e1 = world.entity().set(Position { x: 1, y: 2 });
+    world.entity().set(Velocity { x: 3, y: 4 });
+
+    let entity = world
+        .query_named::<&Position>("VisibleEntities")
+        .build()
+        .entity()
+        .id();
+
+    // the query outlives its handle and can be found by name
+    let found = world.lookup("VisibleEntities");
+    assert_eq!(found, entity);
+
+    let q = world.query_from(found);
+    let mut matched = Vec::new();
+    q.each_iter(|it, i, _| matched.push(it.entity(i).id()));
+    assert_eq!(matched, [e1.id()]);
+}
+
+// ─── page_iter_captured_query / worker_iter_captured_query ───────────────────
+// TODO: missing API: QueryIter::page() / QueryIter::worker() not yet implemented
+// See flecs_ecs/src/core/query_iter.rs line 159:
+// "TODO : worker_iterable and page_iterable not implemented yet"
 
 // ─── optional_module ──────────────────────────────────────────────────────────
 // TODO: missing API: world.import::<Module>() with Singleton semantics differs
@@ -4085,3 +4747,442 @@ fn query_pair_with_variable_src() {
 // ─── pair_with_variable_src_no_row_fields ────────────────────────────────────
 // Similar to query_pair_with_variable_src above but with non-tag Rel component.
 // Covered by the variable src test above.
+
+#[test]
+fn query_dsl_transitive_pair() {
+    #[derive(Component)]
+    struct LocatedIn;
+
+    let world = World::new();
+
+    world
+        .component::<LocatedIn>()
+        .add_trait::<flecs::Transitive>();
+
+    let c = world.entity_named("C");
+    let b = world.entity_named("B").add((LocatedIn::id(), c));
+    let a = world.entity_named("A").add((LocatedIn::id(), b));
+
+    // transitive: A is located in C through B
+    let q = query!(&world, (LocatedIn, $c)).build();
+    let mut matched = Vec::new();
+    q.each_entity(|e, _| matched.push(e.id()));
+    matched.sort();
+    assert_eq!(matched, vec![b.id(), a.id()]);
+
+    // self on the target disables transitive matching
+    let q = query!(&world, (LocatedIn, $c | self)).build();
+    let mut matched = Vec::new();
+    q.each_entity(|e, _| matched.push(e.id()));
+    assert_eq!(matched, vec![b.id()]);
+}
+
+#[test]
+fn query_dsl_transitive_reflexive_pair() {
+    #[derive(Component)]
+    struct LocatedIn;
+
+    let world = World::new();
+
+    world
+        .component::<LocatedIn>()
+        .add_trait::<flecs::Transitive>()
+        .add_trait::<flecs::Reflexive>();
+
+    let c = world.entity_named("C");
+    let b = world.entity_named("B").add((LocatedIn::id(), c));
+    let a = world.entity_named("A").add((LocatedIn::id(), b));
+
+    // reflexive: C is also considered to be located in itself
+    let q = query!(&world, (LocatedIn, $c)).build();
+    let mut matched = Vec::new();
+    q.each_entity(|e, _| matched.push(e.id()));
+    matched.sort();
+    let mut expected = vec![c.id(), b.id(), a.id()];
+    expected.sort();
+    assert_eq!(matched, expected);
+}
+
+#[test]
+fn query_tuple_with_without_terms() {
+    let world = World::new();
+
+    let e1 = world.entity().set(Position { x: 1, y: 2 }).add(TagA::id());
+    world
+        .entity()
+        .set(Position { x: 3, y: 4 })
+        .add(TagA::id())
+        .add(TagB::id());
+    world.entity().set(Position { x: 5, y: 6 });
+    let e4 = world
+        .entity()
+        .set(Position { x: 7, y: 8 })
+        .add(TagA::id())
+        .set(Velocity { x: 1, y: 1 });
+
+    let q = world.new_query::<(&Position, With<TagA>, Without<TagB>)>();
+
+    let mut matched = Vec::new();
+    q.each_entity(|e, (p, (), ())| matched.push((e.id(), p.x)));
+
+    let mut expected = Vec::new();
+    world
+        .query::<&Position>()
+        .with(TagA::id())
+        .without(TagB::id())
+        .build()
+        .each_entity(|e, p| expected.push((e.id(), p.x)));
+
+    assert_eq!(matched, expected);
+    matched.sort();
+    assert_eq!(matched, vec![(e1.id(), 1), (e4.id(), 7)]);
+
+    // filter terms still take up a field index
+    let q = world.new_query::<(&Position, Without<TagB>, &Velocity)>();
+    let mut count = 0;
+    q.run(|mut it| {
+        while it.next() {
+            let v = it.field::<Velocity>(2);
+            for i in it.iter() {
+                assert_eq!(it.get_entity(i).unwrap(), e4);
+                assert_eq!(v[i].x, 1);
+                count += 1;
+            }
+        }
+    });
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn query_tuple_with_without_pair_and_component() {
+    let world = World::new();
+
+    let e1 = world
+        .entity()
+        .set(Position { x: 1, y: 2 })
+        .add((Likes::id(), Apples::id()));
+    world
+        .entity()
+        .set(Position { x: 3, y: 4 })
+        .add((Likes::id(), Pears::id()));
+    let e3 = world
+        .entity()
+        .set(Position { x: 5, y: 6 })
+        .add((Likes::id(), Apples::id()))
+        .set(Velocity { x: 1, y: 1 });
+
+    let mut matched = Vec::new();
+    world
+        .new_query::<(&mut Position, With<(Likes, Apples)>)>()
+        .each_entity(|e, (p, ())| {
+            p.x += 10;
+            matched.push(e.id());
+        });
+    matched.sort();
+    assert_eq!(matched, vec![e1.id(), e3.id()]);
+
+    let mut matched = Vec::new();
+    world
+        .new_query::<(With<(Likes, Apples)>, Without<Velocity>, &Position)>()
+        .each_entity(|e, ((), (), p)| {
+            assert_eq!(p.x, 11);
+            matched.push(e.id());
+        });
+    assert_eq!(matched, vec![e1.id()]);
+
+    let mut count = 0;
+    world.new_query::<With<Velocity>>().each_entity(|e, ()| {
+        assert_eq!(e, e3);
+        count += 1;
+    });
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn system_tuple_with_without_terms() {
+    let world = World::new();
+
+    world.entity().set(Position { x: 1, y: 2 }).add(TagA::id());
+    world
+        .entity()
+        .set(Position { x: 3, y: 4 })
+        .add(TagA::id())
+        .add(TagB::id());
+
+    world
+        .system::<(&mut Position, With<TagA>, Without<TagB>)>()
+        .each(|(p, (), ())| p.x += 10);
+
+    world.progress();
+
+    let mut xs = Vec::new();
+    world.each::<&Position>(|p| xs.push(p.x));
+    xs.sort_unstable();
+    assert_eq!(xs, [3, 11]);
+}
+
+#[test]
+fn query_iter_src_inherited_component() {
+    #[derive(Component)]
+    #[flecs(traits((OnInstantiate, Inherit)))]
+    struct Health(i32);
+
+    let world = World::new();
+
+    let base = world.prefab().set(Health(100));
+    let inst = world.entity().is_a(base).set(Position { x: 1, y: 2 });
+    let own = world.entity().set(Health(50)).set(Position { x: 3, y: 4 });
+
+    let mut found = Vec::new();
+    world.new_query::<(&Health, &Position)>().run(|mut it| {
+        while it.next() {
+            let health = it.field::<Health>(0);
+            assert_eq!(it.id(0), world.id_view_from(Health::id()));
+            assert_eq!(it.src(1), 0);
+            for i in it.iter() {
+                let e = it.get_entity(i).unwrap().id();
+                let src = it.src(0).id();
+                // inherited fields are shared, so there is a single value
+                let value = if it.is_self(0) {
+                    health[i].0
+                } else {
+                    health[0].0
+                };
+                found.push((e, src, value));
+            }
+        }
+    });
+
+    found.sort();
+    let mut expected = vec![(inst.id(), base.id(), 100), (own.id(), Entity::new(0), 50)];
+    expected.sort();
+    assert_eq!(found, expected);
+}
+
+#[test]
+#[should_panic(expected = "field index 2 out of range")]
+fn query_iter_src_out_of_range_panics() {
+    let world = World::new();
+
+    world.entity().set(Position { x: 1, y: 2 });
+
+    world.new_query::<&Position>().run(|mut it| {
+        while it.next() {
+            let _ = it.src(2);
+        }
+    });
+}
+
+#[test]
+fn query_with_any_optional_matches_any_term() {
+    let world = World::new();
+
+    let e_p = world.entity().set(Position { x: 1, y: 2 });
+    let e_v = world.entity().set(Velocity { x: 3, y: 4 });
+    let e_m = world.entity().set(Mass { value: 5 });
+    let e_pv = world
+        .entity()
+        .set(Position { x: 1, y: 2 })
+        .set(Velocity { x: 3, y: 4 });
+    let e_vm = world
+        .entity()
+        .set(Velocity { x: 3, y: 4 })
+        .set(Mass { value: 5 });
+    let e_all = world
+        .entity()
+        .set(Position { x: 1, y: 2 })
+        .set(Velocity { x: 3, y: 4 })
+        .set(Mass { value: 5 });
+    world.entity().add(TagA::id());
+
+    let q = world
+        .query::<(Option<&Position>, Option<&Velocity>, Option<&Mass>)>()
+        .with_any_optional()
+        .build();
+
+    let mut found = Vec::new();
+    q.each_entity(|e, (p, v, m)| {
+        if let Some(p) = p {
+            assert_eq!((p.x, p.y), (1, 2));
+        }
+        if let Some(v) = v {
+            assert_eq!((v.x, v.y), (3, 4));
+        }
+        if let Some(m) = m {
+            assert_eq!(m.value, 5);
+        }
+        found.push((e.id(), p.is_some(), v.is_some(), m.is_some()));
+    });
+    found.sort();
+
+    let mut expected = vec![
+        (e_p.id(), true, false, false),
+        (e_v.id(), false, true, false),
+        (e_m.id(), false, false, true),
+        (e_pv.id(), true, true, false),
+        (e_vm.id(), false, true, true),
+        (e_all.id(), true, true, true),
+    ];
+    expected.sort();
+    assert_eq!(found, expected);
+}
+
+#[test]
+fn query_with_any_optional_with_required_term() {
+    let world = World::new();
+
+    world.entity().set(Position { x: 1, y: 2 }).add(TagA::id());
+    world.entity().set(Velocity { x: 3, y: 4 });
+    world.entity().add(TagA::id());
+
+    // only optional terms added before with_any_optional() are part of the chain
+    let q = world
+        .query::<(Option<&Position>, Option<&Velocity>)>()
+        .with_any_optional()
+        .with(TagA::id())
+        .build();
+
+    let mut count = 0;
+    q.each(|(p, v)| {
+        assert!(p.is_some());
+        assert!(v.is_none());
+        count += 1;
+    });
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn query_with_any_optional_flagged_src() {
+    let world = World::new();
+
+    world.entity().set(Position { x: 1, y: 2 });
+    world.entity().set(Velocity { x: 3, y: 4 });
+    world.entity().add(TagA::id());
+
+    // `self_()` sets a traversal flag on the source, which still is `$this`
+    let q = world
+        .query::<(Option<&Position>, Option<&Velocity>)>()
+        .term_at(0)
+        .self_()
+        .with_any_optional()
+        .build();
+
+    let mut count = 0;
+    q.each(|(p, v)| {
+        assert!(p.is_some() != v.is_some());
+        count += 1;
+    });
+    assert_eq!(count, 2);
+}
+
+#[test]
+#[should_panic(expected = "requires at least one optional term")]
+fn query_with_any_optional_without_optional_terms_panics() {
+    let world = World::new();
+
+    world.query::<&Position>().with_any_optional();
+}
+
+std::thread_local! {
+    static DRAINED_COMMAND_DROPS: core::cell::RefCell<Vec<u32>> =
+        const { core::cell::RefCell::new(Vec::new()) };
+}
+
+/// Records its id on drop; the default value (id 0) is the placeholder left in storage.
+#[derive(Component, Default)]
+struct DrainedCommand {
+    id: u32,
+}
+
+impl Drop for DrainedCommand {
+    fn drop(&mut self) {
+        DRAINED_COMMAND_DROPS.with(|d| d.borrow_mut().push(self.id));
+    }
+}
+
+#[test]
+fn query_drain_moves_out_and_removes() {
+    let world = World::new();
+
+    let e1 = world
+        .entity()
+        .set(Position { x: 0, y: 0 })
+        .set(DrainedCommand { id: 1 });
+    let e2 = world
+        .entity()
+        .set(Position { x: 0, y: 0 })
+        .set(DrainedCommand { id: 2 });
+    let e3 = world.entity().set(DrainedCommand { id: 3 });
+    // matched by the query, but has no command to drain
+    let e4 = world.entity().set(Position { x: 0, y: 0 });
+
+    DRAINED_COMMAND_DROPS.with(|d| d.borrow_mut().clear());
+
+    let mut drained = Vec::new();
+    world
+        .new_query::<&Position>()
+        .drain(|e, cmd: DrainedCommand| {
+            // removal is deferred until iteration is done
+            assert!(e.has(DrainedCommand::id()));
+            e.get::<&mut Position>(|p| p.x = cmd.id as i32);
+            drained.push((e.id(), cmd.id));
+            // the moved out value is dropped here, before the placeholder
+            drop(cmd);
+        });
+
+    drained.sort();
+    assert_eq!(drained, vec![(e1.id(), 1), (e2.id(), 2)]);
+
+    assert!(!e1.has(DrainedCommand::id()));
+    assert!(!e2.has(DrainedCommand::id()));
+    assert!(e3.has(DrainedCommand::id()));
+    e1.get::<&Position>(|p| assert_eq!(p.x, 1));
+    e2.get::<&Position>(|p| assert_eq!(p.x, 2));
+    e4.get::<&Position>(|p| assert_eq!(p.x, 0));
+
+    let mut drops = DRAINED_COMMAND_DROPS.with(|d| d.borrow().clone());
+    drops.sort();
+    // each drained value once, plus one placeholder per removal
+    assert_eq!(drops, vec![0, 0, 1, 2]);
+
+    drop(world);
+
+    let mut drops = DRAINED_COMMAND_DROPS.with(|d| d.borrow().clone());
+    drops.sort();
+    assert_eq!(drops, vec![0, 0, 1, 2, 3]);
+}
+
+#[test]
+fn query_drain_panic_ends_defer() {
+    let world = World::new();
+    world.entity().set(DrainedCommand { id: 1 });
+    let query = world.new_query::<&DrainedCommand>();
+
+    let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+        query.drain(|_, _: DrainedCommand| panic!("drain callback"));
+    }));
+
+    assert!(result.is_err());
+    assert!(!world.is_deferred());
+}
+
+#[test]
+fn query_drain_component_term() {
+    let world = World::new();
+
+    for id in 1..=3 {
+        world.entity().set(DrainedCommand { id });
+    }
+
+    DRAINED_COMMAND_DROPS.with(|d| d.borrow_mut().clear());
+
+    let query = world.new_query::<&DrainedCommand>();
+    let mut sum = 0;
+    query.drain(|_, cmd: DrainedCommand| sum += cmd.id);
+
+    assert_eq!(sum, 6);
+    assert_eq!(query.count(), 0);
+    let drops = DRAINED_COMMAND_DROPS.with(|d| d.borrow().clone());
+    assert_eq!(drops.iter().filter(|&&id| id == 0).count(), 3);
+    assert_eq!(drops.iter().filter(|&&id| id != 0).count(), 3);
+}
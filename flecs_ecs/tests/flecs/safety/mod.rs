@@ -145,6 +145,95 @@ mod entity_view {
         }
     }
 
+    mod get_mut_or {
+        use super::*;
+
+        #[derive(Component, Default)]
+        struct Baz(u8);
+
+        #[test]
+        #[should_panic(expected = "Cannot set write")]
+        fn read_default() {
+            let world = World::new();
+            let entity = world.entity().set(Baz(0));
+            entity.get::<&Baz>(|_| {
+                entity.get_mut_or_default::<Baz, _>(|_| {});
+            });
+        }
+
+        #[test]
+        #[should_panic(expected = "Cannot set write")]
+        fn default_default() {
+            let world = World::new();
+            let entity = world.entity();
+            entity.get_mut_or_default::<Baz, _>(|_| {
+                entity.get_mut_or_default::<Baz, _>(|_| {});
+            });
+        }
+
+        #[test]
+        #[should_panic(expected = "Cannot set write")]
+        fn read_insert_with() {
+            let world = World::new();
+            let entity = world.entity().set(Foo(0));
+            entity.get::<&Foo>(|_| {
+                entity.get_mut_or_insert_with::<Foo, _>(|| Foo(1), |_| {});
+            });
+        }
+
+        #[test]
+        #[should_panic(expected = "Cannot increment read")]
+        fn insert_with_read() {
+            let world = World::new();
+            let entity = world.entity();
+            entity.get_mut_or_insert_with::<Foo, _>(
+                || Foo(1),
+                |_| {
+                    let _ = entity.cloned::<&Foo>();
+                },
+            );
+        }
+
+        #[test]
+        fn sequential_no_violation() {
+            let world = World::new();
+            let entity = world.entity();
+            entity.get_mut_or_default::<Baz, _>(|b| b.0 += 1);
+            entity.get_mut_or_insert_with::<Foo, _>(|| Foo(1), |f| f.0 += 1);
+            entity.get::<(&Baz, &Foo)>(|(b, f)| {
+                assert_eq!(b.0, 1);
+                assert_eq!(f.0, 2);
+            });
+        }
+    }
+
+    mod drain {
+        use super::*;
+
+        #[derive(Component, Default)]
+        struct Qux(u8);
+
+        #[test]
+        #[should_panic(expected = "Cannot set write")]
+        fn read_drain() {
+            let world = World::new();
+            let entity = world.entity().set(Foo(0)).set(Qux(0));
+            entity.get::<&Qux>(|_| {
+                world.new_query::<&Foo>().drain(|_, _: Qux| {});
+            });
+        }
+
+        #[test]
+        fn drain_then_read() {
+            let world = World::new();
+            world.entity().set(Foo(0)).set(Qux(1));
+            world.new_query::<&Foo>().drain(|e, q: Qux| {
+                assert_eq!(q.0, 1);
+                e.get::<&Qux>(|q| assert_eq!(q.0, 0));
+            });
+        }
+    }
+
     mod from_query {
         use super::*;
 
@@ -845,6 +934,110 @@ mod query_in_query {
     }
 }
 
+mod worker_in_worker {
+    use super::*;
+
+    #[test]
+    fn read_read_ok() {
+        let world = World::new();
+        world.entity().set(Foo(0));
+        world.entity().set(Foo(1));
+        let query = query!(world, &Foo).build();
+        query.worker(0, 2).each(|_| {
+            query.worker(1, 2).each(|_| {});
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot set write")]
+    fn read_write_violation() {
+        let world = World::new();
+        world.entity().set(Foo(0));
+        world.entity().set(Foo(1));
+        let read = query!(world, &Foo).build();
+        let write = query!(world, &mut Foo).build();
+        read.worker(0, 2).each(|_| {
+            write.worker(1, 2).each(|_| {});
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot set write")]
+    fn write_write_violation() {
+        let world = World::new();
+        world.entity().set(Foo(0));
+        world.entity().set(Foo(1));
+        let query = query!(world, &mut Foo).build();
+        query.worker(0, 2).each(|_| {
+            query.worker(1, 2).each(|_| {});
+        });
+    }
+}
+
+mod par_system {
+    use super::*;
+    use alloc::sync::Arc;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Component)]
+    struct Total(u32);
+
+    #[test]
+    fn own_entity_write_singleton_read_ok() {
+        let world = World::new();
+        world.set_threads(4);
+        world.set(Total(1));
+        let entities: Vec<_> = (0..64)
+            .map(|i| world.entity().set(Foo(i)).set(Total(0)))
+            .collect();
+
+        system!(world, &Foo).par_each_entity(|entity, foo| {
+            let singleton = entity.world().get::<&Total>(|total| total.0);
+            entity.get::<&mut Total>(|total| total.0 = foo.0 as u32 + singleton);
+        });
+        world.progress();
+
+        for (i, entity) in entities.iter().enumerate() {
+            entity.get::<&Total>(|total| assert_eq!(total.0, i as u32 + 1));
+        }
+    }
+
+    #[test]
+    fn singleton_write_write_violation() {
+        let world = World::new();
+        world.set_threads(4);
+        world.set(Total(0));
+        for i in 0..64 {
+            world.entity().set(Foo(i));
+        }
+
+        let violations = Arc::new(AtomicUsize::new(0));
+        let violations_clone = violations.clone();
+
+        system!(world, &Foo).par_each_entity(move |entity, _| {
+            let world = entity.world();
+            let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+                world.get::<&mut Total>(|total| {
+                    // hold the write until another worker ran into it
+                    let start = std::time::Instant::now();
+                    while violations_clone.load(Ordering::Relaxed) == 0
+                        && start.elapsed() < core::time::Duration::from_secs(2)
+                    {
+                        std::thread::yield_now();
+                    }
+                    total.0 += 1;
+                });
+            }));
+            if result.is_err() {
+                violations_clone.fetch_add(1, Ordering::Relaxed);
+            }
+        });
+        world.progress();
+
+        assert!(violations.load(Ordering::Relaxed) > 0);
+    }
+}
+
 mod observer_in_observer {
     use super::*;
 
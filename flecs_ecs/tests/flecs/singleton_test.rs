@@ -895,3 +895,28 @@ fn singleton_try_get_mut_r_t_pair_types() {
     assert_eq!(x, 10);
     assert_eq!(y, 20);
 }
+
+#[test]
+fn singleton_try_target() {
+    #[derive(Component)]
+    struct CurrentLevel;
+
+    let world = World::new();
+
+    assert!(world.try_target(CurrentLevel::id(), None).is_none());
+
+    let level3 = world.entity_named("level3");
+    world.add((CurrentLevel::id(), level3));
+
+    assert_eq!(world.try_target(CurrentLevel::id(), None), Some(level3));
+    assert!(world.try_target(CurrentLevel::id(), Some(1)).is_none());
+    assert!(world.has((CurrentLevel::id(), level3)));
+
+    // the relationship entity holds the singleton pair
+    assert_eq!(
+        world
+            .singleton::<CurrentLevel>()
+            .target(CurrentLevel::id(), 0),
+        Some(level3)
+    );
+}
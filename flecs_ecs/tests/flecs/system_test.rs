@@ -2434,6 +2434,43 @@ fn startup_system() {
     });
 }
 
+#[test]
+fn interval_tick_source_from_system() {
+    #[derive(Component, Default)]
+    struct Fired {
+        spawn: Vec<i64>,
+        cleanup: Vec<i64>,
+    }
+
+    let world = World::new();
+
+    world.set(Fired::default());
+
+    let spawn = world.system::<()>().set_interval(0.5).run(|mut it| {
+        let world = it.world();
+        let frame = world.info().frame_count_total;
+        while it.next() {}
+        world.get::<&mut Fired>(|f| f.spawn.push(frame));
+    });
+
+    world.system::<()>().set_tick_source(spawn).run(|mut it| {
+        let world = it.world();
+        let frame = world.info().frame_count_total;
+        while it.next() {}
+        world.get::<&mut Fired>(|f| f.cleanup.push(frame));
+    });
+
+    // one simulated second, in steps that are exact in floating point
+    for _ in 0..8 {
+        world.progress_time(0.125);
+    }
+
+    world.get::<&Fired>(|f| {
+        assert_eq!(f.spawn.len(), 2);
+        assert_eq!(f.spawn, f.cleanup);
+    });
+}
+
 #[test]
 fn interval_tick_source() {
     let world = World::new();
@@ -3222,3 +3259,120 @@ fn custom_pipeline_w_name() {
 
     assert_eq!(count.get(), 1);
 }
+
+#[test]
+fn immediate_system_changes_visible_in_same_frame() {
+    let world = World::new();
+
+    let e = world.entity().set(Position { x: 10, y: 20 });
+    let e_id = e.id();
+
+    let s = world
+        .system::<&Position>()
+        .without(Velocity::id())
+        .run_immediate(|mut it| {
+            assert!(!it.world().is_readonly());
+            while it.next() {
+                for i in it.iter() {
+                    it.get_entity(i).unwrap().set(Velocity { x: 1, y: 2 });
+                }
+            }
+        });
+
+    assert!(s.immediate());
+
+    let count = std::rc::Rc::new(core::cell::Cell::new(0));
+    let count_c = count.clone();
+
+    world.system::<&Velocity>().each_entity(move |e2, v| {
+        assert_eq!(v.x, 1);
+        assert_eq!(v.y, 2);
+        assert_eq!(e2.id(), e_id);
+        count_c.set(count_c.get() + 1);
+    });
+
+    world.progress();
+
+    assert_eq!(count.get(), 1);
+    assert!(e.has(Velocity::id()));
+}
+
+#[test]
+fn immediate_false() {
+    let world = World::new();
+
+    let s = world
+        .system::<()>()
+        .immediate(false)
+        .run(|mut it| while it.next() {});
+
+    assert!(!s.immediate());
+}
+
+#[test]
+fn multi_threaded_toggle() {
+    let threads = std::thread::available_parallelism()
+        .map_or(2, core::num::NonZero::get)
+        .clamp(2, 4);
+
+    let world = World::new();
+    world.set_threads(threads as i32);
+
+    let entities: Vec<_> = (0..1000)
+        .map(|i| {
+            let e = world
+                .entity()
+                .set(Position { x: i, y: 0 })
+                .set(Velocity { x: 1, y: 2 });
+            // spread the entities over a few tables
+            if i % 3 == 0 {
+                e.add(TagA::id());
+            }
+            e
+        })
+        .collect();
+
+    let stages = std::sync::Arc::new(std::sync::atomic::AtomicU64::new(0));
+    let stages_c = stages.clone();
+
+    let mut builder = world.system::<(&mut Position, &Velocity)>();
+    // SAFETY: the callback only touches the components of its own entity and an
+    // atomic, and Position and Velocity are Send + Sync.
+    unsafe { builder.multi_threaded(true) };
+    let s = builder.each_iter(move |it, _, (p, v)| {
+        stages_c.fetch_or(
+            1 << it.world().stage_id(),
+            std::sync::atomic::Ordering::Relaxed,
+        );
+        p.x += v.x;
+        p.y += v.y;
+    });
+
+    assert!(s.multi_threaded());
+
+    world.progress();
+    world.progress();
+
+    for (i, e) in entities.iter().enumerate() {
+        e.get::<&Position>(|p| {
+            assert_eq!(p.x, i as i32 + 2);
+            assert_eq!(p.y, 4);
+        });
+    }
+
+    // every worker got a share of the entities
+    let used = stages.load(std::sync::atomic::Ordering::Relaxed);
+    assert_eq!(used.count_ones() as usize, threads);
+}
+
+#[test]
+fn multi_threaded_toggle_false() {
+    let world = World::new();
+
+    let mut builder = world.system::<()>();
+    // SAFETY: disabling multi threading is always sound.
+    unsafe { builder.multi_threaded(false) };
+    let s = builder.run(|mut it| while it.next() {});
+
+    assert!(!s.multi_threaded());
+}
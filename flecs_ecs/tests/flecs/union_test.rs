@@ -163,3 +163,33 @@ fn union_switch_enum_type() {
     assert!(!e.has((world.component_id::<Color>(), *flecs::Wildcard)));
     assert_eq!(e.table(), table);
 }
+
+#[derive(Component)]
+#[flecs(union)]
+struct UnionMovement;
+
+#[test]
+fn union_derive_attribute() {
+    let world = World::new();
+
+    let c = world.component::<UnionMovement>();
+    assert!(c.has(flecs::DontFragment));
+    assert!(c.has(flecs::Exclusive));
+
+    let e = world.entity().add((UnionMovement::id(), Standing::id()));
+    assert_eq!(
+        e.target(UnionMovement::id(), 0).unwrap().id(),
+        world.component_id::<Standing>()
+    );
+
+    let table = e.table();
+
+    e.add((UnionMovement::id(), Walking::id()));
+    assert!(e.has((UnionMovement::id(), Walking::id())));
+    assert!(!e.has((UnionMovement::id(), Standing::id())));
+    assert_eq!(e.table(), table);
+    assert_eq!(
+        e.target(UnionMovement::id(), 0).unwrap().id(),
+        world.component_id::<Walking>()
+    );
+}
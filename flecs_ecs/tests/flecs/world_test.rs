@@ -465,6 +465,36 @@ fn staged_count() {
     assert_eq!(stage.count(Position::id()), 6);
 }
 
+#[test]
+fn staged_commands_multiple_stages_merge() {
+    let world = World::new();
+    world.set_stage_count(4);
+    assert_eq!(world.get_stage_count(), 4);
+
+    let e = world.entity();
+
+    world.readonly_begin(false);
+
+    for i in 0..world.get_stage_count() {
+        let stage = world.stage(i);
+        assert_eq!(stage.stage_id(), i);
+        stage.entity().add(Position::id());
+        e.mut_current_stage(stage).set(Mass { value: i });
+    }
+
+    assert_eq!(world.count(Position::id()), 0);
+    assert!(!e.has(Mass::id()));
+
+    world.readonly_end();
+
+    assert_eq!(world.count(Position::id()), 4);
+
+    // stages are merged in order, so the last stage wins
+    e.get::<&Mass>(|m| {
+        assert_eq!(m.value, 3);
+    });
+}
+
 #[test]
 fn async_stage_add() {
     let world = World::new();
@@ -732,6 +762,25 @@ fn with_scope() {
     assert_eq!(count, 3);
 }
 
+#[test]
+fn with_scope_restores_on_panic() {
+    let world = World::new();
+
+    let parent = world.entity_named("P");
+
+    let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+        world.scope(parent, |world| {
+            world.entity_named("C");
+            panic!("scope closure panicked");
+        });
+    }));
+
+    assert!(result.is_err());
+    assert!(world.get_scope().is_none());
+    assert!(world.lookup("P::C").has((flecs::ChildOf::ID, parent.id())));
+    assert!(world.entity_named("Root").parent().is_none());
+}
+
 #[test]
 fn with_scope_type() {
     #[derive(Component)]
@@ -1493,6 +1542,36 @@ fn get_alive() {
     assert_eq!(alive, e2.id());
 }
 
+#[test]
+fn get_alive_from_raw_parts() {
+    let world = World::new();
+
+    let e1 = world.entity();
+    assert_eq!(e1.generation(), 0);
+    assert_eq!(e1.raw_id(), *e1.id());
+    e1.destruct();
+
+    let e2 = world.entity();
+    assert_eq!(e2.id().index(), e1.id().index());
+    assert_eq!(e2.generation(), 1);
+
+    let from_parts = Entity::from_raw_parts(e2.id().index(), e2.generation());
+    assert_eq!(from_parts, e2.id());
+    assert_eq!(world.get_alive(from_parts), e2);
+
+    // the stale id resolves to the entity currently using its index
+    let stale = Entity::from_raw_parts(e1.id().index(), 0);
+    assert_eq!(stale, e1.id());
+    assert!(!world.is_alive(stale));
+    assert_eq!(world.get_alive(stale).generation(), 1);
+}
+
+#[test]
+#[should_panic(expected = "generation does not fit")]
+fn entity_from_raw_parts_generation_overflow() {
+    let _ = Entity::from_raw_parts(1, 1 << 16);
+}
+
 #[test]
 fn make_alive() {
     let world = World::new();
@@ -1514,6 +1593,41 @@ fn make_alive() {
     assert!(world.is_alive(e3));
 }
 
+#[test]
+fn make_alive_restore_ids() {
+    let world = World::new();
+
+    // ids as saved from another world, the upper 32 bits hold the generation
+    let ids: [u64; 3] = [1000, 1001 | (1 << 32), 2000 | (3 << 32)];
+
+    for (i, id) in ids.iter().enumerate() {
+        world.make_alive(*id).set(Position { x: i as i32, y: 0 });
+    }
+
+    for (i, id) in ids.iter().enumerate() {
+        assert!(world.is_alive(*id));
+        let e = world.entity_from_id(*id);
+        assert_eq!(e, *id);
+        e.get::<&Position>(|p| assert_eq!(p.x, i as i32));
+    }
+
+    // making an entity alive again with the same generation is a no-op
+    assert_eq!(world.make_alive(ids[0]), ids[0]);
+}
+
+#[test]
+#[should_panic(expected = "different generation")]
+fn make_alive_generation_conflict_panics() {
+    let world = World::new();
+
+    let e1 = world.entity();
+    e1.destruct();
+    let e2 = world.entity();
+    assert!(e2.is_alive());
+
+    world.make_alive(e1.id());
+}
+
 #[test]
 fn reset_all() {}
 
@@ -1608,12 +1722,41 @@ fn set_lookup_path() {
     assert!(world.try_lookup("Child").is_none());
     assert_eq!(world.lookup("Parent::Child").id(), child_id);
 
-    let old_path = world.set_lookup_path(parent.id());
-    assert_eq!(world.lookup("Parent").id(), parent.id());
-    assert_eq!(world.lookup("Child").id(), child_id);
-    assert_eq!(world.lookup("Parent::Child").id(), child_id);
+    world.set_lookup_path(&[parent], |world| {
+        assert_eq!(world.lookup("Parent").id(), parent.id());
+        assert_eq!(world.lookup("Child").id(), child_id);
+        assert_eq!(world.lookup("Parent::Child").id(), child_id);
+    });
+
+    assert!(world.try_lookup("Child").is_none());
+}
 
-    unsafe { flecs_ecs::sys::ecs_set_lookup_path(world.ptr_mut(), old_path) };
+#[test]
+fn set_lookup_path_precedence() {
+    let world = World::new();
+
+    let first = world.entity_named("First");
+    let second = world.entity_named("Second");
+    let first_foo = world.entity_named("Foo").child_of(first);
+    let second_foo = world.entity_named("Foo").child_of(second);
+    let bar = world.entity_named("Bar").child_of(second);
+
+    world.set_lookup_path(&[first, second], |world| {
+        assert_eq!(world.lookup("Foo"), first_foo);
+        assert_eq!(world.lookup("Bar"), bar);
+
+        // a nested path is restored when its closure returns
+        world.set_lookup_path(&[second], |world| {
+            assert_eq!(world.lookup("Foo"), second_foo);
+        });
+        assert_eq!(world.lookup("Foo"), first_foo);
+    });
+
+    world.set_lookup_path(&[second, first], |world| {
+        assert_eq!(world.lookup("Foo"), second_foo);
+    });
+
+    assert!(world.try_lookup("Foo").is_none());
 }
 
 #[test]
@@ -1996,6 +2139,38 @@ fn exclusive_access_other_mutate() {
     world.exclusive_access_end(false);
 }
 
+#[test]
+fn exclusive_access_scope() {
+    let world = World::new();
+
+    {
+        let _access = world.exclusive_access_scope(Some(c"main"));
+        let e = world.entity().add(Position::id());
+        assert!(e.has(Position::id()));
+    }
+
+    // exclusive access was ended, so it can be obtained again
+    let access = world.exclusive_access_scope(None);
+    drop(access);
+
+    // and other threads can mutate the world again
+    struct SendPtr(*mut flecs_ecs::sys::ecs_world_t);
+    // SAFETY: the pointer is only used while this thread waits for the other
+    // thread to finish.
+    unsafe impl Send for SendPtr {}
+
+    let world_ptr = SendPtr(world.ptr_mut());
+    std::thread::spawn(move || {
+        let world_ptr = world_ptr;
+        let world_ref = unsafe { WorldRef::from_ptr(world_ptr.0) };
+        world_ref.entity_named("from_thread");
+    })
+    .join()
+    .unwrap();
+
+    assert!(world.try_lookup("from_thread").is_some());
+}
+
 #[test]
 fn id_if_registered() {
     {
@@ -2139,6 +2314,37 @@ fn get_type_info_rel_type_tgt_type_tag() {
     assert!(ti.is_none());
 }
 
+#[test]
+fn type_info_layout_and_hooks() {
+    #[derive(Component, Default, Clone)]
+    struct Name {
+        value: String,
+    }
+
+    let world = World::new();
+
+    let ti = world.type_info(world.component_id::<Position>()).unwrap();
+    assert_eq!(ti.component, world.component_id::<Position>());
+    assert_eq!(ti.size, core::mem::size_of::<Position>());
+    assert_eq!(ti.alignment, core::mem::align_of::<Position>());
+    assert!(!ti.has_dtor);
+
+    let ti = world.type_info(world.component_id::<Name>()).unwrap();
+    assert_eq!(ti.component, world.component_id::<Name>());
+    assert_eq!(ti.size, core::mem::size_of::<Name>());
+    assert_eq!(ti.alignment, core::mem::align_of::<Name>());
+    assert!(ti.has_ctor);
+    assert!(ti.has_dtor);
+    assert!(ti.has_copy);
+    assert!(ti.has_move);
+
+    let tgt = world.entity();
+    let ti = world.type_info((world.component_id::<Name>(), tgt.id()));
+    assert_eq!(ti.map(|ti| ti.size), Some(core::mem::size_of::<Name>()));
+
+    assert!(world.type_info(world.component_id::<TagA>()).is_none());
+}
+
 #[test]
 fn builtin_after_reset() {
     let world = World::new();
@@ -2166,6 +2372,54 @@ fn readonly_begin_end() {
     assert_eq!(count, 1);
 }
 
+#[test]
+fn readonly_scope_merges_on_drop() {
+    let world = World::new();
+
+    let e = world.entity();
+
+    {
+        let _readonly = world.readonly_scope();
+        assert!(world.is_readonly());
+
+        let stage = world.stage(0);
+        e.mut_current_stage(stage).set(Position { x: 10, y: 20 });
+        assert!(!e.has(Position::id()));
+    }
+
+    assert!(!world.is_readonly());
+    e.get::<&Position>(|p| assert_eq!((p.x, p.y), (10, 20)));
+}
+
+#[test]
+fn readonly_scope_ends_on_panic() {
+    let world = World::new();
+
+    let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+        let _readonly = world.readonly_scope();
+        panic!("boom");
+    }));
+
+    assert!(result.is_err());
+    assert!(!world.is_readonly());
+}
+
+#[test]
+fn readonly_scope_defers_world_operations() {
+    let world = World::new();
+
+    let e = world.entity();
+
+    let readonly = world.readonly_scope();
+    e.set(Position { x: 10, y: 20 }).add(Tag);
+    assert!(!e.has(Position::id()));
+    assert!(!e.has(Tag));
+    drop(readonly);
+
+    assert!(e.has(Tag));
+    e.get::<&Position>(|p| assert_eq!((p.x, p.y), (10, 20)));
+}
+
 #[test]
 fn defer_begin_end() {
     let world = World::new();
@@ -2247,6 +2501,90 @@ fn stage_count() {
     assert_eq!(world.get_stage_count(), 4);
 }
 
+#[test]
+fn world_builder_stage_count() {
+    let world = World::builder().with_stages(3).build();
+    assert_eq!(world.get_stage_count(), 3);
+    assert!(world.try_lookup("flecs::pipeline").is_some());
+
+    let world = World::builder()
+        .without_builtin_pipeline()
+        .with_stages(2)
+        .preallocate_entity_count(100)
+        .build();
+    assert_eq!(world.get_stage_count(), 2);
+    assert!(world.try_lookup("flecs::pipeline").is_none());
+
+    let world = World::builder().build();
+    assert_eq!(world.get_stage_count(), 1);
+}
+
+#[test]
+fn world_builder_threads_override_stages() {
+    let world = World::builder().with_threads(3).with_stages(2).build();
+    assert_eq!(world.get_stage_count(), 3);
+    assert_eq!(world.get_threads(), 3);
+}
+
+#[test]
+#[should_panic(expected = "worker threads require the builtin pipeline")]
+fn world_builder_threads_without_pipeline_panics() {
+    World::builder()
+        .without_builtin_pipeline()
+        .with_threads(2)
+        .build();
+}
+
+/// Records commands into the stages in reverse order, then returns every entity
+/// with `Position` after the merge.
+fn staged_workload() -> Vec<(u64, i32, Option<i32>, bool)> {
+    let world = World::new();
+    world.set_stage_count(3);
+
+    let shared = world.entity().set(Position { x: 0, y: 0 });
+
+    world.readonly_begin(false);
+    for i in (0..3).rev() {
+        let stage = world.stage(i);
+        let e = shared.mut_current_stage(stage);
+        e.set(Velocity { x: i, y: i * 10 });
+        match i {
+            0 => {
+                e.add(TagA::id());
+            }
+            2 => {
+                e.remove(TagA::id());
+            }
+            _ => {}
+        }
+        stage.entity().set(Position { x: 100 + i, y: 0 });
+    }
+    world.readonly_end();
+
+    let mut result = Vec::new();
+    world.each_entity::<&Position>(|e, p| {
+        let v = e.try_get::<&Velocity>(|v| v.x);
+        result.push((e.id().0, p.x, v, e.has(TagA::id())));
+    });
+    result.sort();
+
+    // stages merge in id order: the set of stage 2 is applied last, and its remove
+    // follows the add of stage 0
+    let shared = result.iter().find(|r| r.0 == shared.id().0).unwrap();
+    assert_eq!(*shared, (shared.0, 0, Some(2), false));
+
+    result
+}
+
+#[test]
+fn stage_merge_order_deterministic() {
+    let first = staged_workload();
+    let second = staged_workload();
+
+    assert_eq!(first.len(), 4);
+    assert_eq!(first, second);
+}
+
 #[test]
 fn preallocate_entity_count() {
     let world = World::new();
@@ -2269,6 +2607,31 @@ fn get_info() {
     assert!(info.table_count >= 0);
 }
 
+#[test]
+fn get_info_after_progress() {
+    let world = World::new();
+
+    world.system::<&mut Position>().each(|p| p.x += 1);
+    world.entity().set(Position { x: 0, y: 0 });
+    world.entity().add((Likes::id(), Tag::id()));
+
+    let before = world.info();
+    assert_eq!(before.frame_count_total, 0);
+    assert!(before.component_id_count > 0);
+    assert!(before.tag_id_count > 0);
+    assert!(before.pair_id_count > 0);
+    assert!(before.table_count > 0);
+
+    world.progress_time(0.5);
+
+    let after = world.info();
+    assert_eq!(after.frame_count_total, before.frame_count_total + 1);
+    assert!((after.delta_time - 0.5).abs() < f32::EPSILON);
+    assert!((after.world_time_total - 0.5).abs() < f64::EPSILON);
+    assert!(after.world_time_total_raw > 0.0);
+    assert!(after.systems_ran_total > before.systems_ran_total);
+}
+
 #[test]
 fn should_quit() {
     let world = World::new();
@@ -2276,6 +2639,93 @@ fn should_quit() {
     assert!(!world.should_quit());
 }
 
+#[test]
+fn quit_from_system() {
+    let world = World::new();
+
+    world.set(Count(0));
+    world.system::<()>().run(|mut it| {
+        while it.next() {}
+        let world = it.world();
+        world.get::<&mut Count>(|c| c.0 += 1);
+        if world.get::<&Count>(|c| c.0 == 3) {
+            world.quit();
+        }
+    });
+
+    let mut frames = 0;
+    loop {
+        let running = world.progress();
+        frames += 1;
+        if world.should_quit() {
+            // the frame in which quit was requested already reports it
+            assert!(!running);
+            break;
+        }
+        assert!(running);
+    }
+
+    assert_eq!(frames, 3);
+}
+
+/// Returns a cell receiving the id of the next system created in `world`.
+fn next_system_id(world: &World) -> alloc::rc::Rc<core::cell::Cell<u64>> {
+    let system_id = alloc::rc::Rc::new(core::cell::Cell::new(0));
+    let system_id_c = system_id.clone();
+    world
+        .observer::<flecs::OnAdd, ()>()
+        .with(flecs::system::System::id())
+        .each_entity(move |e, _| system_id_c.set(*e.id()));
+    system_id
+}
+
+#[test]
+fn run_once_defers_structural_changes() {
+    let world = World::new();
+
+    let e1 = world.entity().set(Position { x: 1, y: 2 });
+    let e2 = world.entity().set(Position { x: 3, y: 4 });
+    world.entity().set(Velocity { x: 1, y: 1 });
+
+    let system_id = next_system_id(&world);
+
+    world.set(Count(0));
+    world.run_once::<&Position>(|e, _p| {
+        e.add(Tag);
+        assert!(e.world().is_deferred());
+        assert!(!e.has(Tag));
+        e.world().get::<&mut Count>(|c| c.0 += 1);
+    });
+
+    assert!(!world.is_deferred());
+    assert!(e1.has(Tag));
+    assert!(e2.has(Tag));
+    world.get::<&Count>(|c| assert_eq!(c.0, 2));
+
+    // the transient system is cleaned up
+    let system_entity = world.entity_from_id(system_id.get());
+    assert_ne!(system_entity, 0);
+    assert!(!system_entity.is_alive());
+}
+
+#[test]
+fn run_once_destructs_system_on_panic() {
+    let world = World::new();
+    world.entity().set(Position { x: 1, y: 2 });
+
+    let system_id = next_system_id(&world);
+
+    let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+        world.run_once::<&Position>(|_, _| panic!("run_once callback"));
+    }));
+    assert!(result.is_err());
+
+    let system_entity = world.entity_from_id(system_id.get());
+    assert_ne!(system_entity, 0);
+    assert!(!system_entity.is_alive());
+    assert!(!world.is_deferred());
+}
+
 #[test]
 fn is_deferred() {
     let world = World::new();
@@ -2287,6 +2737,51 @@ fn is_deferred() {
     assert!(!world.is_deferred());
 }
 
+#[test]
+fn defer_end_nested_reports_flush() {
+    let world = World::new();
+    let e = world.entity();
+
+    assert!(world.defer_begin());
+    assert!(!world.defer_begin());
+    assert!(!world.defer_begin());
+    e.set(Position { x: 1, y: 2 });
+
+    assert!(!world.defer_end());
+    assert!(!world.defer_end());
+    assert!(world.is_deferred());
+    assert!(!e.has(Position::id()));
+
+    assert!(world.defer_end());
+    assert!(!world.is_deferred());
+    assert!(e.has(Position::id()));
+}
+
+#[test]
+fn is_deferred_in_defer_scope() {
+    let world = World::new();
+
+    assert!(!world.is_deferred());
+
+    world.defer(|| {
+        assert!(world.is_deferred());
+
+        // nested defer scopes keep the world deferred until the outermost ends
+        world.defer(|| {
+            assert!(world.is_deferred());
+        });
+        assert!(world.is_deferred());
+
+        world.defer_suspend();
+        assert!(!world.is_deferred());
+        assert!(world.is_defer_suspended());
+        world.defer_resume();
+        assert!(world.is_deferred());
+    });
+
+    assert!(!world.is_deferred());
+}
+
 #[test]
 fn is_readonly() {
     let world = World::new();
@@ -2374,3 +2869,318 @@ fn context_operations() {
     let ctx = world.context();
     assert_eq!(ctx as *const i32, &ctx_val as *const i32);
 }
+
+#[test]
+fn typed_ctx_round_trip() {
+    #[derive(Debug, PartialEq)]
+    struct Assets {
+        names: Vec<String>,
+        budget: u32,
+    }
+
+    let world = World::new();
+
+    assert_eq!(world.get_ctx::<Assets, _>(|_| ()), None);
+
+    world.set_ctx(Assets {
+        names: vec!["player".to_string()],
+        budget: 10,
+    });
+
+    world.get_ctx_mut::<Assets, _>(|assets| {
+        assets.names.push("enemy".to_string());
+        assets.budget -= 1;
+    });
+
+    world.system::<()>().run(|mut it| {
+        while it.next() {}
+        let budget = it.world().get_ctx::<Assets, _>(|assets| assets.budget);
+        assert_eq!(budget, Some(9));
+    });
+    world.progress();
+
+    let assets = world.get_ctx::<Assets, _>(|assets| assets.names.clone());
+    assert_eq!(
+        assets,
+        Some(vec!["player".to_string(), "enemy".to_string()])
+    );
+
+    // a context of another type is not returned
+    assert_eq!(world.get_ctx::<u32, _>(|v| *v), None);
+}
+
+/// World context that counts how often it is dropped.
+struct DropCounter(alloc::rc::Rc<core::cell::Cell<u32>>);
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[test]
+fn typed_ctx_dropped_on_replace_and_world_drop() {
+    let drops = alloc::rc::Rc::new(core::cell::Cell::new(0));
+
+    let world = World::new();
+    world.set_ctx(DropCounter(drops.clone()));
+    assert_eq!(drops.get(), 0);
+
+    world.set_ctx(DropCounter(drops.clone()));
+    assert_eq!(drops.get(), 1);
+
+    drop(world);
+    assert_eq!(drops.get(), 2);
+}
+
+#[test]
+fn typed_ctx_dropped_by_set_context() {
+    let drops = alloc::rc::Rc::new(core::cell::Cell::new(0));
+
+    let world = World::new();
+    world.set_ctx(DropCounter(drops.clone()));
+
+    let mut raw = 42;
+    world.set_context(&mut raw as *mut i32 as *mut core::ffi::c_void, None);
+    assert_eq!(drops.get(), 1);
+    assert!(world.get_ctx::<DropCounter, _>(|_| ()).is_none());
+
+    drop(world);
+    assert_eq!(drops.get(), 1);
+}
+
+#[test]
+fn typed_ctx_access_from_worker_thread_panics() {
+    use alloc::sync::Arc;
+    use core::panic::AssertUnwindSafe;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    let world = World::new();
+    world.set_ctx(0u32);
+
+    for _ in 0..1024 {
+        world.entity().set(Position { x: 0, y: 0 });
+    }
+
+    world.set_threads(4);
+
+    let owner_thread = std::thread::current().id();
+    let violations = Arc::new(AtomicUsize::new(0));
+    let worker_hits = Arc::new(AtomicUsize::new(0));
+    let violations_clone = violations.clone();
+    let worker_hits_clone = worker_hits.clone();
+
+    world
+        .system::<&mut Position>()
+        .par_each_entity(move |entity, _| {
+            let on_worker = std::thread::current().id() != owner_thread;
+            if on_worker {
+                worker_hits_clone.fetch_add(1, Ordering::Relaxed);
+            }
+
+            let world = entity.world();
+            let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+                world.get_ctx::<u32, _>(|v| *v);
+            }));
+
+            assert_eq!(result.is_err(), on_worker);
+            if result.is_err() {
+                violations_clone.fetch_add(1, Ordering::Relaxed);
+            }
+        });
+
+    world.progress();
+
+    assert!(worker_hits.load(Ordering::Relaxed) > 0);
+    assert_eq!(
+        violations.load(Ordering::Relaxed),
+        worker_hits.load(Ordering::Relaxed)
+    );
+}
+
+#[test]
+#[should_panic(expected = "already borrowed")]
+fn typed_ctx_nested_mut_borrow_panics() {
+    let world = World::new();
+    world.set_ctx(0u32);
+
+    world.get_ctx::<u32, _>(|_| {
+        world.get_ctx_mut::<u32, _>(|v| *v += 1);
+    });
+}
+
+#[test]
+fn set_entity_range_sequential_ids() {
+    let world = World::new();
+
+    let range = world.set_entity_range(1000, 2000);
+    assert_eq!(range.min(), 1000);
+    assert_eq!(range.max(), 2000);
+    assert!(world.entity_range_get().is_some());
+
+    let mut prev = None;
+    for _ in 0..100 {
+        let id = *world.entity().id();
+        assert!((1000..=2000).contains(&id));
+        if let Some(prev) = prev {
+            assert_eq!(id, prev + 1);
+        }
+        prev = Some(id);
+    }
+}
+
+#[test]
+fn progress_timed_sleeping_system() {
+    let world = World::new();
+
+    world.system::<()>().run(|mut it| {
+        while it.next() {}
+        std::thread::sleep(core::time::Duration::from_millis(2));
+    });
+
+    let stats = world.progress_timed(1.0);
+
+    assert!(stats.frame_time >= 0.0);
+    assert!(stats.system_time > 0.0);
+    assert!(stats.merge_time >= 0.0);
+    assert!(stats.frame_time >= stats.system_time);
+}
+
+#[test]
+fn progress_until_counter() {
+    let world = World::new();
+
+    world.set(Count(0));
+    world.system::<()>().run(|mut it| {
+        while it.next() {}
+        it.world().get::<&mut Count>(|c| c.0 += 1);
+    });
+
+    let done = world.progress_until(100, 1.0, |world| world.get::<&Count>(|c| c.0 == 10));
+    assert!(done);
+    world.get::<&Count>(|c| assert_eq!(c.0, 10));
+
+    let done = world.progress_until(5, 1.0, |world| world.get::<&Count>(|c| c.0 == 100));
+    assert!(!done);
+    world.get::<&Count>(|c| assert_eq!(c.0, 15));
+
+    // quitting stops the loop before the frame limit
+    world.quit();
+    let done = world.progress_until(5, 1.0, |_| false);
+    assert!(!done);
+    world.get::<&Count>(|c| assert_eq!(c.0, 16));
+}
+
+#[test]
+fn time_scale_delta_time() {
+    #[derive(Component, Default)]
+    struct Elapsed {
+        time: f32,
+        frames: i32,
+    }
+
+    let world = World::new();
+
+    world.set(Elapsed::default());
+    world.system::<()>().run(|mut it| {
+        while it.next() {}
+        let dt = it.delta_time();
+        it.world().get::<&mut Elapsed>(|e| {
+            e.time += dt;
+            e.frames += 1;
+        });
+    });
+
+    world.set_time_scale(0.5);
+    for _ in 0..4 {
+        world.progress_time(1.0);
+    }
+    assert!((world.delta_time() - 0.5).abs() < f32::EPSILON);
+    assert!((world.delta_time_raw() - 1.0).abs() < f32::EPSILON);
+    world.get::<&Elapsed>(|e| {
+        assert!((e.time - 2.0).abs() < f32::EPSILON);
+        assert_eq!(e.frames, 4);
+    });
+
+    // a scale of 0 pauses time but still runs the frame
+    world.set_time_scale(0.0);
+    for _ in 0..3 {
+        world.progress_time(1.0);
+    }
+    assert!(world.delta_time().abs() < f32::EPSILON);
+    world.get::<&Elapsed>(|e| {
+        assert!((e.time - 2.0).abs() < f32::EPSILON);
+        assert_eq!(e.frames, 7);
+    });
+}
+
+#[test]
+fn target_fps_limits_progress() {
+    let world = World::new();
+
+    world.set_target_fps(20.0);
+    assert!((world.get_target_fps() - 20.0).abs() < f32::EPSILON);
+
+    // the first frame has no previous frame to measure against, and doesn't sleep
+    world.progress();
+
+    let start = std::time::Instant::now();
+    for _ in 0..4 {
+        world.progress();
+    }
+    let elapsed = start.elapsed().as_secs_f32();
+
+    // 4 frames at 20 fps take 200ms
+    assert!(elapsed > 0.15, "elapsed: {elapsed}");
+    assert!(elapsed < 1.0, "elapsed: {elapsed}");
+
+    // the measured delta includes the time slept
+    let delta = world.info().delta_time;
+    assert!(delta > 0.04 && delta < 0.25, "delta: {delta}");
+}
+
+#[test]
+fn world_memory_stats_shrink() {
+    let world = World::new();
+
+    let mut kept = Vec::new();
+    let mut churn = Vec::new();
+    for i in 0..1000 {
+        let e = world.entity().set(Position { x: i, y: i * 2 });
+        if i % 100 == 0 {
+            kept.push(e.id());
+        } else {
+            churn.push(e.id());
+        }
+        churn.push(
+            world
+                .entity()
+                .set(Position { x: i, y: i })
+                .set(Velocity { x: i, y: i })
+                .id(),
+        );
+    }
+
+    for e in churn {
+        world.entity_from_id(e).destruct();
+    }
+
+    let before = world.memory_stats();
+    assert!(before.allocated_bytes > before.used_bytes);
+
+    world.shrink_memory();
+
+    let after = world.memory_stats();
+    assert!(after.allocated_bytes < before.allocated_bytes);
+    assert!(after.table_count < before.table_count);
+    assert_eq!(after.entity_count, before.entity_count);
+    assert_eq!(after.used_bytes, before.used_bytes);
+
+    assert_eq!(world.count(Position::id()), 10);
+    for (i, e) in kept.into_iter().enumerate() {
+        let x = i as i32 * 100;
+        world
+            .entity_from_id(e)
+            .get::<&Position>(|p| assert_eq!((p.x, p.y), (x, x * 2)));
+    }
+}
@@ -0,0 +1,89 @@
+//! A `no_std` static library using the core ECS, built by the `no_std` CI job.
+//!
+//! It provides its own panic handler and global allocator, so the build fails
+//! with duplicate lang items if anything links `std`.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::alloc::{GlobalAlloc, Layout};
+
+use flecs_ecs::prelude::*;
+
+#[derive(Component, Debug, Default)]
+pub struct Position {
+    pub x: f32,
+    pub y: f32,
+}
+
+#[derive(Component, Debug, Default)]
+pub struct Velocity {
+    pub x: f32,
+    pub y: f32,
+}
+
+#[derive(Component)]
+pub struct Likes;
+
+#[unsafe(no_mangle)]
+pub extern "C" fn flecs_no_std_simulate() -> usize {
+    simulate().len()
+}
+
+pub fn simulate() -> Vec<f32> {
+    let world = World::new();
+
+    let bob = world
+        .entity_named("bob")
+        .set(Position { x: 0.0, y: 0.0 })
+        .set(Velocity { x: 1.0, y: 2.0 });
+
+    world
+        .entity()
+        .set(Position { x: 10.0, y: 20.0 })
+        .set(Velocity { x: 3.0, y: 4.0 })
+        .add((Likes::id(), bob));
+
+    world.system::<(&mut Position, &Velocity)>().each(|(p, v)| {
+        p.x += v.x;
+        p.y += v.y;
+    });
+
+    world.progress();
+
+    let mut xs = Vec::new();
+    world.query::<&Position>().build().each(|p| xs.push(p.x));
+    xs
+}
+
+struct LibcAlloc;
+
+// SAFETY: forwards to the C allocator, which returns memory aligned to at least
+// the requested alignment or null on failure.
+unsafe impl GlobalAlloc for LibcAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let align = layout.align().max(core::mem::size_of::<usize>());
+        let mut ptr = core::ptr::null_mut();
+        // SAFETY: `align` is a power of two and a multiple of the pointer size.
+        match unsafe { libc::posix_memalign(&mut ptr, align, layout.size()) } {
+            0 => ptr as *mut u8,
+            _ => core::ptr::null_mut(),
+        }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, _layout: Layout) {
+        // SAFETY: `ptr` was allocated by `posix_memalign` above.
+        unsafe { libc::free(ptr as *mut _) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: LibcAlloc = LibcAlloc;
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    // SAFETY: aborting the process is always sound.
+    unsafe { libc::abort() }
+}
@@ -6,11 +6,24 @@ use syn::{
     Data, DeriveInput, Expr, Fields, Ident, LitStr, Path, Result, Token, Type, parse::ParseStream,
 };
 
+/// Bare `#[flecs(...)]` entries that add a single flecs trait, with the trait they add.
+const BARE_TRAITS: &[(&str, &str)] = &[("can_toggle", "CanToggle"), ("symmetric", "Symmetric")];
+
 // Parse #[flecs(...)] attribute and build calls to _component.add_trait::<flecs::...>();
-// Additionally parse special options like `meta`, `on_registration`, and `name = "..."`.
+// Additionally parse special options like `meta`, `bitmask`, `on_registration`, `union`,
+// `inherit`, `dont_inherit`, `override`, `name = "..."`, `scope = Path`, `default = expr`
+// and the trait shorthands listed in `BARE_TRAITS`.
 pub(crate) fn collect_flecs_traits_calls(
     input: &DeriveInput,
-) -> (TokenStream, bool, bool, Option<LitStr>, TokenStream) {
+) -> (
+    TokenStream,
+    bool,
+    bool,
+    Option<LitStr>,
+    TokenStream,
+    Option<Expr>,
+    TokenStream,
+) {
     use syn::{
         parenthesized, parse::Parse, parse::ParseStream, punctuated::Punctuated, token::Comma,
     };
@@ -19,8 +32,14 @@ pub(crate) fn collect_flecs_traits_calls(
         Single(Path),
         Pair(Path, Path),
         Name(LitStr),
+        Scope(Path),
+        Default(Expr),
         Meta(Span),
+        Bitmask(Span, Vec<(Ident, Expr)>),
         OnRegistration,
+        Union,
+        BareTrait(Ident),
+        OnInstantiate(Ident),
         Add(Vec<Type>),
         Set(Vec<Expr>),
         Traits(Vec<Item>),
@@ -41,22 +60,42 @@ pub(crate) fn collect_flecs_traits_calls(
                 let second: Path = inner.parse()?;
                 Ok(Item::Pair(first, second))
             } else if input.peek(Ident) && input.peek2(Token![=]) {
-                // name = "..."
+                // name = "...", scope = Path or default = expr
                 let ident: Ident = input.parse()?;
                 input.parse::<Token![=]>()?;
-                let value: LitStr = input.parse()?;
                 if ident == "name" {
+                    let value: LitStr = input.parse()?;
                     Ok(Item::Name(value))
+                } else if ident == "scope" {
+                    let value: Path = input.parse()?;
+                    Ok(Item::Scope(value))
+                } else if ident == "default" {
+                    let value: Expr = input.parse()?;
+                    Ok(Item::Default(value))
                 } else {
                     Err(syn::Error::new(
                         ident.span(),
-                        "Unsupported flecs option. Expected `name = \"...\"`",
+                        "Unsupported flecs option. Expected `name = \"...\"`, `scope = Path` or `default = expr`",
                     ))
                 }
             } else if input.peek(Ident) && input.peek2(syn::token::Paren) {
-                // function-like entries: add(...), set(...), traits(...), hooks(...), on_*(...)
+                // function-like entries: add(...), set(...), bitmask(...), traits(...), hooks(...), on_*(...)
                 let ident: Ident = input.parse()?;
-                if ident == "add" {
+                if ident == "bitmask" {
+                    let inner;
+                    parenthesized!(inner in input);
+                    let mut flags: Vec<(Ident, Expr)> = Vec::new();
+                    while !inner.is_empty() {
+                        let name: Ident = inner.parse()?;
+                        inner.parse::<Token![=]>()?;
+                        let value: Expr = inner.parse()?;
+                        flags.push((name, value));
+                        if !inner.is_empty() {
+                            inner.parse::<Comma>()?;
+                        }
+                    }
+                    Ok(Item::Bitmask(ident.span(), flags))
+                } else if ident == "add" {
                     let inner;
                     parenthesized!(inner in input);
                     let mut tys: Vec<Type> = Vec::new();
@@ -169,20 +208,40 @@ pub(crate) fn collect_flecs_traits_calls(
                 } else {
                     Err(syn::Error::new(
                         ident.span(),
-                        "Unknown flecs function. Expected `add(...)` or `set(...)` or `traits(...)` or `hooks(...)`",
+                        "Unknown flecs function. Expected `add(...)`, `set(...)`, `bitmask(...)`, `traits(...)` or `hooks(...)`",
                     ))
                 }
             } else {
                 // Bare identifier/path entry. Recognize `meta` and `on_registration` specially.
-                if input.peek(Ident) {
+                if input.peek(Token![override]) {
+                    let kw = input.parse::<Token![override]>()?;
+                    Ok(Item::OnInstantiate(Ident::new("Override", kw.span)))
+                } else if input.peek(Ident) {
                     let fork = input.fork();
                     let ident_peek: Ident = fork.parse()?;
                     if ident_peek == "meta" {
                         let meta_ident = input.parse::<Ident>()?;
                         Ok(Item::Meta(meta_ident.span()))
+                    } else if ident_peek == "bitmask" {
+                        let ident = input.parse::<Ident>()?;
+                        Ok(Item::Bitmask(ident.span(), Vec::new()))
                     } else if ident_peek == "on_registration" {
                         let _ = input.parse::<Ident>()?;
                         Ok(Item::OnRegistration)
+                    } else if ident_peek == "union" {
+                        let _ = input.parse::<Ident>()?;
+                        Ok(Item::Union)
+                    } else if let Some((_, flecs_trait)) =
+                        BARE_TRAITS.iter().find(|(attr, _)| ident_peek == attr)
+                    {
+                        let ident = input.parse::<Ident>()?;
+                        Ok(Item::BareTrait(Ident::new(flecs_trait, ident.span())))
+                    } else if ident_peek == "inherit" {
+                        let ident = input.parse::<Ident>()?;
+                        Ok(Item::OnInstantiate(Ident::new("Inherit", ident.span())))
+                    } else if ident_peek == "dont_inherit" {
+                        let ident = input.parse::<Ident>()?;
+                        Ok(Item::OnInstantiate(Ident::new("DontInherit", ident.span())))
                     } else {
                         let p: Path = input.parse()?;
                         Ok(Item::Single(p))
@@ -261,15 +320,101 @@ pub(crate) fn collect_flecs_traits_calls(
         )
     }
 
+    /// Returns an error for single-segment paths in `traits(...)` that don't name a builtin
+    /// flecs component trait, so a typo or a builtin that isn't a trait is reported once on
+    /// the attribute. Qualified paths are left to the `FlecsComponentTrait` bound of
+    /// `add_trait`, which every name listed here must satisfy.
+    fn validate_traits_single(p: &Path) -> Option<TokenStream> {
+        const BUILTIN_TRAITS: &[&str] = &[
+            "Acyclic",
+            "CanToggle",
+            "ChildOf",
+            "Delete",
+            "DependsOn",
+            "Disabled",
+            "DontFragment",
+            "DontInherit",
+            "Exclusive",
+            "Final",
+            "Inherit",
+            "Inheritable",
+            "IsA",
+            "NotQueryable",
+            "OnDelete",
+            "OnDeleteTarget",
+            "OnInstantiate",
+            "OneOf",
+            "OrderedChildren",
+            "Override",
+            "PairIsTag",
+            "Panic",
+            "Prefab",
+            "Reflexive",
+            "Relationship",
+            "Remove",
+            "Singleton",
+            "SlotOf",
+            "Sparse",
+            "Symmetric",
+            "Target",
+            "Trait",
+            "Transitive",
+            "Traversable",
+            "With",
+        ];
+        if p.segments.len() != 1 {
+            return None;
+        }
+        let ident = &p.segments.first().unwrap().ident;
+        if BUILTIN_TRAITS.iter().any(|name| ident == name) {
+            return None;
+        }
+        Some(
+            syn::Error::new(
+                ident.span(),
+                format!(
+                    "`{ident}` is not a builtin flecs component trait; expected one of: {}",
+                    BUILTIN_TRAITS.join(", ")
+                ),
+            )
+            .to_compile_error(),
+        )
+    }
+
+    /// Records the `OnInstantiate` policy of the component, rejecting a second one.
+    fn set_on_instantiate(slot: &mut Option<Ident>, policy: &Ident) -> Result<()> {
+        match slot {
+            Some(prev) if prev == policy => Err(syn::Error::new(
+                policy.span(),
+                format!("Duplicate `OnInstantiate` policy `{policy}` in #[flecs(...)] attribute"),
+            )),
+            Some(prev) => Err(syn::Error::new(
+                policy.span(),
+                format!(
+                    "Conflicting `OnInstantiate` policies `{prev}` and `{policy}` in #[flecs(...)] attribute"
+                ),
+            )),
+            None => {
+                *slot = Some(policy.clone());
+                Ok(())
+            }
+        }
+    }
+
     let mut out = TokenStream::new();
     let mut trait_consts = TokenStream::new();
+    let mut items = TokenStream::new();
     let mut has_flecs_meta = false;
     let mut has_on_registration = false;
+    let mut is_dont_fragment = false;
     let mut flecs_name: Option<LitStr> = None;
+    let mut custom_default: Option<Expr> = None;
     // Track ordering across all #[flecs(...)] attributes as encountered
     let mut position: usize = 0;
     let mut name_pos: Option<(usize, Span)> = None;
     let mut meta_pos: Option<(usize, Span)> = None;
+    let mut bitmask_span: Option<Span> = None;
+    let mut on_instantiate: Option<Ident> = None;
     for attr in &input.attrs {
         if attr.path().is_ident("flecs") {
             let args: Result<Punctuated<Item, Token![,]>> =
@@ -285,15 +430,17 @@ pub(crate) fn collect_flecs_traits_calls(
                             for t in items {
                                 match t {
                                     Item::Single(p) => {
+                                        if let Some(err) = validate_traits_single(p) {
+                                            out.extend(err);
+                                            continue;
+                                        }
                                         if let Some(seg) = p.segments.last() {
                                             if seg.ident == "Sparse" {
                                                 trait_consts.extend(
                                                     quote! { const IS_SPARSE: bool = true; },
                                                 );
                                             } else if seg.ident == "DontFragment" {
-                                                trait_consts.extend(
-                                                    quote! { const IS_DONT_FRAGMENT: bool = true; },
-                                                );
+                                                is_dont_fragment = true;
                                             }
                                         }
                                         let q = qualify(p);
@@ -309,23 +456,19 @@ pub(crate) fn collect_flecs_traits_calls(
                                             .last()
                                             .is_some_and(|s| s.ident == "OnInstantiate")
                                         {
-                                            let policy = p2.segments.last().and_then(|s| {
-                                                if s.ident == "Override" {
-                                                    Some(quote! { Override })
-                                                } else if s.ident == "Inherit" {
-                                                    Some(quote! { Inherit })
-                                                } else if s.ident == "DontInherit" {
-                                                    Some(quote! { DontInherit })
-                                                } else {
-                                                    None
-                                                }
+                                            let policy = p2.segments.last().filter(|s| {
+                                                s.ident == "Override"
+                                                    || s.ident == "Inherit"
+                                                    || s.ident == "DontInherit"
                                             });
-                                            if let Some(policy) = policy {
-                                                trait_consts.extend(quote! {
-                                                    const ON_INSTANTIATE:
-                                                        flecs_ecs::core::component_registration::OnInstantiatePolicy =
-                                                        flecs_ecs::core::component_registration::OnInstantiatePolicy::#policy;
-                                                });
+                                            if let Some(policy) = policy
+                                                && let Err(err) = set_on_instantiate(
+                                                    &mut on_instantiate,
+                                                    &policy.ident,
+                                                )
+                                            {
+                                                out.extend(err.to_compile_error());
+                                                continue;
                                             }
                                         }
                                         let q1 = qualify(p1);
@@ -376,9 +519,63 @@ pub(crate) fn collect_flecs_traits_calls(
                                 meta_pos = Some((position, *span));
                             }
                         }
+                        Item::Bitmask(span, flags) => {
+                            if bitmask_span.is_some() {
+                                out.extend(quote! { compile_error!("Duplicate `bitmask` in #[flecs(...)] attribute"); });
+                                continue;
+                            }
+                            bitmask_span = Some(*span);
+                            if matches!(input.data, Data::Enum(_)) {
+                                out.extend(
+                                    syn::Error::new(
+                                        *span,
+                                        "`bitmask` expects a struct wrapping a `u32`, not an enum",
+                                    )
+                                    .to_compile_error(),
+                                );
+                                continue;
+                            }
+                            let (has_repr_c, _, has_repr_transparent) = check_repr_c(input);
+                            if !has_repr_c && !has_repr_transparent {
+                                out.extend(
+                                    syn::Error::new(
+                                        *span,
+                                        "`bitmask` requires the struct to be `#[repr(C)]` or `#[repr(transparent)]`",
+                                    )
+                                    .to_compile_error(),
+                                );
+                                continue;
+                            }
+                            out.extend(bitmask_calls(*span, input, flags, &mut items));
+                        }
                         Item::OnRegistration => {
                             has_on_registration = true;
                         }
+                        Item::Union => {
+                            // Union relationships are non-fragmenting exclusive relationships
+                            is_dont_fragment = true;
+                            out.extend(quote! {
+                                _component.add_trait::<flecs_ecs::core::flecs::DontFragment>();
+                                _component.add_trait::<flecs_ecs::core::flecs::Exclusive>();
+                            });
+                        }
+                        Item::BareTrait(flecs_trait) => {
+                            out.extend(quote! {
+                                _component.add_trait::<flecs_ecs::core::flecs::#flecs_trait>();
+                            });
+                        }
+                        Item::OnInstantiate(policy) => {
+                            if let Err(err) = set_on_instantiate(&mut on_instantiate, policy) {
+                                out.extend(err.to_compile_error());
+                                continue;
+                            }
+                            out.extend(quote! {
+                                _component.add_trait::<(
+                                    flecs_ecs::core::flecs::OnInstantiate,
+                                    flecs_ecs::core::flecs::#policy,
+                                )>();
+                            });
+                        }
                         Item::Add(tys) => {
                             for ty in tys {
                                 match ty {
@@ -438,6 +635,18 @@ pub(crate) fn collect_flecs_traits_calls(
                                 out.extend(quote! { compile_error!("Duplicate `name` in #[flecs(...)] attribute"); });
                             }
                         }
+                        Item::Scope(scope) => {
+                            // Create the component entity as a child of the scope entity
+                            out.extend(quote! { _component.child_of(<#scope>::id()); });
+                        }
+                        Item::Default(expr) => {
+                            // used by the ctor hook instead of `Default::default()`
+                            if custom_default.is_none() {
+                                custom_default = Some(expr.clone());
+                            } else {
+                                out.extend(quote! { compile_error!("Duplicate `default` in #[flecs(...)] attribute"); });
+                            }
+                        }
                         Item::Single(_) | Item::Pair(_, _) => {
                             out.extend(quote! { compile_error!("Traits should be wrapped in traits(...). Use #[flecs(traits(YourTrait))]"); });
                         }
@@ -449,6 +658,16 @@ pub(crate) fn collect_flecs_traits_calls(
             }
         }
     }
+    if is_dont_fragment {
+        trait_consts.extend(quote! { const IS_DONT_FRAGMENT: bool = true; });
+    }
+    if let Some(policy) = on_instantiate {
+        trait_consts.extend(quote! {
+            const ON_INSTANTIATE: flecs_ecs::core::component_registration::OnInstantiatePolicy =
+                flecs_ecs::core::component_registration::OnInstantiatePolicy::#policy;
+        });
+    }
+
     // Validate ordering: if name/meta are provided, they must occupy the first two positions in any order.
     let mut ordering_error: Option<(String, Span)> = None;
     match (name_pos, meta_pos) {
@@ -490,6 +709,16 @@ pub(crate) fn collect_flecs_traits_calls(
         out.extend(syn::Error::new(span, msg).to_compile_error());
     }
 
+    if let (Some(span), true) = (bitmask_span, has_flecs_meta) {
+        out.extend(
+            syn::Error::new(
+                span,
+                "`bitmask` already provides the reflection data and cannot be combined with `meta`",
+            )
+            .to_compile_error(),
+        );
+    }
+
     // If meta was requested, ensure we invoke it during registration.
     let out = if has_flecs_meta {
         quote! {
@@ -506,9 +735,70 @@ pub(crate) fn collect_flecs_traits_calls(
         has_on_registration,
         flecs_name,
         trait_consts,
+        custom_default,
+        items,
     )
 }
 
+// Mark the component as a meta bitmask and register one `(Constant, u32)` child per flag.
+// The type is reflected as a `u32`, so its layout is checked to match at compile time.
+#[cfg(feature = "flecs_meta")]
+fn bitmask_calls(
+    span: Span,
+    input: &DeriveInput,
+    flags: &[(Ident, Expr)],
+    items: &mut TokenStream,
+) -> TokenStream {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let bits = flags.iter().map(|(name, value)| {
+        quote! {
+            _component.bit(stringify!(#name), {
+                let value: u32 = #value;
+                value
+            });
+        }
+    });
+    // an associated const, so it is checked for every instantiation of a generic type;
+    // types without generics are checked right away
+    items.extend(quote::quote_spanned! {span=>
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #[doc(hidden)]
+            const FLECS_BITMASK_LAYOUT: () = assert!(
+                ::core::mem::size_of::<Self>() == 4 && ::core::mem::align_of::<Self>() == 4,
+                "`bitmask` requires a type with the size and alignment of a `u32`"
+            );
+        }
+    });
+    if input.generics.params.is_empty() {
+        items.extend(quote::quote_spanned! {span=>
+            const _: () = #ident::FLECS_BITMASK_LAYOUT;
+        });
+    }
+    let layout_check = quote::quote_spanned! {span=>
+        let () = Self::FLECS_BITMASK_LAYOUT;
+    };
+    quote! {
+        #layout_check
+        _component.bitmask();
+        #( #bits )*
+    }
+}
+
+#[cfg(not(feature = "flecs_meta"))]
+fn bitmask_calls(
+    span: Span,
+    _input: &DeriveInput,
+    _flags: &[(Ident, Expr)],
+    _items: &mut TokenStream,
+) -> TokenStream {
+    syn::Error::new(
+        span,
+        "`bitmask` requires the `flecs_meta` feature, please enable it in your `Cargo.toml`",
+    )
+    .to_compile_error()
+}
+
 pub(crate) fn impl_meta(
     input: &DeriveInput,
     has_repr_c: bool,
@@ -675,6 +965,7 @@ pub(crate) fn impl_cached_component_data_struct(
     flecs_traits_calls: &TokenStream,
     flecs_name: &Option<LitStr>,
     trait_consts: &TokenStream,
+    custom_default: &Option<Expr>,
 ) -> proc_macro2::TokenStream {
     let is_generic = !ast.generics.params.is_empty();
 
@@ -795,9 +1086,14 @@ pub(crate) fn impl_cached_component_data_struct(
         contains_where_bound = !where_clause.predicates.is_empty();
     }
 
-    let hook_impl = if !is_generic {
+    let register_default_hooks = if custom_default.is_some() {
+        quote! {
+            fn __register_default_hooks(type_hooks: &mut flecs_ecs::sys::ecs_type_hooks_t) {
+                flecs_ecs::core::lifecycle_traits::register_custom_ctor_lifecycle_actions::<#name #type_generics>(type_hooks);
+            }
+        }
+    } else if !is_generic {
         quote! {
-
             fn __register_default_hooks(type_hooks: &mut flecs_ecs::sys::ecs_type_hooks_t) {
                 use flecs_ecs::core::component_registration::ComponentInfo;
                 const IMPLS_DEFAULT: bool =  #name::IMPLS_DEFAULT;
@@ -811,6 +1107,31 @@ pub(crate) fn impl_cached_component_data_struct(
                     );
                 }
             }
+        }
+    } else if contains_lifetime_bound && !contains_any_generic_type {
+        quote! {
+            fn __register_default_hooks(type_hooks: &mut flecs_ecs::sys::ecs_type_hooks_t) {
+                use flecs_ecs::core::component_registration::ComponentInfo;
+                const IMPLS_DEFAULT: bool =  #name::<'_>::IMPLS_DEFAULT;
+                const IS_ENUM: bool =  <#name::<'_> as ComponentInfo>::IS_ENUM;
+
+                if IMPLS_DEFAULT {
+                    flecs_ecs::core::lifecycle_traits::register_ctor_lifecycle_actions::<<flecs_ecs::core::component_registration::registration_types::ConditionalTypeSelector<IMPLS_DEFAULT,#name #type_generics>as flecs_ecs::core::component_registration::FlecsDefaultType> ::Type, >(type_hooks);
+                } else if !IS_ENUM {
+                    flecs_ecs::core::lifecycle_traits::register_ctor_panic_lifecycle_actions::<#name #type_generics>(
+                        type_hooks,
+                    );
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let hook_impl = if !is_generic {
+        quote! {
+
+            #register_default_hooks
 
             fn __register_clone_hooks(type_hooks: &mut flecs_ecs::sys::ecs_type_hooks_t) {
                 use flecs_ecs::core::component_registration::ComponentInfo;
@@ -858,19 +1179,7 @@ pub(crate) fn impl_cached_component_data_struct(
     } else if contains_lifetime_bound && !contains_any_generic_type {
         quote! {
 
-            fn __register_default_hooks(type_hooks: &mut flecs_ecs::sys::ecs_type_hooks_t) {
-                use flecs_ecs::core::component_registration::ComponentInfo;
-                const IMPLS_DEFAULT: bool =  #name::<'_>::IMPLS_DEFAULT;
-                const IS_ENUM: bool =  <#name::<'_> as ComponentInfo>::IS_ENUM;
-
-                if IMPLS_DEFAULT {
-                    flecs_ecs::core::lifecycle_traits::register_ctor_lifecycle_actions::<<flecs_ecs::core::component_registration::registration_types::ConditionalTypeSelector<IMPLS_DEFAULT,#name #type_generics>as flecs_ecs::core::component_registration::FlecsDefaultType> ::Type, >(type_hooks);
-                } else if !IS_ENUM {
-                    flecs_ecs::core::lifecycle_traits::register_ctor_panic_lifecycle_actions::<#name #type_generics>(
-                        type_hooks,
-                    );
-                }
-            }
+            #register_default_hooks
 
             fn __register_clone_hooks(type_hooks: &mut flecs_ecs::sys::ecs_type_hooks_t) {
                 use flecs_ecs::core::component_registration::ComponentInfo;
@@ -917,6 +1226,8 @@ pub(crate) fn impl_cached_component_data_struct(
         }
     } else {
         quote! {
+            #register_default_hooks
+
             fn __register_clone_hooks(type_hooks: &mut flecs_ecs::sys::ecs_type_hooks_t) {
                     flecs_ecs::core::lifecycle_traits::register_copy_panic_lifecycle_action::<#name #type_generics>(
                         type_hooks,
@@ -985,6 +1296,38 @@ pub(crate) fn impl_cached_component_data_struct(
         }
     };
 
+    // `default = expr` provides the ctor hook, so the component counts as default constructible
+    let (clone_default, custom_default_impl) = if let Some(expr) = custom_default {
+        let impls_clone = if !is_generic
+            || (contains_lifetime_bound && !contains_any_generic_type)
+            || (contains_any_generic_type && contains_all_clone_bound)
+        {
+            quote! {
+                const IMPLS_CLONE: bool = {
+                    use flecs_ecs::core::utility::traits::DoesNotImpl;
+                    flecs_ecs::core::utility::types::ImplementsClone::<#name #type_generics>::IMPLS
+                };
+            }
+        } else {
+            quote! { const IMPLS_CLONE: bool = false; }
+        };
+        (
+            quote! {
+                #impls_clone
+                const IMPLS_DEFAULT: bool = true;
+            },
+            quote! {
+                impl #impl_generics flecs_ecs::core::lifecycle_traits::CustomDefault for #name #type_generics #where_clause {
+                    fn __custom_default() -> Self {
+                        #expr
+                    }
+                }
+            },
+        )
+    } else {
+        (clone_default, quote! {})
+    };
+
     let partial_ord_bound = if !is_generic
         || (contains_lifetime_bound && !contains_any_generic_type)
         || (contains_any_generic_type && contains_all_partial_ord_bound)
@@ -1192,6 +1535,7 @@ pub(crate) fn impl_cached_component_data_struct(
     quote! {
         #is_empty_component_trait
         #common_traits
+        #custom_default_impl
         #component_id
         #on_component_registration
         #internal_on_component_registration
@@ -1314,6 +1658,7 @@ pub(crate) fn impl_cached_component_data_enum(
 
     let has_variants = !variants.is_empty();
     let size_variants = variants.len() as u32;
+    let len_variants = variants.len();
     let not_empty_trait_or_error = if has_variants {
         quote! { impl #impl_generics flecs_ecs::core::DataComponent for #name #type_generics #where_clause {} }
     } else {
@@ -1322,7 +1667,7 @@ pub(crate) fn impl_cached_component_data_enum(
 
     let cached_enum_data_impl = quote! {
         const SIZE_ENUM_FIELDS: u32 = #size_variants;
-        type VariantIterator = std::vec::IntoIter<#name #impl_generics>;
+        type VariantIterator = core::array::IntoIter<#name #impl_generics, #len_variants>;
 
         fn name_cstr(&self) -> &core::ffi::CStr {
             match self {
@@ -1346,7 +1691,7 @@ pub(crate) fn impl_cached_component_data_enum(
         }
 
         fn iter() -> Self::VariantIterator {
-            vec![#(#variant_constructors),*].into_iter()
+            [#(#variant_constructors),*].into_iter()
         }
     };
 
@@ -1593,8 +1938,13 @@ pub(crate) fn impl_cached_component_data_enum(
     }
 }
 
-pub(crate) fn check_repr_c(input: &syn::DeriveInput) -> (bool, TokenStream) {
+/// Checks the `#[repr(...)]` attributes of the type.
+///
+/// Returns whether it is `#[repr(C)]` or has an integer repr, the underlying type of such an
+/// enum, and whether it is `#[repr(transparent)]`.
+pub(crate) fn check_repr_c(input: &syn::DeriveInput) -> (bool, TokenStream, bool) {
     let mut token_stream = TokenStream::new();
+    let mut found_repr_transparent = false;
 
     for attr in &input.attrs {
         if attr.path().is_ident("repr") {
@@ -1624,6 +1974,12 @@ pub(crate) fn check_repr_c(input: &syn::DeriveInput) -> (bool, TokenStream) {
                         let ident = path.get_ident().cloned().unwrap();
                         token_stream = quote! { #ident };
                         break;
+                    } else if path.is_ident("transparent") {
+                        found_repr_transparent = true;
+                    }
+
+                    if input.peek(Token![,]) {
+                        input.parse::<Token![,]>()?;
                     }
                 }
                 Ok(found_repr_c)
@@ -1632,7 +1988,7 @@ pub(crate) fn check_repr_c(input: &syn::DeriveInput) -> (bool, TokenStream) {
             if let Ok(found_repr_c) = result
                 && found_repr_c
             {
-                return (true, token_stream); // Return true immediately if `#[repr(C)]` is found
+                return (true, token_stream, false); // Return true immediately if `#[repr(C)]` is found
             }
         }
     }
@@ -1640,6 +1996,7 @@ pub(crate) fn check_repr_c(input: &syn::DeriveInput) -> (bool, TokenStream) {
     (
         false,
         quote! { flecs_ecs::core::component_registration::NoneEnum },
+        found_repr_transparent,
     ) // Return false if no `#[repr(C)]` and variants is found
 }
 
@@ -1660,8 +2017,15 @@ pub(crate) fn expand_component_derive(mut input: syn::DeriveInput) -> proc_macro
     use alloc::vec::Vec;
 
     // Collect #[flecs(...)] trait requests and options (e.g., meta) to apply on registration
-    let (flecs_traits_calls, has_flecs_meta, has_on_registration, flecs_name, trait_consts) =
-        collect_flecs_traits_calls(&input);
+    let (
+        flecs_traits_calls,
+        has_flecs_meta,
+        has_on_registration,
+        flecs_name,
+        trait_consts,
+        custom_default,
+        flecs_items,
+    ) = collect_flecs_traits_calls(&input);
 
     let has_repr_c = check_repr_c(&input);
 
@@ -1683,6 +2047,7 @@ pub(crate) fn expand_component_derive(mut input: syn::DeriveInput) -> proc_macro
                 &flecs_traits_calls,
                 &flecs_name,
                 &trait_consts,
+                &custom_default,
             ));
         }
         syn::Data::Enum(_) => {
@@ -1696,7 +2061,10 @@ pub(crate) fn expand_component_derive(mut input: syn::DeriveInput) -> proc_macro
                     &flecs_traits_calls,
                     &flecs_name,
                     &trait_consts,
+                    &custom_default,
                 ));
+            } else if custom_default.is_some() {
+                return quote! { compile_error!("`default = ...` is not supported for #[repr(C)] enums"); };
             } else {
                 generated_impls.push(impl_cached_component_data_enum(
                     &mut input,
@@ -1720,5 +2088,6 @@ pub(crate) fn expand_component_derive(mut input: syn::DeriveInput) -> proc_macro
     quote! {
         #( #generated_impls )*
         #meta_impl
+        #flecs_items
     }
 }
@@ -224,7 +224,7 @@ pub(crate) fn expand_ecs_rust_trait(name: Ident) -> TokenStream {
             pub fn register_vtable<T: #name + flecs_ecs::core::component_registration::ComponentId>(world: &flecs_ecs::core::World) -> usize {
                 // Create a dangling pointer to T and cast it to a trait object
                 // This gives us access to the vtable without needing an actual instance
-                let trait_obj_ptr = std::ptr::NonNull::<T>::dangling() as std::ptr::NonNull<dyn #name>;
+                let trait_obj_ptr = core::ptr::NonNull::<T>::dangling() as core::ptr::NonNull<dyn #name>;
 
                 // SAFETY: the const assertion generated alongside this component guarantees
                 // `NonNull<dyn Trait>` is two pointer-sized words (data, vtable).
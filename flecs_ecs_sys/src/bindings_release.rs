@@ -9804,6 +9804,10 @@ unsafe extern "C-unwind" {
         size: usize,
     ) -> ecs_get_ptr_t;
 }
+unsafe extern "C-unwind" {
+    #[doc = "Id a single-term observer is registered for. Observers for a trivial term\n don't keep a query, so their term can't be read from ecs_observer_t::query.\n Returns 0 for observers that aren't registered for a single id."]
+    pub fn ecs_rust_observer_register_id(observer: *const ecs_observer_t) -> ecs_id_t;
+}
 unsafe extern "C-unwind" {
     #[doc = "ABI guards: C-side sizeof for structs with FLECS_DEBUG-gated fields, so the\n Rust side can assert its bindings match the compiled profile."]
     pub fn ecs_rust_sizeof_ecs_ref_t() -> usize;
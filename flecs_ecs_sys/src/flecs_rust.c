@@ -362,6 +362,15 @@ error:
     return ECS_GET_PTR_NULL;
 }
 
+ecs_id_t ecs_rust_observer_register_id(
+    const ecs_observer_t *observer)
+{
+    ecs_check(observer != NULL, ECS_INVALID_PARAMETER, NULL);
+    return flecs_observer_impl(observer)->register_id;
+error:
+    return 0;
+}
+
 size_t ecs_rust_sizeof_ecs_ref_t(void) {
     return sizeof(ecs_ref_t);
 }
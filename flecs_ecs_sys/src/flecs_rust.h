@@ -43,6 +43,13 @@ ecs_get_ptr_t ecs_rust_get_sparse_id(
     ecs_id_t id,
     size_t size);
 
+/* Id a single-term observer is registered for. Observers for a trivial term
+ * don't keep a query, so their term can't be read from ecs_observer_t::query.
+ * Returns 0 for observers that aren't registered for a single id. */
+FLECS_API
+ecs_id_t ecs_rust_observer_register_id(
+    const ecs_observer_t *observer);
+
 /* ABI guards: C-side sizeof for structs with FLECS_DEBUG-gated fields, so the
  * Rust side can assert its bindings match the compiled profile. */
 FLECS_API size_t ecs_rust_sizeof_ecs_ref_t(void);
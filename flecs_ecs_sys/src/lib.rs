@@ -4,6 +4,8 @@
 //!
 //! [`flecs`]: https://www.flecs.dev/
 
+#![no_std]
+
 #[cfg(all(feature = "force_build_release", feature = "force_build_debug"))]
 compile_error!(
     "Features 'force_build_release' and 'force_build_debug' cannot be enabled at the same time."
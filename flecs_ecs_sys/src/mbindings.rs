@@ -5,10 +5,9 @@
 
 use super::*;
 
-#[cfg(feature = "flecs_alerts")]
+// not gated on `flecs_alerts`, the pregenerated bindings always declare `ecs_alert_init`
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-//#[cfg(feature = "flecs_alerts")] //TODO flecs ecs_alert_init not properly defined in flecs c api.
 pub struct ecs_alert_desc_t {
     pub _canary: i32,
     #[doc = "Entity associated with alert"]